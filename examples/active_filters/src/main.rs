@@ -0,0 +1,152 @@
+use chrono::NaiveDate;
+use leptos::*;
+use leptos_struct_table::*;
+use std::collections::VecDeque;
+use std::ops::Range;
+
+#[derive(TableRow, Clone)]
+#[table(sortable, classes_provider = "BootstrapClassesPreset")]
+pub struct Book {
+    pub id: u32,
+    pub title: String,
+    pub author: String,
+    pub publish_date: NaiveDate,
+}
+
+/// Local data that is filtered by the title and sorted by the table.
+pub struct BookDataProvider {
+    books: Vec<Book>,
+    sorting: VecDeque<(usize, ColumnSort)>,
+    title_filter: RwSignal<String>,
+}
+
+impl BookDataProvider {
+    fn filtered_and_sorted(&self) -> Vec<Book> {
+        let title_filter = self.title_filter.get_untracked().to_lowercase();
+
+        let mut books = self
+            .books
+            .iter()
+            .filter(|book| book.title.to_lowercase().contains(&title_filter))
+            .cloned()
+            .collect::<Vec<_>>();
+
+        for (col_index, sort) in self.sorting.iter().rev() {
+            books.sort_by(|a, b| {
+                let ordering = match col_index {
+                    0 => a.id.cmp(&b.id),
+                    1 => a.title.cmp(&b.title),
+                    2 => a.author.cmp(&b.author),
+                    _ => a.publish_date.cmp(&b.publish_date),
+                };
+
+                match sort {
+                    ColumnSort::Descending => ordering.reverse(),
+                    _ => ordering,
+                }
+            });
+        }
+
+        books
+    }
+}
+
+impl TableDataProvider<Book> for BookDataProvider {
+    async fn get_rows(&self, range: Range<usize>) -> Result<(Vec<Book>, Range<usize>), String> {
+        Ok(get_vec_range_clamped(&self.filtered_and_sorted(), range))
+    }
+
+    async fn row_count(&self) -> Option<usize> {
+        Some(self.filtered_and_sorted().len())
+    }
+
+    fn set_sorting(&mut self, sorting: &VecDeque<(usize, ColumnSort)>) {
+        self.sorting = sorting.clone();
+    }
+
+    fn track(&self) {
+        self.title_filter.track();
+    }
+}
+
+#[component]
+pub fn App() -> impl IntoView {
+    let sorting = create_rw_signal(VecDeque::new());
+    let title_filter = create_rw_signal(String::new());
+
+    let rows = BookDataProvider {
+        books: books(),
+        sorting: VecDeque::new(),
+        title_filter,
+    };
+
+    // the chips are derived from the same signals that are used for filtering
+    let filters = Signal::derive(move || {
+        let title = title_filter.get();
+
+        if title.is_empty() {
+            vec![]
+        } else {
+            vec![ActiveFilter::new(
+                format!("Title contains '{title}'"),
+                move || title_filter.set(String::new()),
+            )]
+        }
+    });
+
+    view! {
+        <div class="container">
+            <input
+                type="search"
+                class="form-control my-3"
+                placeholder="Filter by title"
+                prop:value=title_filter
+                on:input=move |evt| title_filter.set(event_target_value(&evt))
+            />
+
+            <ActiveFiltersBar<Book, _> sorting filters />
+
+            <table class="table table-hover">
+                <TableContent rows sorting />
+            </table>
+        </div>
+    }
+}
+
+fn books() -> Vec<Book> {
+    vec![
+        Book {
+            id: 1,
+            title: "The Great Gatsby".to_string(),
+            author: "F. Scott Fitzgerald".to_string(),
+            publish_date: NaiveDate::from_ymd_opt(1925, 4, 10).unwrap(),
+        },
+        Book {
+            id: 2,
+            title: "The Grapes of Wrath".to_string(),
+            author: "John Steinbeck".to_string(),
+            publish_date: NaiveDate::from_ymd_opt(1939, 4, 14).unwrap(),
+        },
+        Book {
+            id: 3,
+            title: "Nineteen Eighty-Four".to_string(),
+            author: "George Orwell".to_string(),
+            publish_date: NaiveDate::from_ymd_opt(1949, 6, 8).unwrap(),
+        },
+        Book {
+            id: 4,
+            title: "Ulysses".to_string(),
+            author: "James Joyce".to_string(),
+            publish_date: NaiveDate::from_ymd_opt(1922, 2, 2).unwrap(),
+        },
+    ]
+}
+
+fn main() {
+    _ = console_log::init_with_level(log::Level::Debug);
+    console_error_panic_hook::set_once();
+
+    mount_to_body(|| {
+        view! { <App/> }
+    })
+}
@@ -0,0 +1,80 @@
+use futures::future::{FutureExt, LocalBoxFuture, Shared};
+use gloo_timers::future::TimeoutFuture;
+use leptos::*;
+use leptos_struct_table::*;
+use std::ops::Range;
+
+#[derive(TableRow, Clone)]
+pub struct Person {
+    pub id: u32,
+    pub name: String,
+    pub age: u32,
+}
+
+/// Pretend this is some client that first has to authenticate before it can load any data.
+async fn connect() -> Vec<Person> {
+    TimeoutFuture::new(2000).await;
+
+    vec![
+        Person {
+            id: 1,
+            name: "John".to_string(),
+            age: 32,
+        },
+        Person {
+            id: 2,
+            name: "Jane".to_string(),
+            age: 28,
+        },
+        Person {
+            id: 3,
+            name: "Bob".to_string(),
+            age: 45,
+        },
+    ]
+}
+
+/// A data provider that only becomes ready once `connect()` has finished.
+pub struct LazyDataProvider {
+    data: Shared<LocalBoxFuture<'static, Vec<Person>>>,
+}
+
+impl LazyDataProvider {
+    pub fn new() -> Self {
+        Self {
+            data: connect().boxed_local().shared(),
+        }
+    }
+}
+
+impl TableDataProvider<Person> for LazyDataProvider {
+    async fn get_rows(&self, range: Range<usize>) -> Result<(Vec<Person>, Range<usize>), String> {
+        let data = self.data.clone().await;
+
+        Ok(get_vec_range_clamped(&data, range))
+    }
+
+    async fn row_count(&self) -> Option<usize> {
+        Some(self.data.clone().await.len())
+    }
+}
+
+fn main() {
+    _ = console_log::init_with_level(log::Level::Debug);
+    console_error_panic_hook::set_once();
+
+    mount_to_body(|| {
+        let rows = LazyDataProvider::new();
+
+        view! {
+            <table>
+                <TableContent
+                    rows
+                    suspense_fallback=|col_count: usize| view! {
+                        <tr><td colspan=col_count>"Connecting..."</td></tr>
+                    }
+                />
+            </table>
+        }
+    })
+}
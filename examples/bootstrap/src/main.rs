@@ -49,8 +49,8 @@ fn main() {
         ];
 
         view! {
-            <div class="container".to_string()>
-                <table class="table-hover">
+            <div class="container">
+                <table class="table table-striped table-hover">
                     <TableContent rows />
                 </table>
             </div>
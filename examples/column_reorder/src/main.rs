@@ -0,0 +1,118 @@
+use chrono::NaiveDate;
+use leptos::*;
+use leptos_struct_table::*;
+
+const COLUMN_ORDER_KEY: &str = "column_reorder_example.column_order";
+
+#[derive(TableRow, Clone)]
+#[table(
+    sortable,
+    classes_provider = "BootstrapClassesPreset",
+    impl_vec_data_provider
+)]
+pub struct Book {
+    #[table(skip)]
+    pub id: u32,
+    pub title: String,
+    pub author: String,
+    pub publish_date: NaiveDate,
+    pub pages: u32,
+}
+
+fn load_column_order() -> Vec<usize> {
+    window()
+        .local_storage()
+        .ok()
+        .flatten()
+        .and_then(|storage| storage.get_item(COLUMN_ORDER_KEY).ok().flatten())
+        .map(|order| {
+            order
+                .split(',')
+                .filter_map(|col_index| col_index.parse().ok())
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+fn save_column_order(column_order: &[usize]) {
+    if let Ok(Some(storage)) = window().local_storage() {
+        let order = column_order
+            .iter()
+            .map(|col_index| col_index.to_string())
+            .collect::<Vec<_>>()
+            .join(",");
+
+        let _ = storage.set_item(COLUMN_ORDER_KEY, &order);
+    }
+}
+
+#[component]
+pub fn App() -> impl IntoView {
+    let rows = books();
+
+    let column_order = create_rw_signal(load_column_order());
+    let column_reorder_controller = ColumnReorderController::default();
+
+    let on_column_reorder = move |evt: ColumnReorderEvent| save_column_order(&evt.column_order);
+
+    let reset = move |_| {
+        column_order.set(vec![]);
+        save_column_order(&[]);
+    };
+
+    view! {
+        <div class="container">
+            <p class="my-3">
+                "Drag the column headers to reorder the columns. The order is kept when you reload the page. "
+                <button type="button" class="btn btn-sm btn-outline-secondary" on:click=reset>
+                    "Reset order"
+                </button>
+            </p>
+            <table class="table table-hover">
+                <TableContent rows column_order column_reorder_controller on_column_reorder />
+            </table>
+        </div>
+    }
+}
+
+fn books() -> Vec<Book> {
+    vec![
+        Book {
+            id: 1,
+            title: "The Great Gatsby".to_string(),
+            author: "F. Scott Fitzgerald".to_string(),
+            publish_date: NaiveDate::from_ymd_opt(1925, 4, 10).unwrap(),
+            pages: 218,
+        },
+        Book {
+            id: 2,
+            title: "The Grapes of Wrath".to_string(),
+            author: "John Steinbeck".to_string(),
+            publish_date: NaiveDate::from_ymd_opt(1939, 4, 14).unwrap(),
+            pages: 464,
+        },
+        Book {
+            id: 3,
+            title: "Nineteen Eighty-Four".to_string(),
+            author: "George Orwell".to_string(),
+            publish_date: NaiveDate::from_ymd_opt(1949, 6, 8).unwrap(),
+            pages: 328,
+        },
+        Book {
+            id: 4,
+            title: "Ulysses".to_string(),
+            author: "James Joyce".to_string(),
+            publish_date: NaiveDate::from_ymd_opt(1922, 2, 2).unwrap(),
+            pages: 730,
+        },
+    ]
+}
+
+fn main() {
+    _ = console_log::init_with_level(log::Level::Debug);
+    console_error_panic_hook::set_once();
+
+    mount_to_body(|| {
+        view! { <App/> }
+    })
+}
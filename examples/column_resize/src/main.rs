@@ -0,0 +1,120 @@
+use chrono::NaiveDate;
+use leptos::*;
+use leptos_struct_table::*;
+
+const COLUMN_WIDTHS_KEY: &str = "column_resize_example.column_widths";
+
+#[derive(TableRow, Clone)]
+#[table(
+    sortable,
+    classes_provider = "BootstrapClassesPreset",
+    impl_vec_data_provider
+)]
+pub struct Book {
+    #[table(skip)]
+    pub id: u32,
+    pub title: String,
+    pub author: String,
+    pub publish_date: NaiveDate,
+    pub pages: u32,
+}
+
+fn load_column_widths() -> Vec<Option<f64>> {
+    window()
+        .local_storage()
+        .ok()
+        .flatten()
+        .and_then(|storage| storage.get_item(COLUMN_WIDTHS_KEY).ok().flatten())
+        .map(|widths| widths.split(',').map(|width| width.parse().ok()).collect())
+        .unwrap_or_default()
+}
+
+fn save_column_widths(column_widths: &[Option<f64>]) {
+    if let Ok(Some(storage)) = window().local_storage() {
+        let widths = column_widths
+            .iter()
+            .map(|width| width.map(|width| width.to_string()).unwrap_or_default())
+            .collect::<Vec<_>>()
+            .join(",");
+
+        let _ = storage.set_item(COLUMN_WIDTHS_KEY, &widths);
+    }
+}
+
+#[component]
+pub fn App() -> impl IntoView {
+    let rows = books();
+
+    // the title needs some room, the number of pages doesn't and the date always fits
+    let column_resize_controller = ColumnResizeController::default()
+        .min_width(0, 120.0)
+        .max_width(3, 150.0)
+        .fixed(2);
+
+    column_resize_controller.widths.set(load_column_widths());
+
+    create_effect(move |_| {
+        column_resize_controller
+            .widths
+            .with(|widths| save_column_widths(widths));
+    });
+
+    let reset = move |_| column_resize_controller.widths.set(vec![]);
+
+    view! {
+        <div class="container">
+            <p class="my-3">
+                "Drag the right edge of the column headers to resize the columns. Double click it to reset a single column. "
+                "The widths are kept when you reload the page. "
+                <button type="button" class="btn btn-sm btn-outline-secondary" on:click=reset>
+                    "Reset widths"
+                </button>
+            </p>
+            <table class="table table-hover">
+                <TableContent rows column_resize_controller />
+            </table>
+        </div>
+    }
+}
+
+fn books() -> Vec<Book> {
+    vec![
+        Book {
+            id: 1,
+            title: "The Great Gatsby".to_string(),
+            author: "F. Scott Fitzgerald".to_string(),
+            publish_date: NaiveDate::from_ymd_opt(1925, 4, 10).unwrap(),
+            pages: 218,
+        },
+        Book {
+            id: 2,
+            title: "The Grapes of Wrath".to_string(),
+            author: "John Steinbeck".to_string(),
+            publish_date: NaiveDate::from_ymd_opt(1939, 4, 14).unwrap(),
+            pages: 464,
+        },
+        Book {
+            id: 3,
+            title: "Nineteen Eighty-Four".to_string(),
+            author: "George Orwell".to_string(),
+            publish_date: NaiveDate::from_ymd_opt(1949, 6, 8).unwrap(),
+            pages: 328,
+        },
+        Book {
+            id: 4,
+            title: "Ulysses".to_string(),
+            author: "James Joyce".to_string(),
+            publish_date: NaiveDate::from_ymd_opt(1922, 2, 2).unwrap(),
+            pages: 730,
+        },
+    ]
+}
+
+fn main() {
+    _ = console_log::init_with_level(log::Level::Debug);
+    console_error_panic_hook::set_once();
+
+    mount_to_body(|| {
+        view! { <App/> }
+    })
+}
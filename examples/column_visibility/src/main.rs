@@ -0,0 +1,117 @@
+use chrono::NaiveDate;
+use leptos::*;
+use leptos_struct_table::*;
+
+#[derive(TableRow, Clone)]
+#[table(
+    sortable,
+    classes_provider = "BootstrapClassesPreset",
+    impl_vec_data_provider
+)]
+pub struct Employee {
+    #[table(skip)]
+    pub id: u32,
+    pub name: String,
+    pub department: String,
+    pub hired: NaiveDate,
+    #[table(format(precision = 2))]
+    pub salary: f64,
+}
+
+#[component]
+pub fn App() -> impl IntoView {
+    let rows = employees();
+
+    // e.g. from the session of the user
+    let is_admin = false;
+
+    // only admins see the salaries initially
+    let column_visibility = create_rw_signal(column_visibility::<Employee>(|_, col_name| {
+        is_admin || col_name != "salary"
+    }));
+
+    let is_visible = move |col_index: usize| {
+        column_visibility.with(|visibility| visibility.get(col_index).copied().unwrap_or(true))
+    };
+
+    let toggle = move |col_index: usize| {
+        column_visibility.update(|visibility| {
+            if visibility.len() <= col_index {
+                visibility.resize(col_index + 1, true);
+            }
+            visibility[col_index] = !visibility[col_index];
+        })
+    };
+
+    let column_chooser = (0..Employee::COLUMN_COUNT)
+        .map(|col_index| {
+            view! {
+                <li>
+                    <label class="dropdown-item">
+                        <input
+                            type="checkbox"
+                            class="form-check-input me-2"
+                            prop:checked=move || is_visible(col_index)
+                            on:change=move |_| toggle(col_index)
+                        />
+                        {Employee::col_name(col_index)}
+                    </label>
+                </li>
+            }
+        })
+        .collect_view();
+
+    view! {
+        <div class="container">
+            <details class="column-chooser my-3">
+                <summary class="btn btn-sm btn-outline-secondary">"Columns"</summary>
+                <ul class="dropdown-menu show">{column_chooser}</ul>
+            </details>
+            <table class="table table-hover">
+                <TableContent rows column_visibility />
+            </table>
+        </div>
+    }
+}
+
+fn employees() -> Vec<Employee> {
+    vec![
+        Employee {
+            id: 1,
+            name: "Ada Lovelace".to_string(),
+            department: "Research".to_string(),
+            hired: NaiveDate::from_ymd_opt(2019, 3, 1).unwrap(),
+            salary: 98000.0,
+        },
+        Employee {
+            id: 2,
+            name: "Grace Hopper".to_string(),
+            department: "Engineering".to_string(),
+            hired: NaiveDate::from_ymd_opt(2017, 9, 15).unwrap(),
+            salary: 105000.0,
+        },
+        Employee {
+            id: 3,
+            name: "Alan Turing".to_string(),
+            department: "Research".to_string(),
+            hired: NaiveDate::from_ymd_opt(2020, 1, 6).unwrap(),
+            salary: 92000.0,
+        },
+        Employee {
+            id: 4,
+            name: "Margaret Hamilton".to_string(),
+            department: "Engineering".to_string(),
+            hired: NaiveDate::from_ymd_opt(2018, 6, 11).unwrap(),
+            salary: 110000.0,
+        },
+    ]
+}
+
+fn main() {
+    _ = console_log::init_with_level(log::Level::Debug);
+    console_error_panic_hook::set_once();
+
+    mount_to_body(|| {
+        view! { <App/> }
+    })
+}
@@ -117,19 +117,10 @@ pub fn SvgHeadCellRenderer<F>(
 where
     F: Fn(TableHeadEvent) + 'static,
 {
-    let style = move || {
-        let sort = match sort_direction() {
-            ColumnSort::Ascending => "--sort-icon: '▲';",
-            ColumnSort::Descending => "--sort-icon: '▼';",
-            ColumnSort::None => "--sort-icon: '';",
-        };
-
-        let priority = match sort_priority() {
-            Some(priority) => format!("--sort-priority: '{}';", priority + 1),
-            None => "--sort-priority: '';".to_string(),
-        };
-
-        format!("{} {}", sort, &priority)
+    let style = move || match sort_direction() {
+        ColumnSort::Ascending => "--sort-icon: '▲';",
+        ColumnSort::Descending => "--sort-icon: '▼';",
+        ColumnSort::None => "--sort-icon: '';",
     };
 
     let transform = transform_from_index(index, 0);
@@ -147,6 +138,16 @@ where
         >
             <text x="0" y=ROW_HEIGHT_HALF class=inner_class dominant-baseline="central">
                 {children()}
+                {move || {
+                    sort_priority()
+                        .map(|priority| {
+                            view! {
+                                <tspan dx="4" font-size="0.625em" opacity="0.6">
+                                    {priority + 1}
+                                </tspan>
+                            }
+                        })
+                }}
             </text>
         </g>
     }
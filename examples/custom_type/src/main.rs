@@ -20,6 +20,31 @@ pub struct Book {
     /// Description of the book. Optional.
     #[table(none_value = "-")]
     pub description: Option<String>,
+    /// Price of the book. Rendered by the renderer registered for `Money` below.
+    pub price: Money,
+}
+
+/// Amount of money in cents.
+#[derive(Clone, Copy, PartialEq, PartialOrd)]
+pub struct Money(pub i64);
+
+impl IntoView for Money {
+    fn into_view(self) -> View {
+        format!("{}.{:02}", self.0 / 100, self.0 % 100).into_view()
+    }
+}
+
+/// Renders all fields of type `Money` right aligned with a currency sign.
+#[component]
+pub fn MoneyTableCellRenderer(
+    /// The class attribute for the cell element. Generated by the classes provider.
+    class: String,
+    /// The value to display.
+    value: MaybeSignal<Money>,
+) -> impl IntoView {
+    view! {
+        <td class=class style="text-align: right">{value} " €"</td>
+    }
 }
 
 /// New-type pattern because otherwise the impl TableRow doesn't work because of orphan rules.
@@ -39,27 +64,38 @@ fn main() {
                 description: Some(
                     "A story of wealth, love, and the American Dream in the 1920s.".to_string(),
                 ),
+                price: Money(1299),
             })),
             RcBook(Rc::new(Book {
                 title: "The Grapes of Wrath".to_string(),
                 author: "John Steinbeck".to_string(),
                 publish_date: Some(NaiveDate::from_ymd_opt(1939, 4, 14).unwrap()),
                 description: None,
+                price: Money(999),
             })),
             RcBook(Rc::new(Book {
                 title: "Nineteen Eighty-Four".to_string(),
                 author: "George Orwell".to_string(),
                 publish_date: Some(NaiveDate::from_ymd_opt(1949, 6, 8).unwrap()),
                 description: None,
+                price: Money(1450),
             })),
             RcBook(Rc::new(Book {
                 title: "Ulysses".to_string(),
                 author: "James Joyce".to_string(),
                 publish_date: Some(NaiveDate::from_ymd_opt(1922, 2, 2).unwrap()),
                 description: None,
+                price: Money(2100),
             })),
         ];
 
+        // every `Money` field of every table below uses this renderer without a `renderer` attribute
+        provide_context(TypeCellRenderers::new().register(
+            |class, value: MaybeSignal<Money>, _index| {
+                view! { <MoneyTableCellRenderer class value /> }
+            },
+        ));
+
         view! {
             <table>
                 <TableContent rows/>
@@ -0,0 +1,99 @@
+//! Pretend this is a server that decides which columns to show.
+
+use gloo_timers::future::TimeoutFuture;
+use leptos_struct_table::{CellValue, ColumnDef, ColumnSort};
+use serde::{Deserialize, Serialize};
+use std::ops::Range;
+
+#[derive(Serialize, Deserialize)]
+pub struct Response {
+    pub columns: Vec<ColumnDef>,
+    pub rows: Vec<Vec<CellValue>>,
+    pub total: usize,
+}
+
+fn column(name: &str, title: &str, kind: &str) -> serde_json::Value {
+    serde_json::json!({ "name": name, "title": title, "kind": kind, "sortable": true })
+}
+
+fn schema_and_data(resource: &str) -> (Vec<serde_json::Value>, Vec<serde_json::Value>) {
+    match resource {
+        "products" => (
+            vec![
+                column("sku", "SKU", "Text"),
+                column("price", "Price", "Number"),
+                column("in_stock", "In Stock", "Bool"),
+            ],
+            (1..=200)
+                .map(|i| {
+                    serde_json::json!([
+                        format!("P-{i:04}"),
+                        (i * 37 % 1000) as f64 / 10.0,
+                        i % 3 != 0
+                    ])
+                })
+                .collect(),
+        ),
+        _ => (
+            vec![
+                column("id", "ID", "Number"),
+                column("name", "Name", "Text"),
+                column("email", "E-Mail", "Text"),
+                column("team", "Team", "Text"),
+            ],
+            (1..=500)
+                .map(|i| {
+                    serde_json::json!([
+                        i,
+                        format!("User {i}"),
+                        format!("user{i}@example.com"),
+                        if i % 4 == 0 {
+                            serde_json::Value::Null
+                        } else {
+                            serde_json::json!(["Red", "Green", "Blue"][i % 3])
+                        },
+                    ])
+                })
+                .collect(),
+        ),
+    }
+}
+
+/// Returns the JSON the server would send for a request of `range` rows of `resource`
+/// sorted by the column called `sort_by`.
+pub async fn fetch(
+    resource: &str,
+    range: Range<usize>,
+    sort_by: Option<(String, ColumnSort)>,
+) -> String {
+    TimeoutFuture::new(300).await;
+
+    let (columns, mut rows) = schema_and_data(resource);
+
+    if let Some((name, sort)) = sort_by {
+        if let Some(col_index) = columns.iter().position(|column| column["name"] == name) {
+            rows.sort_by(|a, b| {
+                let a =
+                    serde_json::from_value::<CellValue>(a[col_index].clone()).unwrap_or_default();
+                let b =
+                    serde_json::from_value::<CellValue>(b[col_index].clone()).unwrap_or_default();
+                let ordering = a.partial_cmp(&b).unwrap_or(std::cmp::Ordering::Equal);
+
+                if sort == ColumnSort::Descending {
+                    ordering.reverse()
+                } else {
+                    ordering
+                }
+            });
+        }
+    }
+
+    let total = rows.len();
+    let rows = rows
+        .into_iter()
+        .skip(range.start)
+        .take(range.len())
+        .collect::<Vec<_>>();
+
+    serde_json::json!({ "columns": columns, "rows": rows, "total": total }).to_string()
+}
@@ -0,0 +1,87 @@
+mod backend;
+
+use leptos::*;
+use leptos_struct_table::*;
+use std::collections::VecDeque;
+use std::ops::Range;
+
+/// Loads rows and column definitions of `resource` from the backend.
+pub struct BackendDataProvider {
+    resource: Signal<&'static str>,
+    columns: DynamicColumns,
+    sort_by: Option<(String, ColumnSort)>,
+}
+
+impl TableDataProvider<DynamicRow> for BackendDataProvider {
+    async fn get_rows(
+        &self,
+        range: Range<usize>,
+    ) -> Result<(Vec<DynamicRow>, Range<usize>), String> {
+        let json = backend::fetch(
+            self.resource.get_untracked(),
+            range.clone(),
+            self.sort_by.clone(),
+        )
+        .await;
+
+        let response =
+            serde_json::from_str::<backend::Response>(&json).map_err(|e| e.to_string())?;
+
+        // the backend decides which columns there are
+        self.columns.set(response.columns);
+
+        let rows = response
+            .rows
+            .into_iter()
+            .map(DynamicRow::new)
+            .collect::<Vec<_>>();
+        let len = rows.len();
+
+        Ok((rows, range.start..range.start + len))
+    }
+
+    fn set_sorting(&mut self, sorting: &VecDeque<(usize, ColumnSort)>) {
+        // the column indices refer to the current columns so map them to names for the backend
+        self.sort_by = sorting.front().and_then(|(col_index, sort)| {
+            self.columns.col_name(*col_index).map(|name| (name, *sort))
+        });
+    }
+
+    fn track(&self) {
+        self.resource.track();
+    }
+}
+
+fn main() {
+    _ = console_log::init_with_level(log::Level::Debug);
+    console_error_panic_hook::set_once();
+
+    mount_to_body(|| {
+        let columns = DynamicColumns::default();
+        provide_context(columns);
+
+        let (resource, set_resource) = create_signal("users");
+        let sorting = create_rw_signal(VecDeque::new());
+
+        let show = move |new_resource: &'static str| {
+            // the sorting refers to the columns of the previous resource
+            sorting.set(VecDeque::new());
+            set_resource.set(new_resource);
+        };
+
+        let rows = BackendDataProvider {
+            resource: resource.into(),
+            columns,
+            sort_by: None,
+        };
+
+        view! {
+            <button on:click=move |_| show("users")>"Users"</button>
+            <button on:click=move |_| show("products")>"Products"</button>
+
+            <table>
+                <TableContent rows sorting />
+            </table>
+        }
+    })
+}
@@ -5,6 +5,7 @@ use crate::renderer::*;
 use chrono::NaiveDate;
 use leptos::*;
 use leptos_struct_table::*;
+use std::fmt::{Display, Formatter};
 use std::ops::Range;
 use tailwind::TailwindClassesPreset;
 
@@ -15,9 +16,36 @@ pub struct Book {
     pub id: u32,
     #[table(renderer = "InputCellRenderer")]
     pub title: String,
-    #[table(renderer = "InputCellRenderer")]
+    #[table(renderer = "TextInputTableCellRenderer")]
     pub author: String,
+    #[table(renderer = "NaiveDateInputTableCellRenderer")]
     pub publish_date: NaiveDate,
+    #[table(renderer = "NumberInputTableCellRenderer")]
+    pub pages: u32,
+    #[table(renderer = "CheckboxInputTableCellRenderer")]
+    pub available: bool,
+    #[table(renderer = "SelectTableCellRenderer")]
+    pub genre: Genre,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum Genre {
+    Novel,
+    Dystopia,
+    Modernism,
+}
+
+impl Display for Genre {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+// Makes the genre editable with a `<select>`
+impl SelectOptions for Genre {
+    fn options() -> Vec<Self> {
+        vec![Genre::Novel, Genre::Dystopia, Genre::Modernism]
+    }
 }
 
 impl TableDataProvider<Book> for RwSignal<Vec<Book>> {
@@ -41,24 +69,36 @@ fn main() {
                 title: "The Great Gatsby".to_string(),
                 author: "F. Scott Fitzgerald".to_string(),
                 publish_date: NaiveDate::from_ymd_opt(1925, 4, 10).unwrap(),
+                pages: 180,
+                available: true,
+                genre: Genre::Novel,
             },
             Book {
                 id: 2,
                 title: "The Grapes of Wrath".to_string(),
                 author: "John Steinbeck".to_string(),
                 publish_date: NaiveDate::from_ymd_opt(1939, 4, 14).unwrap(),
+                pages: 464,
+                available: false,
+                genre: Genre::Novel,
             },
             Book {
                 id: 3,
                 title: "Nineteen Eighty-Four".to_string(),
                 author: "George Orwell".to_string(),
                 publish_date: NaiveDate::from_ymd_opt(1949, 6, 8).unwrap(),
+                pages: 328,
+                available: true,
+                genre: Genre::Dystopia,
             },
             Book {
                 id: 4,
                 title: "Ulysses".to_string(),
                 author: "James Joyce".to_string(),
                 publish_date: NaiveDate::from_ymd_opt(1922, 2, 2).unwrap(),
+                pages: 730,
+                available: false,
+                genre: Genre::Modernism,
             },
         ]);
 
@@ -68,10 +108,16 @@ fn main() {
             });
         };
 
+        let table_controller = TableController::default();
+
         view! {
+            <button class="m-10 mb-0 px-3 py-1 rounded-md border dark:border-gray-700" on:click=move |_| table_controller.edit_cell(3, "title")>
+                "Edit title of \"Ulysses\""
+            </button>
+
             <div class="rounded-md overflow-clip m-10 border dark:border-gray-700 w-[50%]".to_string()>
                 <table class="text-sm text-left text-gray-500 dark:text-gray-400 mb-[-1px]">
-                    <TableContent rows on_change/>
+                    <TableContent rows on_change table_controller/>
                 </table>
             </div>
 
@@ -1,4 +1,6 @@
+use leptos::html::Input;
 use leptos::*;
+use leptos_struct_table::*;
 
 /// A renderer that shows an <input> tag and emits the `on_change` event when the <input> is changed.
 #[component]
@@ -17,9 +19,21 @@ pub fn InputCellRenderer<F>(
 where
     F: Fn(String) + 'static,
 {
+    let input_ref = create_node_ref::<Input>();
+
+    // focus the input when this cell is opened through `TableController::edit_cell`
+    let editing = use_cell_editing(index);
+    create_effect(move |_| {
+        if editing.get() {
+            if let Some(input) = input_ref.get() {
+                let _ = input.focus();
+            }
+        }
+    });
+
     view! {
         <td class=class>
-            <input type="text" value=value on:change=move |evt| { on_change(event_target_value(&evt)); } />
+            <input type="text" node_ref=input_ref value=value on:change=move |evt| { on_change(event_target_value(&evt)); } />
         </td>
     }
 }
@@ -0,0 +1,142 @@
+use leptos::*;
+use leptos_struct_table::*;
+use std::collections::HashSet;
+
+#[derive(TableRow, Clone)]
+#[table(sortable, classes_provider = "BootstrapClassesPreset", impl_vec_data_provider)]
+pub struct Customer {
+    #[table(title = "#")]
+    pub id: u32,
+    pub name: String,
+    pub email: String,
+    pub city: String,
+    #[table(title = "Orders")]
+    pub order_count: FieldGetter<usize>,
+    #[table(skip)]
+    pub orders: Vec<Order>,
+}
+
+impl Customer {
+    pub fn order_count(&self) -> usize {
+        self.orders.len()
+    }
+}
+
+#[derive(Clone)]
+pub struct Order {
+    pub id: u32,
+    pub product: String,
+    pub quantity: u32,
+    pub total: f64,
+}
+
+/// Renders the orders of an expanded customer as a nested table.
+fn order_table(customer: Customer, _index: usize) -> impl IntoView {
+    let orders = customer
+        .orders
+        .into_iter()
+        .map(|order| {
+            view! {
+                <tr>
+                    <td>{order.id}</td>
+                    <td>{order.product}</td>
+                    <td class="text-end">{order.quantity}</td>
+                    <td class="text-end">{format!("{:.2}", order.total)}</td>
+                </tr>
+            }
+        })
+        .collect_view();
+
+    view! {
+        <table class="table table-sm mb-0">
+            <thead>
+                <tr>
+                    <th>"Order"</th>
+                    <th>"Product"</th>
+                    <th class="text-end">"Quantity"</th>
+                    <th class="text-end">"Total"</th>
+                </tr>
+            </thead>
+            <tbody>{orders}</tbody>
+        </table>
+    }
+}
+
+#[component]
+pub fn App() -> impl IntoView {
+    let rows = customers();
+    let selected_indices = create_rw_signal(HashSet::new());
+    let expanded = create_rw_signal(HashSet::new());
+
+    view! {
+        <div class="container">
+            <button
+                class="btn btn-sm btn-outline-secondary my-3"
+                on:click=move |_| expanded.set(HashSet::new())
+            >
+                "Collapse all"
+            </button>
+            <table class="table table-hover">
+                <TableContent
+                    rows
+                    selection=Selection::Multiple(selected_indices)
+                    expanded_row_renderer=order_table
+                    expanded
+                />
+            </table>
+        </div>
+    }
+}
+
+fn customers() -> Vec<Customer> {
+    let names = [
+        "Ada Lovelace",
+        "Grace Hopper",
+        "Alan Turing",
+        "Margaret Hamilton",
+        "Konrad Zuse",
+    ];
+    let cities = ["London", "New York", "Manchester", "Boston", "Berlin"];
+    let products = ["Keyboard", "Monitor", "Mouse", "Laptop Stand", "USB-C Hub"];
+
+    (0..200)
+        .map(|i| {
+            let name = names[i % names.len()];
+
+            let orders = (0..i % 4 + 1)
+                .map(|j| {
+                    let quantity = ((i + j) % 3 + 1) as u32;
+
+                    Order {
+                        id: 5000 + (i * 4 + j) as u32,
+                        product: products[(i + j) % products.len()].to_string(),
+                        quantity,
+                        total: quantity as f64 * 24.99,
+                    }
+                })
+                .collect();
+
+            Customer {
+                id: 1 + i as u32,
+                name: name.to_string(),
+                email: format!(
+                    "{}{}@example.com",
+                    name.split(' ').next().unwrap().to_lowercase(),
+                    i
+                ),
+                city: cities[i % cities.len()].to_string(),
+                order_count: Default::default(),
+                orders,
+            }
+        })
+        .collect()
+}
+
+fn main() {
+    _ = console_log::init_with_level(log::Level::Debug);
+    console_error_panic_hook::set_once();
+
+    mount_to_body(|| {
+        view! { <App/> }
+    })
+}
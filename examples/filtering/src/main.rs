@@ -0,0 +1,174 @@
+use chrono::NaiveDate;
+use leptos::*;
+use leptos_struct_table::*;
+use std::collections::{HashMap, VecDeque};
+use std::ops::Range;
+
+#[derive(TableRow, Clone)]
+#[table(
+    sortable,
+    classes_provider = "BootstrapClassesPreset",
+    impl_vec_data_provider
+)]
+pub struct Book {
+    pub id: u32,
+    pub title: String,
+    pub author: String,
+    #[table(format(string = "%Y"))]
+    pub publish_date: NaiveDate,
+}
+
+// The filters are matched against these values and not against the rendered cells.
+impl TableRowExport for Book {
+    fn col_value(&self, col_index: usize) -> Option<String> {
+        match col_index {
+            0 => Some(self.id.to_string()),
+            1 => Some(self.title.clone()),
+            2 => Some(self.author.clone()),
+            3 => Some(self.publish_date.format("%Y").to_string()),
+            _ => None,
+        }
+    }
+}
+
+/// Stands in for a data provider that loads the rows from a database on the server.
+pub struct ServerBookDataProvider {
+    books: Vec<Book>,
+    filters: HashMap<usize, String>,
+}
+
+impl ServerBookDataProvider {
+    /// The `WHERE` clause a server backed data provider would send to the database.
+    fn where_clause(filters: &HashMap<usize, String>) -> Option<String> {
+        let mut conditions = filters
+            .iter()
+            .map(|(col_index, value)| {
+                format!(
+                    "{} LIKE '%{}%'",
+                    Book::col_name(*col_index),
+                    value.replace('\'', "''")
+                )
+            })
+            .collect::<Vec<_>>();
+
+        if conditions.is_empty() {
+            return None;
+        }
+
+        conditions.sort();
+
+        Some(format!("WHERE {}", conditions.join(" AND ")))
+    }
+
+    fn query(&self) -> Vec<Book> {
+        self.books
+            .iter()
+            .filter(|book| row_matches_filters(*book, &self.filters))
+            .cloned()
+            .collect()
+    }
+}
+
+impl TableDataProvider<Book> for ServerBookDataProvider {
+    async fn get_rows(&self, range: Range<usize>) -> Result<(Vec<Book>, Range<usize>), String> {
+        Ok(get_vec_range_clamped(&self.query(), range))
+    }
+
+    async fn row_count(&self) -> Option<usize> {
+        Some(self.query().len())
+    }
+
+    fn set_sorting(&mut self, sorting: &VecDeque<(usize, ColumnSort)>) {
+        self.books.set_sorting(sorting);
+    }
+
+    fn set_filters(&mut self, filters: &HashMap<usize, String>) {
+        self.filters = filters.clone();
+    }
+}
+
+#[component]
+pub fn App() -> impl IntoView {
+    let local_rows = FilteredVecDataProvider::new(books());
+    let server_rows = ServerBookDataProvider {
+        books: books(),
+        filters: HashMap::new(),
+    };
+
+    let search = create_rw_signal(String::new());
+
+    let (where_clause, set_where_clause) = create_signal(None::<String>);
+
+    let on_filter_change = move |evt: FilterChangeEvent| {
+        set_where_clause.set(ServerBookDataProvider::where_clause(&evt.filters));
+    };
+
+    view! {
+        <div class="container">
+            <h2 class="h5 mt-3">"Local data"</h2>
+            <input
+                type="search"
+                class="form-control mb-2"
+                placeholder="Search all columns"
+                prop:value=search
+                on:input=move |evt| search.set(event_target_value(&evt))
+            />
+            <table class="table table-hover">
+                <TableContent
+                    rows=local_rows
+                    filterable=true
+                    search
+                    empty_row_renderer=DefaultEmptyRowRenderer
+                />
+            </table>
+
+            <h2 class="h5 mt-3">"Async data provider"</h2>
+            <p>
+                <code>
+                    {move || where_clause.get().unwrap_or_else(|| "-- no filters".to_string())}
+                </code>
+            </p>
+            <table class="table table-hover">
+                <TableContent rows=server_rows filterable=true on_filter_change />
+            </table>
+        </div>
+    }
+}
+
+fn books() -> Vec<Book> {
+    vec![
+        Book {
+            id: 1,
+            title: "The Great Gatsby".to_string(),
+            author: "F. Scott Fitzgerald".to_string(),
+            publish_date: NaiveDate::from_ymd_opt(1925, 4, 10).unwrap(),
+        },
+        Book {
+            id: 2,
+            title: "The Grapes of Wrath".to_string(),
+            author: "John Steinbeck".to_string(),
+            publish_date: NaiveDate::from_ymd_opt(1939, 4, 14).unwrap(),
+        },
+        Book {
+            id: 3,
+            title: "Nineteen Eighty-Four".to_string(),
+            author: "George Orwell".to_string(),
+            publish_date: NaiveDate::from_ymd_opt(1949, 6, 8).unwrap(),
+        },
+        Book {
+            id: 4,
+            title: "Ulysses".to_string(),
+            author: "James Joyce".to_string(),
+            publish_date: NaiveDate::from_ymd_opt(1922, 2, 2).unwrap(),
+        },
+    ]
+}
+
+fn main() {
+    _ = console_log::init_with_level(log::Level::Debug);
+    console_error_panic_hook::set_once();
+
+    mount_to_body(|| {
+        view! { <App/> }
+    })
+}
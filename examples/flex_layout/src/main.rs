@@ -0,0 +1,65 @@
+mod renderers;
+
+use leptos::html::Div;
+use leptos::*;
+use leptos_struct_table::*;
+use renderers::*;
+
+// This generates the component PersonTable
+#[derive(TableRow, Clone)]
+#[table(
+    thead_cell_renderer = "DivHeadCellRenderer",
+    sortable,
+    impl_vec_data_provider
+)]
+pub struct Person {
+    #[table(renderer = "DivCellRenderer")]
+    pub id: u32,
+    #[table(renderer = "DivCellRenderer")]
+    pub name: String,
+    #[table(renderer = "DivCellRenderer")]
+    pub city: String,
+}
+
+fn main() {
+    _ = console_log::init_with_level(log::Level::Debug);
+    console_error_panic_hook::set_once();
+
+    mount_to_body(|| {
+        let cities = ["Berlin", "Lisbon", "Oslo", "Rome", "Vienna"];
+
+        let rows = (1..=1000)
+            .map(|id| Person {
+                id,
+                name: format!("Person {id}"),
+                city: cities[id as usize % cities.len()].to_string(),
+            })
+            .collect::<Vec<_>>();
+
+        let scroll_container = create_node_ref::<Div>();
+
+        view! {
+            <div style="display: flex; flex-direction: column; height: 100vh; font-family: sans-serif;">
+                <h1>"Flexbox table"</h1>
+                <div
+                    node_ref=scroll_container
+                    style="flex-grow: 1; min-height: 0; overflow: auto; border: 1px solid #ddd;"
+                >
+                    <div role="table" style="display: flex; flex-direction: column;">
+                        <TableContent
+                            rows
+                            scroll_container
+                            thead_renderer=DivRenderer
+                            thead_row_renderer=DivRowWrapperRenderer
+                            tbody_renderer=DivBodyRenderer
+                            row_renderer=DivRowRenderer
+                            loading_row_renderer=DivLoadingRowRenderer
+                            error_row_renderer=DivErrorRowRenderer
+                            row_placeholder_renderer=DivRowPlaceholderRenderer
+                        />
+                    </div>
+                </div>
+            </div>
+        }
+    })
+}
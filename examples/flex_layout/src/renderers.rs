@@ -0,0 +1,142 @@
+use leptos::html::{AnyElement, Div};
+use leptos::*;
+use leptos_struct_table::*;
+
+const ROW_STYLE: &str = "display: flex; flex-shrink: 0; height: 32px; align-items: center; border-bottom: 1px solid #eee;";
+const CELL_STYLE: &str = "flex: 1 1 0; padding: 0 8px;";
+
+wrapper_render_fn!(
+    /// div
+    DivRenderer,
+    div,
+    "rowgroup",
+);
+
+#[allow(non_snake_case)]
+pub fn DivRowWrapperRenderer(content: View, class: Signal<String>) -> impl IntoView {
+    view! { <div class=class role="row" style=ROW_STYLE>{content}</div> }
+}
+
+#[allow(non_snake_case)]
+pub fn DivBodyRenderer(
+    content: Fragment,
+    class: Signal<String>,
+    node_ref: NodeRef<AnyElement>,
+) -> impl IntoView {
+    let tbody_ref = create_node_ref::<Div>();
+    tbody_ref.on_load(move |e| node_ref.load(&e.into_any()));
+
+    view! {
+        <div class=class role="rowgroup" node_ref=tbody_ref style="display: flex; flex-direction: column;">
+            {content}
+        </div>
+    }
+}
+
+/// Replaces the rows that are not rendered. It has to be a direct child of the body element and
+/// has to be exactly `height` pixels high so the total height of the body stays the same as if all rows
+/// were rendered. This is why `flex-shrink` is turned off here.
+#[allow(non_snake_case)]
+pub fn DivRowPlaceholderRenderer(height: Signal<f64>) -> impl IntoView {
+    view! { <div style:height=move || format!("{}px", height.get()) style="flex-shrink: 0;" aria-hidden="true"></div> }
+}
+
+#[allow(unused_variables, non_snake_case)]
+pub fn DivRowRenderer<Row>(
+    class: Signal<String>,
+    row: Row,
+    index: usize,
+    selected: Signal<bool>,
+    on_select: EventHandler<web_sys::MouseEvent>,
+    on_change: EventHandler<ChangeEvent<Row>>,
+) -> impl IntoView
+where
+    Row: TableRow + Clone + 'static,
+{
+    view! {
+        <div
+            class=class
+            role="row"
+            aria-selected=move || selected.get().to_string()
+            style=ROW_STYLE
+            on:click=move |mouse_event| on_select.run(mouse_event)
+        >
+            {row.render_row(index, on_change)}
+        </div>
+    }
+}
+
+#[allow(unused_variables, non_snake_case, unstable_name_collisions)]
+pub fn DivLoadingRowRenderer(
+    class: Signal<String>,
+    get_cell_class: Callback<usize, String>,
+    get_inner_cell_class: Callback<usize, String>,
+    index: usize,
+    col_count: usize,
+) -> impl IntoView {
+    view! {
+        <div class=class role="row" style=ROW_STYLE>
+            {(0..col_count)
+                .map(|col_index| view! { <div class=get_cell_class.call(col_index) role="cell" style=CELL_STYLE>"…"</div> })
+                .collect_view()}
+        </div>
+    }
+}
+
+#[allow(unused_variables, non_snake_case)]
+pub fn DivErrorRowRenderer(err: String, index: usize, col_count: usize) -> impl IntoView {
+    view! {
+        <div role="row" style=ROW_STYLE>
+            <div role="cell">{err}</div>
+        </div>
+    }
+}
+
+#[component]
+#[allow(unused_variables)]
+pub fn DivHeadCellRenderer<F>(
+    #[prop(into)] class: Signal<String>,
+    #[prop(into)] inner_class: String,
+    index: usize,
+    #[prop(into)] sort_priority: Signal<Option<usize>>,
+    #[prop(into)] sort_direction: Signal<ColumnSort>,
+    on_click: F,
+    children: Children,
+) -> impl IntoView
+where
+    F: Fn(TableHeadEvent) + 'static,
+{
+    let sort_icon = move || match sort_direction() {
+        ColumnSort::Ascending => " ▲",
+        ColumnSort::Descending => " ▼",
+        ColumnSort::None => "",
+    };
+
+    view! {
+        <div
+            class=class
+            role="columnheader"
+            aria-sort=move || sort_direction().as_aria_sort()
+            style=format!("{CELL_STYLE} font-weight: bold; cursor: pointer;")
+            on:click=move |mouse_event| on_click(TableHeadEvent { index, mouse_event })
+        >
+            <span class=inner_class>{children()}</span>
+            {sort_icon}
+        </div>
+    }
+}
+
+#[component]
+#[allow(unused_variables)]
+pub fn DivCellRenderer<T, F>(
+    class: String,
+    #[prop(into)] value: MaybeSignal<T>,
+    on_change: F,
+    index: usize,
+) -> impl IntoView
+where
+    T: IntoView + Clone + 'static,
+    F: Fn(T) + 'static,
+{
+    view! { <div class=class role="cell" style=CELL_STYLE>{value}</div> }
+}
@@ -12,21 +12,40 @@ pub fn App() -> impl IntoView {
 
     let pagination_controller = PaginationController::default();
 
+    let (paginated, set_paginated) = create_signal(true);
+
+    // The display strategy can be switched at runtime without remounting the table
+    let display_strategy = Signal::derive(move || {
+        if paginated.get() {
+            DisplayStrategy::Pagination {
+                controller: pagination_controller,
+                page_size: PageSize::Fixed(10),
+            }
+        } else {
+            DisplayStrategy::Virtualization
+        }
+    });
+
     view! {
+        <label class="m-10 mb-0 flex items-center gap-2 text-sm text-gray-500 dark:text-gray-400">
+            <input
+                type="checkbox"
+                prop:checked=paginated
+                on:change=move |evt| set_paginated.set(event_target_checked(&evt))
+            />
+            "Paginated"
+        </label>
+
         <div class="rounded-md overflow-clip m-10 border dark:border-gray-700".to_string()>
             <table class="text-sm text-left text-gray-500 dark:text-gray-400 mb-[-1px] w-[calc(100vw-5rem)]">
-                <TableContent
-                    rows=rows
-                    display_strategy=DisplayStrategy::Pagination {
-                        controller: pagination_controller,
-                        row_count: 10,
-                    }
-                />
+                <TableContent rows=rows display_strategy />
 
             </table>
         </div>
 
-        <Paginator pagination_controller />
+        <Show when=paginated>
+            <Paginator pagination_controller />
+        </Show>
     }
 }
 
@@ -50,8 +69,30 @@ pub fn Paginator(pagination_controller: PaginationController) -> impl IntoView {
         start..end
     };
 
+    let page_size = pagination_controller.page_size();
+
     view! {
-        <nav aria-label="Page navigation example" class="m-10 flex justify-end">
+        <nav aria-label="Page navigation example" class="m-10 flex justify-end gap-4">
+            <select
+                class="h-8 px-2 text-sm text-gray-500 bg-white border border-gray-300 rounded-lg dark:bg-gray-800 dark:border-gray-700 dark:text-gray-400"
+                on:change=move |evt| {
+                    if let Ok(page_size) = event_target_value(&evt).parse() {
+                        pagination_controller.set_page_size(page_size);
+                    }
+                }
+            >
+                {[10, 25, 50]
+                    .into_iter()
+                    .map(|size| {
+                        view! {
+                            <option value=size selected=move || page_size() == Some(size)>
+                                {size}
+                                " / page"
+                            </option>
+                        }
+                    })
+                    .collect_view()}
+            </select>
             <ul class="inline-flex -space-x-px text-sm">
                 <li>
                     <a
@@ -111,7 +152,7 @@ pub fn PageLink(page: usize, pagination_controller: PaginationController) -> imp
                 on:click=move |evt| {
                     evt.prevent_default();
                     evt.stop_propagation();
-                    pagination_controller.current_page.set(page);
+                    pagination_controller.go_to(page);
                 }
             >
 
@@ -0,0 +1,73 @@
+use leptos::*;
+use leptos_struct_table::*;
+use leptos_use::use_debounce_fn_with_arg;
+use serde::Deserialize;
+
+// The API returns more fields than these. They are ignored when deserializing.
+#[derive(TableRow, Clone, Deserialize)]
+#[table(sortable, classes_provider = "BootstrapClassesPreset")]
+pub struct Product {
+    #[table(skip)]
+    pub id: u32,
+    pub title: String,
+    #[table(none_value = "-")]
+    pub brand: Option<String>,
+    pub category: String,
+    #[table(format(precision = 2))]
+    pub price: f64,
+    pub rating: f64,
+}
+
+#[component]
+pub fn App() -> impl IntoView {
+    let search = create_rw_signal(String::new());
+    let (error, set_error) = create_signal(None::<String>);
+
+    // DummyJSON calls the offset `skip`, sorts by `sortBy` and returns the rows as `products`
+    let rows = RestTableDataProvider::<Product>::new("https://dummyjson.com/products/search")
+        .offset_param("skip")
+        .sort_param("sortBy")
+        .data_field("products")
+        .params(Signal::derive(move || {
+            vec![("q".to_string(), search.get())]
+        }));
+
+    let on_input = use_debounce_fn_with_arg(move |value| search.set(value), 300.0);
+
+    let on_error = move |evt: LoadErrorEvent<RestError>| set_error(Some(evt.error.to_string()));
+
+    let table_controller = TableController::default();
+    let is_loading = table_controller.is_loading();
+
+    view! {
+        <div class="container">
+            <input
+                type="search"
+                class="form-control my-3"
+                placeholder="Search products"
+                prop:readonly=is_loading
+                on:input=move |evt| {
+                    on_input(event_target_value(&evt));
+                }
+            />
+            {move || error().map(|error| view! { <div class="alert alert-danger">{error}</div> })}
+            <table class="table table-hover">
+                <TableContent
+                    rows
+                    on_error
+                    table_controller
+                    loading_overlay_renderer=DefaultLoadingOverlayRenderer
+                />
+            </table>
+        </div>
+    }
+}
+
+fn main() {
+    _ = console_log::init_with_level(log::Level::Debug);
+    console_error_panic_hook::set_once();
+
+    mount_to_body(|| {
+        view! { <App/> }
+    })
+}
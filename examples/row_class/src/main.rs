@@ -0,0 +1,109 @@
+use chrono::NaiveDate;
+use leptos::*;
+use leptos_struct_table::*;
+
+#[derive(TableRow, Clone)]
+#[table(
+    sortable,
+    classes_provider = "BootstrapClassesPreset",
+    impl_vec_data_provider
+)]
+pub struct Invoice {
+    pub number: String,
+    pub customer: String,
+    pub due_date: NaiveDate,
+    pub amount: f64,
+    pub status: Status,
+}
+
+#[derive(Clone, Copy, PartialEq, PartialOrd)]
+pub enum Status {
+    Open,
+    Paid,
+    Overdue,
+}
+
+impl IntoView for Status {
+    fn into_view(self) -> View {
+        match self {
+            Status::Open => "Open",
+            Status::Paid => "Paid",
+            Status::Overdue => "Overdue",
+        }
+        .into_view()
+    }
+}
+
+impl Invoice {
+    // Passed to the `row_class_fn` prop. The zebra striping comes from `table-striped`.
+    fn row_class(&self, _index: usize) -> String {
+        match self.status {
+            Status::Open => "",
+            Status::Paid => "table-success",
+            Status::Overdue => "table-danger fw-bold",
+        }
+        .to_string()
+    }
+}
+
+#[component]
+pub fn App() -> impl IntoView {
+    let rows = invoices();
+
+    view! {
+        <div class="container">
+            <table class="table table-striped">
+                <TableContent rows row_class_fn=Invoice::row_class />
+            </table>
+        </div>
+    }
+}
+
+fn invoices() -> Vec<Invoice> {
+    vec![
+        Invoice {
+            number: "2024-001".to_string(),
+            customer: "Acme Corp.".to_string(),
+            due_date: NaiveDate::from_ymd_opt(2024, 1, 31).unwrap(),
+            amount: 1200.0,
+            status: Status::Paid,
+        },
+        Invoice {
+            number: "2024-002".to_string(),
+            customer: "Globex".to_string(),
+            due_date: NaiveDate::from_ymd_opt(2024, 2, 15).unwrap(),
+            amount: 340.5,
+            status: Status::Overdue,
+        },
+        Invoice {
+            number: "2024-003".to_string(),
+            customer: "Initech".to_string(),
+            due_date: NaiveDate::from_ymd_opt(2024, 3, 1).unwrap(),
+            amount: 99.99,
+            status: Status::Open,
+        },
+        Invoice {
+            number: "2024-004".to_string(),
+            customer: "Umbrella Corp.".to_string(),
+            due_date: NaiveDate::from_ymd_opt(2024, 3, 15).unwrap(),
+            amount: 5400.0,
+            status: Status::Open,
+        },
+        Invoice {
+            number: "2024-005".to_string(),
+            customer: "Hooli".to_string(),
+            due_date: NaiveDate::from_ymd_opt(2024, 2, 1).unwrap(),
+            amount: 780.0,
+            status: Status::Overdue,
+        },
+    ]
+}
+
+fn main() {
+    _ = console_log::init_with_level(log::Level::Debug);
+    console_error_panic_hook::set_once();
+
+    mount_to_body(|| {
+        view! { <App/> }
+    })
+}
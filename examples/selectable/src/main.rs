@@ -67,7 +67,7 @@ fn main() {
                         row_class="select-none"
                         on_selection_change={move |evt: SelectionChangeEvent<Book>| {
                             set_selected_row.update(|selected_row| {
-                                *selected_row = Some(evt.row);
+                                *selected_row = evt.row;
                             })
                         }}
                     />
@@ -28,7 +28,11 @@ impl TableClassesProvider for ClassesPreset {
     }
 
     fn thead_cell_inner(&self) -> String {
-        "flex items-center after:content-[--sort-icon] after:pl-1 after:opacity-40 before:content-[--sort-priority] before:order-last before:pl-0.5 before:font-light before:opacity-40".to_string()
+        "flex items-center after:content-[--sort-icon] after:pl-1 after:opacity-40".to_string()
+    }
+
+    fn thead_cell_sort_priority(&self) -> String {
+        "order-last pl-0.5 font-light opacity-40".to_string()
     }
 
     fn row(&self, row_index: usize, selected: bool, template_classes: &str) -> String {
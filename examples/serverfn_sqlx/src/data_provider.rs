@@ -36,7 +36,9 @@ pub async fn list_customers(query: CustomerQuery) -> Result<Vec<Customer>, Serve
 
     let CustomerQuery { sort, range, name } = query;
 
-    let mut query = QueryBuilder::new("SELECT customer_id, first_name, last_name, company, city, country, phone, email, website FROM customers");
+    let sql = SqlQuery::<Customer>::new("customers");
+
+    let mut query = QueryBuilder::new(format!("SELECT {} FROM customers", sql.columns()));
     if !name.is_empty() {
         query.push(" WHERE first_name LIKE concat('%', ");
         query.push_bind(&name);
@@ -47,14 +49,13 @@ pub async fn list_customers(query: CustomerQuery) -> Result<Vec<Customer>, Serve
         query.push(", '%')");
     }
 
-    if let Some(order) = Customer::sorting_to_sql(&sort) {
+    if let Some(order) = sql.order_by(&sort) {
+        query.push(" ");
         query.push(order);
     }
 
-    query.push(" LIMIT ");
-    query.push_bind(range.len() as i64);
-    query.push(" OFFSET ");
-    query.push_bind(range.start as i64);
+    query.push(" ");
+    query.push(sql.limit_offset(range));
 
     query
         .build_query_as::<Customer>()
@@ -67,7 +68,7 @@ pub async fn list_customers(query: CustomerQuery) -> Result<Vec<Customer>, Serve
 pub async fn customer_count() -> Result<usize, ServerFnError<String>> {
     use crate::database::get_db;
 
-    let count: i64 = sqlx::query("SELECT COUNT(*) FROM customers")
+    let count: i64 = sqlx::query(&SqlQuery::<Customer>::new("customers").count())
         .fetch_one(get_db())
         .await
         .map_err(|err| ServerFnError::WrappedServerError(format!("{err:?}")))?
@@ -0,0 +1,122 @@
+use leptos::*;
+use leptos_struct_table::*;
+
+#[derive(TableRow, Clone)]
+#[table(sortable, classes_provider = "BootstrapClassesPreset", impl_vec_data_provider)]
+pub struct Order {
+    #[table(title = "#")]
+    pub id: u32,
+    pub customer: String,
+    pub email: String,
+    pub product: String,
+    pub quantity: u32,
+    #[table(format(precision = 2))]
+    pub total: f64,
+    pub street: String,
+    pub city: String,
+    pub country: String,
+    pub status: String,
+    #[table(title = "", renderer = "ActionsCellRenderer", skip_sort)]
+    pub actions: FieldGetter<u32>,
+}
+
+impl Order {
+    pub fn actions(&self) -> u32 {
+        self.id
+    }
+}
+
+/// Renders the buttons of an order. Clicking them doesn't select the row.
+#[component]
+#[allow(unused_variables)]
+fn ActionsCellRenderer<F>(
+    class: String,
+    #[prop(into)] value: MaybeSignal<u32>,
+    on_change: F,
+    index: usize,
+) -> impl IntoView
+where
+    F: Fn(u32) + 'static,
+{
+    view! {
+        <td class=class>
+            <div class="btn-group btn-group-sm">
+                <button
+                    class="btn btn-outline-primary"
+                    on:click=move |_| logging::log!("Edit order {}", value.get_untracked())
+                >
+                    "Edit"
+                </button>
+                <button
+                    class="btn btn-outline-danger"
+                    on:click=move |_| logging::log!("Delete order {}", value.get_untracked())
+                >
+                    "Delete"
+                </button>
+            </div>
+        </td>
+    }
+}
+
+#[component]
+pub fn App() -> impl IntoView {
+    let rows = orders();
+    let selected_index = create_rw_signal(None);
+
+    view! {
+        <div class="container">
+            <div class="table-responsive my-3">
+                <table class="table table-hover">
+                    <TableContent
+                        rows
+                        selection=Selection::Single(selected_index)
+                        sticky_columns=vec![0]
+                        sticky_right_columns=vec![10]
+                    />
+                </table>
+            </div>
+        </div>
+    }
+}
+
+fn orders() -> Vec<Order> {
+    let customers = [
+        ("Ada Lovelace", "ada@example.com", "London", "United Kingdom"),
+        ("Grace Hopper", "grace@example.com", "New York", "United States"),
+        ("Alan Turing", "alan@example.com", "Manchester", "United Kingdom"),
+        ("Margaret Hamilton", "margaret@example.com", "Boston", "United States"),
+        ("Konrad Zuse", "konrad@example.com", "Berlin", "Germany"),
+    ];
+    let products = ["Keyboard", "Monitor", "Mouse", "Laptop Stand", "USB-C Hub"];
+    let statuses = ["Pending", "Shipped", "Delivered"];
+
+    (0..20)
+        .map(|i| {
+            let (customer, email, city, country) = customers[i % customers.len()];
+            let quantity = (i % 4 + 1) as u32;
+
+            Order {
+                id: 1001 + i as u32,
+                customer: customer.to_string(),
+                email: email.to_string(),
+                product: products[i % products.len()].to_string(),
+                quantity,
+                total: quantity as f64 * 24.99,
+                street: format!("{} Main Street", 10 + i),
+                city: city.to_string(),
+                country: country.to_string(),
+                status: statuses[i % statuses.len()].to_string(),
+                actions: Default::default(),
+            }
+        })
+        .collect()
+}
+
+fn main() {
+    _ = console_log::init_with_level(log::Level::Debug);
+    console_error_panic_hook::set_once();
+
+    mount_to_body(|| {
+        view! { <App/> }
+    })
+}
@@ -0,0 +1,428 @@
+use crate::loaded_rows::{LoadedRows, RowState};
+use crate::table_controller::RenderedRowIndex;
+use leptos::*;
+use std::any::{Any, TypeId};
+use std::collections::HashMap;
+use std::ops::Add;
+use std::rc::Rc;
+
+/// Provided by [`TableContent`] so that cell renderers can compute values across rows.
+#[derive(Clone)]
+pub(crate) struct LoadedRowsContext<Row: Clone + 'static> {
+    loaded_rows: RwSignal<LoadedRows<Row>>,
+    /// The `Memo<Vec<T>>`s of [`use_running_total`] by the type of their `value` closure so that
+    /// all cells of a column share them.
+    running_totals: StoredValue<HashMap<TypeId, Box<dyn Any>>>,
+    /// The owner of the table so that the memos outlive the cells that created them.
+    owner: Option<Owner>,
+}
+
+impl<Row: Clone + 'static> LoadedRowsContext<Row> {
+    pub(crate) fn new(loaded_rows: RwSignal<LoadedRows<Row>>) -> Self {
+        Self {
+            loaded_rows,
+            running_totals: store_value(HashMap::new()),
+            owner: Owner::current(),
+        }
+    }
+
+    /// The running totals of `value` for every row. Created on first use and shared afterwards.
+    fn running_totals<T, F>(&self, value: F) -> Memo<Vec<T>>
+    where
+        T: Add<Output = T> + Default + Clone + PartialEq + 'static,
+        F: Fn(&Row) -> T + 'static,
+    {
+        let existing = self.running_totals.with_value(|memos| {
+            memos
+                .get(&TypeId::of::<F>())
+                .and_then(|memo| memo.downcast_ref::<Memo<Vec<T>>>())
+                .copied()
+        });
+        if let Some(memo) = existing {
+            return memo;
+        }
+
+        let loaded_rows = self.loaded_rows;
+        let create = move || {
+            create_memo(move |_| {
+                loaded_rows.with(|loaded_rows| running_totals(loaded_rows, &value))
+            })
+        };
+        let memo = match self.owner {
+            Some(owner) => with_owner(owner, create),
+            None => create(),
+        };
+
+        self.running_totals.update_value(|memos| {
+            memos.insert(TypeId::of::<F>(), Box::new(memo));
+        });
+
+        memo
+    }
+}
+
+/// Returns a `Signal` of the running total of `value` for the current row, i.e. the sum of
+/// `value` over this row and all rows before it in the current sort order.
+///
+/// This is meant to be called inside of a cell renderer, typically for a [`FieldGetter`] column. It
+/// recomputes whenever rows are loaded or the table is reloaded, for example because the sorting changed.
+/// Outside of a [`TableContent`] this returns `T::default()`.
+///
+/// The totals of all rows are computed once and shared by all cells that call this with the same
+/// closure type, so `value` must only depend on the row.
+///
+/// > Please note that only rows that have been loaded are summed up. With data providers that load
+/// > rows in ranges, rows that have never been scrolled into view are not part of the total. If you
+/// > need exact totals for a server-side data source you should compute them there.
+///
+/// Example:
+///
+/// ```
+/// # use leptos::*;
+/// # use leptos_struct_table::*;
+/// #
+/// #[derive(TableRow, Clone)]
+/// #[table(impl_vec_data_provider)]
+/// pub struct Transaction {
+///     amount: f64,
+///     #[table(renderer = "BalanceCellRenderer")]
+///     balance: FieldGetter<f64>,
+/// }
+///
+/// impl Transaction {
+///     // Not used by the renderer below but the macro needs a getter for every `FieldGetter`
+///     pub fn balance(&self) -> f64 {
+///         self.amount
+///     }
+/// }
+///
+/// #[component]
+/// fn BalanceCellRenderer<F>(
+///     class: String,
+///     #[prop(into)] value: MaybeSignal<f64>,
+///     on_change: F,
+///     index: usize,
+/// ) -> impl IntoView
+/// where
+///     F: Fn(f64) + 'static,
+/// {
+///     let balance = use_running_total(|row: &Transaction| row.amount);
+///
+///     view! { <td class=class>{move || format!("{:.2}", balance.get())}</td> }
+/// }
+/// ```
+pub fn use_running_total<Row, T, F>(value: F) -> Signal<T>
+where
+    Row: Clone + 'static,
+    T: Add<Output = T> + Default + Clone + PartialEq + 'static,
+    F: Fn(&Row) -> T + 'static,
+{
+    match (
+        use_context::<LoadedRowsContext<Row>>(),
+        use_context::<RenderedRowIndex>(),
+    ) {
+        (Some(context), Some(RenderedRowIndex(row_index))) => {
+            let running_totals = context.running_totals(value);
+
+            Signal::derive(move || {
+                running_totals.with(|running_totals| running_total(running_totals, row_index))
+            })
+        }
+        _ => Signal::derive(T::default),
+    }
+}
+
+/// The sums of `value` over the loaded rows up to and including each row. Rows that aren't loaded
+/// (yet) are skipped.
+fn running_totals<Row, T>(loaded_rows: &LoadedRows<Row>, value: impl Fn(&Row) -> T) -> Vec<T>
+where
+    Row: Clone,
+    T: Add<Output = T> + Default + Clone,
+{
+    let mut total = T::default();
+
+    loaded_rows[0..loaded_rows.len()]
+        .iter()
+        .map(|row| {
+            if let RowState::Loaded(row) = row {
+                total = total.clone() + value(row);
+            }
+            total.clone()
+        })
+        .collect()
+}
+
+/// The running total of the row at `row_index`. Rows after the cached ones get the total of all rows.
+fn running_total<T: Default + Clone>(running_totals: &[T], row_index: usize) -> T {
+    running_totals
+        .get(row_index)
+        .or(running_totals.last())
+        .cloned()
+        .unwrap_or_default()
+}
+
+/// How the values of a column are combined by [`ColumnAggregates`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Aggregate {
+    Sum,
+    Avg,
+    Min,
+    Max,
+    /// The number of rows that have a value.
+    Count,
+}
+
+impl Aggregate {
+    fn compute(&self, values: impl Iterator<Item = f64>) -> Option<f64> {
+        let mut count = 0usize;
+        let mut result = None::<f64>;
+
+        for value in values {
+            count += 1;
+            result = Some(match (self, result) {
+                (_, None) => value,
+                (Aggregate::Sum | Aggregate::Avg, Some(result)) => result + value,
+                (Aggregate::Min, Some(result)) => result.min(value),
+                (Aggregate::Max, Some(result)) => result.max(value),
+                (Aggregate::Count, Some(result)) => result,
+            });
+        }
+
+        match self {
+            Aggregate::Sum => Some(result.unwrap_or_default()),
+            Aggregate::Avg => result.map(|sum| sum / count as f64),
+            Aggregate::Min | Aggregate::Max => result,
+            Aggregate::Count => Some(count as f64),
+        }
+    }
+}
+
+type AggregateValueClosure<Row> = Rc<dyn Fn(&Row) -> Option<f64>>;
+
+struct AggregateColumn<Row> {
+    aggregate: Aggregate,
+    value: AggregateValueClosure<Row>,
+    precision: Option<usize>,
+}
+
+/// You can pass this to a [`TableContent`] component's `aggregates` prop to compute an aggregate per
+/// column, like the sum of an amount column, and to render them in a `<tfoot>` below the rows.
+///
+/// The value function of a column returns `None` for rows that shouldn't be part of the aggregate,
+/// e.g. for an `Option` field. The aggregates are available as signals through
+/// [`ColumnAggregates::value`] and [`ColumnAggregates::text`], so you can display them elsewhere too.
+///
+/// > Please note that the aggregates are computed over the loaded rows. With data providers that load
+/// > rows in ranges, rows that have never been scrolled into view are not part of them. In that case
+/// > compute the exact values on the server and hand them over with [`ColumnAggregates::set_total`].
+///
+/// ```
+/// # use leptos::*;
+/// # use leptos_struct_table::*;
+/// #
+/// #[derive(TableRow, Clone)]
+/// #[table(impl_vec_data_provider)]
+/// pub struct Invoice {
+///     customer: String,
+///     #[table(format(precision = 2))]
+///     amount: f64,
+///     discount: Option<f64>,
+/// }
+///
+/// #[component]
+/// fn App() -> impl IntoView {
+///     let rows: Vec<Invoice> = vec![];
+///
+///     let aggregates = ColumnAggregates::default()
+///         .column(0, Aggregate::Count, |_: &Invoice| Some(1.0))
+///         .column(1, Aggregate::Sum, |row: &Invoice| Some(row.amount))
+///         .precision(1, 2)
+///         .column(2, Aggregate::Max, |row: &Invoice| row.discount);
+///
+///     view! {
+///         <p>"Total: " {aggregates.text(1)}</p>
+///         <table>
+///             <TableContent rows aggregates />
+///         </table>
+///     }
+/// }
+/// ```
+pub struct ColumnAggregates<Row: 'static> {
+    columns: StoredValue<HashMap<usize, AggregateColumn<Row>>>,
+    values: RwSignal<HashMap<usize, f64>>,
+    totals: RwSignal<HashMap<usize, f64>>,
+}
+
+impl<Row> Clone for ColumnAggregates<Row> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<Row> Copy for ColumnAggregates<Row> {}
+
+impl<Row> Default for ColumnAggregates<Row> {
+    fn default() -> Self {
+        Self {
+            columns: store_value(HashMap::new()),
+            values: create_rw_signal(HashMap::new()),
+            totals: create_rw_signal(HashMap::new()),
+        }
+    }
+}
+
+impl<Row> ColumnAggregates<Row> {
+    /// Computes `aggregate` over the values that `value` returns for the rows in the column `col_index`.
+    pub fn column(
+        self,
+        col_index: usize,
+        aggregate: Aggregate,
+        value: impl Fn(&Row) -> Option<f64> + 'static,
+    ) -> Self {
+        self.columns.update_value(|columns| {
+            columns.insert(
+                col_index,
+                AggregateColumn {
+                    aggregate,
+                    value: Rc::new(value),
+                    precision: None,
+                },
+            );
+        });
+        self
+    }
+
+    /// The number of digits after the decimal point of the aggregate of the column `col_index`
+    /// in [`ColumnAggregates::text`], e.g. the same as the `format(precision)` of the field.
+    pub fn precision(self, col_index: usize, precision: usize) -> Self {
+        self.columns.update_value(|columns| {
+            if let Some(column) = columns.get_mut(&col_index) {
+                column.precision = Some(precision);
+            }
+        });
+        self
+    }
+
+    /// Whether the column `col_index` has an aggregate.
+    pub fn has_aggregate(&self, col_index: usize) -> bool {
+        self.columns
+            .with_value(|columns| columns.contains_key(&col_index))
+    }
+
+    /// Overrides the computed aggregate of the column `col_index`, e.g. with an exact value from the
+    /// server. Pass `None` to go back to the aggregate over the loaded rows.
+    pub fn set_total(&self, col_index: usize, total: Option<f64>) {
+        self.totals.update(|totals| match total {
+            Some(total) => {
+                totals.insert(col_index, total);
+            }
+            None => {
+                totals.remove(&col_index);
+            }
+        });
+    }
+
+    /// The aggregate of the column `col_index`. `None` if the column has no aggregate or there are no
+    /// values to compute the average, minimum or maximum of.
+    pub fn value(&self, col_index: usize) -> Signal<Option<f64>> {
+        let Self { values, totals, .. } = *self;
+
+        Signal::derive(move || {
+            totals
+                .with(|totals| totals.get(&col_index).copied())
+                .or_else(|| values.with(|values| values.get(&col_index).copied()))
+        })
+    }
+
+    /// The aggregate of the column `col_index` formatted with its precision. Empty if there is no value.
+    pub fn text(&self, col_index: usize) -> Signal<String> {
+        let value = self.value(col_index);
+        let precision = self
+            .columns
+            .with_value(|columns| columns.get(&col_index).and_then(|column| column.precision));
+
+        Signal::derive(move || match (value.get(), precision) {
+            (Some(value), Some(precision)) => format!("{value:.precision$}"),
+            (Some(value), None) => value.to_string(),
+            (None, _) => String::new(),
+        })
+    }
+
+    /// Recomputes the aggregates over the loaded rows. Called by [`TableContent`].
+    pub(crate) fn compute(&self, loaded_rows: &LoadedRows<Row>)
+    where
+        Row: Clone,
+    {
+        let rows = loaded_rows[0..loaded_rows.len()]
+            .iter()
+            .filter_map(|row| match row {
+                RowState::Loaded(row) => Some(row),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+
+        let values = self.columns.with_value(|columns| {
+            columns
+                .iter()
+                .filter_map(|(col_index, column)| {
+                    let values = rows.iter().filter_map(|row| (column.value)(row));
+
+                    column
+                        .aggregate
+                        .compute(values)
+                        .map(|value| (*col_index, value))
+                })
+                .collect()
+        });
+
+        self.values.set(values);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn loaded_rows(amounts: &[Option<i32>]) -> LoadedRows<i32> {
+        let mut loaded_rows = LoadedRows::new();
+        loaded_rows.resize(amounts.len());
+
+        for (index, amount) in amounts.iter().enumerate() {
+            if let Some(amount) = amount {
+                loaded_rows.write_loaded(Ok((vec![*amount], index..index + 1)), index..index + 1);
+            }
+        }
+
+        loaded_rows
+    }
+
+    #[test]
+    fn running_totals_sum_up_to_each_row() {
+        let loaded_rows = loaded_rows(&[Some(1), Some(2), Some(3), Some(4)]);
+
+        assert_eq!(
+            running_totals(&loaded_rows, |amount| *amount),
+            [1, 3, 6, 10]
+        );
+    }
+
+    #[test]
+    fn running_totals_skip_rows_that_are_not_loaded() {
+        let mut loaded_rows = loaded_rows(&[None, Some(2), None, Some(4), None]);
+        loaded_rows.write_loading(2..3);
+
+        assert_eq!(
+            running_totals(&loaded_rows, |amount| *amount),
+            [0, 2, 2, 6, 6]
+        );
+    }
+
+    #[test]
+    fn running_total_of_rows_after_the_cache_includes_all_loaded_rows() {
+        let running_totals = running_totals(&loaded_rows(&[Some(1), Some(2)]), |amount| *amount);
+
+        assert_eq!(running_total(&running_totals, 1), 3);
+        assert_eq!(running_total(&running_totals, 10), 3);
+        assert_eq!(running_total::<i32>(&[], 0), 0);
+    }
+}
@@ -0,0 +1,132 @@
+use leptos::*;
+use std::future::Future;
+use std::pin::Pin;
+use std::rc::Rc;
+
+type CellFuture<T, E> = Pin<Box<dyn Future<Output = Result<T, E>>>>;
+
+/// The value of a cell that is only available asynchronously, e.g. because a foreign key has to be
+/// resolved to a name through a cache. Return it from a getter (see [`FieldGetter`] or the `getter`
+/// attribute) to avoid joining the data before it's passed to the table.
+///
+/// The future is started when the cell is rendered. Until it resolves the `loading` view is shown
+/// inside of the cell (nothing by default). If it fails, the `error` view is shown (nothing by
+/// default), e.g. the same text as the `none_value` of an optional field.
+///
+/// The `loading` view is a placeholder for this cell only. It's independent of the
+/// `loading_row_renderer` of [`TableContent`] which is shown for whole rows that haven't been loaded
+/// from the data provider yet. Components without props can be passed directly, so slow columns can
+/// share a placeholder component while fast columns of the same row are shown right away.
+///
+/// ```
+/// # use leptos::*;
+/// # use leptos_struct_table::*;
+/// #
+/// #[derive(TableRow, Clone)]
+/// #[table(impl_vec_data_provider)]
+/// pub struct Order {
+///     id: u32,
+///     #[table(skip)]
+///     customer_id: u32,
+///     customer_name: FieldGetter<AsyncCellValue<String>>,
+/// }
+///
+/// impl Order {
+///     pub fn customer_name(&self) -> AsyncCellValue<String> {
+///         let customer_id = self.customer_id;
+///
+///         AsyncCellValue::new(move || fetch_customer_name(customer_id))
+///             .loading(SkeletonPlaceholder)
+///             .error(|_| "-")
+///     }
+/// }
+///
+/// #[component]
+/// fn SkeletonPlaceholder() -> impl IntoView {
+///     view! { <span class="skeleton" aria-busy="true"></span> }
+/// }
+///
+/// async fn fetch_customer_name(customer_id: u32) -> Result<String, String> {
+///     // e.g. look it up in a cache and ask the server if it's missing
+///     Ok(format!("Customer {customer_id}"))
+/// }
+/// ```
+///
+/// Cells with an async value can't be sorted by the value since it isn't known when the rows are sorted.
+/// Mark such fields with `#[table(skip_sort)]` in sortable tables.
+pub struct AsyncCellValue<T, E = String> {
+    future: Rc<dyn Fn() -> CellFuture<T, E>>,
+    loading: Rc<dyn Fn() -> View>,
+    error: Rc<dyn Fn(E) -> View>,
+}
+
+impl<T, E> Clone for AsyncCellValue<T, E> {
+    fn clone(&self) -> Self {
+        Self {
+            future: Rc::clone(&self.future),
+            loading: Rc::clone(&self.loading),
+            error: Rc::clone(&self.error),
+        }
+    }
+}
+
+impl<T, E> AsyncCellValue<T, E>
+where
+    T: 'static,
+    E: 'static,
+{
+    /// Creates the value from a function that returns the future which resolves to the value.
+    /// It's called every time the cell is rendered.
+    pub fn new<F, Fut>(future: F) -> Self
+    where
+        F: Fn() -> Fut + 'static,
+        Fut: Future<Output = Result<T, E>> + 'static,
+    {
+        Self {
+            future: Rc::new(move || Box::pin(future())),
+            loading: Rc::new(View::default),
+            error: Rc::new(|_| View::default()),
+        }
+    }
+
+    /// Sets the view that is shown inside of the cell until the value is available.
+    pub fn loading<V: IntoView>(mut self, loading: impl Fn() -> V + 'static) -> Self {
+        self.loading = Rc::new(move || loading().into_view());
+        self
+    }
+
+    /// Sets the view that is shown inside of the cell if the future resolves to an error.
+    pub fn error<V: IntoView>(mut self, error: impl Fn(E) -> V + 'static) -> Self {
+        self.error = Rc::new(move |err| error(err).into_view());
+        self
+    }
+}
+
+impl<T, E> IntoView for AsyncCellValue<T, E>
+where
+    T: IntoView + Clone + 'static,
+    E: Clone + 'static,
+{
+    fn into_view(self) -> View {
+        let Self {
+            future,
+            loading,
+            error,
+        } = self;
+
+        let value = create_local_resource(|| (), move |_| future());
+
+        let content = move || {
+            value.get().map(|value| match value {
+                Ok(value) => value.into_view(),
+                Err(err) => error(err),
+            })
+        };
+
+        view! {
+            <Suspense fallback=move || loading()>
+                {content.clone()}
+            </Suspense>
+        }
+    }
+}
@@ -0,0 +1,46 @@
+use crate::components::is_interactive_event_target;
+use crate::sticky_columns::map_root_element;
+use crate::{CellClickEvent, EventHandler};
+use leptos::*;
+
+/// Provided as context by [`TableContent`] if the `on_cell_click` prop is given.
+pub(crate) struct CellClickContext<Row: Clone + 'static>(pub EventHandler<CellClickEvent<Row>>);
+
+impl<Row: Clone + 'static> Clone for CellClickContext<Row> {
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+
+impl<Row: Clone + 'static> CellClickContext<Row> {
+    /// Adds the click listeners to `cells` which is one rendered row with one node per column in
+    /// the natural column order, so the column index is the logical one.
+    pub fn apply(&self, cells: View, row: &Row, row_index: usize) -> View {
+        match cells {
+            View::Component(mut fragment) => {
+                for (col_index, cell) in fragment.children.iter_mut().enumerate() {
+                    let on_cell_click = self.0.clone();
+                    let row = row.clone();
+
+                    *cell = map_root_element(std::mem::take(cell), move |element| {
+                        element.on(ev::click, move |mouse_event| {
+                            if is_interactive_event_target(&mouse_event) {
+                                return;
+                            }
+
+                            on_cell_click.run(CellClickEvent {
+                                row_index,
+                                col_index,
+                                row: row.clone(),
+                                mouse_event,
+                            });
+                        })
+                    });
+                }
+
+                View::Component(fragment)
+            }
+            cells => cells,
+        }
+    }
+}
@@ -0,0 +1,94 @@
+use crate::sticky_columns::map_root_element;
+use leptos::*;
+use std::rc::Rc;
+
+type CellTooltipClosure<Row> = Rc<dyn Fn(&Row, usize) -> Option<String>>;
+
+/// New type wrapper of a closure that returns the tooltip of a body cell from the data of its row
+/// and its column index (see [`TableRow::col_name`]). See the `cell_tooltip_fn` prop of [`TableContent`].
+///
+/// The tooltip is set as the `title` attribute of the root element of the cell, so it works with
+/// the default cell renderers as well as with custom ones that render a single element. Return the
+/// raw value to show the full text of cells that are formatted or truncated, or `None` for no tooltip.
+/// The text is set as an attribute value and doesn't have to be escaped.
+///
+/// ```
+/// # use leptos::*;
+/// # use leptos_struct_table::*;
+/// #
+/// #[derive(TableRow, Clone)]
+/// #[table(impl_vec_data_provider)]
+/// pub struct Article {
+///     title: String,
+///     #[table(renderer = "DefaultUrlTableCellRenderer")]
+///     url: String,
+///     #[table(format(precision = 1))]
+///     rating: f64,
+/// }
+///
+/// impl Article {
+///     fn tooltip(&self, col_index: usize) -> Option<String> {
+///         match col_index {
+///             0 => Some(self.title.clone()),
+///             2 => Some(self.rating.to_string()),
+///             _ => None,
+///         }
+///     }
+/// }
+///
+/// #[component]
+/// fn App() -> impl IntoView {
+///     let rows = vec![Article {
+///         title: "A very long title that is cut off by the column width".to_string(),
+///         url: "https://example.com/articles/a-very-long-title".to_string(),
+///         rating: 4.25,
+///     }];
+///
+///     view! {
+///         <table>
+///             <TableContent rows cell_tooltip_fn=Article::tooltip />
+///         </table>
+///     }
+/// }
+/// ```
+pub struct CellTooltipFn<Row>(CellTooltipClosure<Row>);
+
+impl<Row> Clone for CellTooltipFn<Row> {
+    fn clone(&self) -> Self {
+        Self(Rc::clone(&self.0))
+    }
+}
+
+impl<F, Row> From<F> for CellTooltipFn<Row>
+where
+    F: Fn(&Row, usize) -> Option<String> + 'static,
+{
+    fn from(f: F) -> Self {
+        Self(Rc::new(f))
+    }
+}
+
+impl<Row> CellTooltipFn<Row> {
+    pub fn run(&self, row: &Row, col_index: usize) -> Option<String> {
+        (self.0)(row, col_index)
+    }
+
+    /// Sets the tooltips of `cells` which is one rendered row with one node per column in the
+    /// natural column order.
+    pub(crate) fn apply(&self, cells: View, row: &Row) -> View {
+        match cells {
+            View::Component(mut fragment) => {
+                for (col_index, cell) in fragment.children.iter_mut().enumerate() {
+                    if let Some(tooltip) = self.run(row, col_index) {
+                        *cell = map_root_element(std::mem::take(cell), |element| {
+                            element.attr("title", tooltip)
+                        });
+                    }
+                }
+
+                View::Component(fragment)
+            }
+            cells => cells,
+        }
+    }
+}
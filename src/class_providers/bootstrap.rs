@@ -1,5 +1,13 @@
-use crate::TableClassesProvider;
+use crate::{ColumnSort, TableClassesProvider};
 
+/// Classes preset for [Bootstrap 5](https://getbootstrap.com/).
+///
+/// [`TableContent`] doesn't render the `<table>` element itself, so put the table classes on it
+/// yourself, e.g. `<table class="table table-striped table-hover">`.
+///
+/// Sortable head cells get the class `sortable` and `sort-asc` or `sort-desc` when they're sorted.
+/// Please have a look at the [bootstrap example](https://github.com/Synphonyte/leptos-struct-table/blob/master/examples/bootstrap/src/main.rs)
+/// for CSS that shows the sort indicators.
 #[derive(Clone, Copy)]
 pub struct BootstrapClassesPreset;
 
@@ -8,11 +16,141 @@ impl TableClassesProvider for BootstrapClassesPreset {
         Self
     }
 
+    fn thead(&self, prop_class: &str) -> String {
+        format!("{} {}", "table-light", prop_class)
+    }
+
+    fn thead_row(&self, prop_class: &str) -> String {
+        format!("{} {}", "align-middle", prop_class)
+    }
+
+    fn thead_cell(&self, sort: ColumnSort, macro_class: &str) -> String {
+        format!(
+            "{} {} {}",
+            "sortable user-select-none text-nowrap",
+            sort.as_class(),
+            macro_class
+        )
+    }
+
+    fn thead_cell_unsortable(&self, macro_class: &str) -> String {
+        format!("{} {}", "text-nowrap", macro_class)
+    }
+
+    fn thead_cell_inner(&self) -> String {
+        "d-flex align-items-center".to_string()
+    }
+
+    fn thead_cell_resize_handle(&self) -> String {
+        "border-end".to_string()
+    }
+
+    fn thead_group_cell(&self) -> String {
+        "text-center".to_string()
+    }
+
+    fn tfoot_cell(&self, _col_index: usize) -> String {
+        "fw-bold".to_string()
+    }
+
+    fn badge(&self, _value_slug: &str) -> String {
+        "badge text-bg-secondary".to_string()
+    }
+
+    fn filter_cell(&self, _col_index: usize) -> String {
+        "py-1".to_string()
+    }
+
+    fn filter_input(&self) -> String {
+        "form-control form-control-sm".to_string()
+    }
+
     fn row(&self, _: usize, selected: bool, template_classes: &str) -> String {
         let active = if selected { "table-active" } else { "" };
 
         format!("{} {}", active, template_classes)
     }
 
-    // TODO : skeleton loading
+    fn row_band(&self, row_index: usize, band_size: usize) -> String {
+        if (row_index / band_size.max(1)) % 2 == 1 {
+            "table-light".to_string()
+        } else {
+            "".to_string()
+        }
+    }
+
+    fn row_focused(&self, _row_index: usize) -> String {
+        "table-primary".to_string()
+    }
+
+    fn expanded_row(&self, _row_index: usize) -> String {
+        "table-light".to_string()
+    }
+
+    fn highlighted_row(&self) -> String {
+        "table-warning".to_string()
+    }
+
+    fn row_drop_target(&self) -> String {
+        "table-active".to_string()
+    }
+
+    fn loading_cell(&self, _row_index: usize, _col_index: usize, prop_class: &str) -> String {
+        format!("{} {}", "placeholder-glow", prop_class)
+    }
+
+    fn loading_overlay(&self) -> String {
+        "bg-body bg-opacity-75".to_string()
+    }
+
+    fn empty_row(&self) -> String {
+        "text-center text-body-secondary py-4".to_string()
+    }
+
+    fn caption(&self) -> String {
+        "caption-top fw-semibold text-body".to_string()
+    }
+
+    fn caption_description(&self) -> String {
+        "d-block small fw-normal text-body-secondary".to_string()
+    }
+
+    fn loading_cell_inner(&self, row_index: usize, _col_index: usize, prop_class: &str) -> String {
+        let width = match row_index % 4 {
+            0 => "col-10",
+            1 => "col-11",
+            2 => "col-9",
+            _ => "col-7",
+        };
+
+        format!("{} {} {}", "placeholder", width, prop_class)
+    }
+
+    fn cell(&self, macro_class: &str) -> String {
+        format!("{} {}", "align-middle", macro_class)
+    }
+
+    fn sticky_cell(&self, _col_index: usize, is_head: bool) -> String {
+        if is_head {
+            "z-2 bg-body-tertiary".to_string()
+        } else {
+            "z-1 bg-body".to_string()
+        }
+    }
+
+    fn sticky_right_cell(&self, col_index: usize, is_head: bool) -> String {
+        format!("{} shadow-sm", self.sticky_cell(col_index, is_head))
+    }
+
+    fn active_filters_bar(&self, prop_class: &str) -> String {
+        format!("{} {}", "d-flex flex-wrap gap-2 py-2", prop_class)
+    }
+
+    fn active_filter_chip(&self) -> String {
+        "badge rounded-pill text-bg-light border d-inline-flex align-items-center gap-1".to_string()
+    }
+
+    fn active_filter_chip_remove(&self) -> String {
+        "btn btn-link btn-sm p-0 lh-1 text-reset text-decoration-none".to_string()
+    }
 }
@@ -0,0 +1,150 @@
+use crate::{ColumnSort, TableClassesProvider};
+
+/// Classes preset for [Bulma](https://bulma.io/).
+///
+/// [`TableContent`] doesn't render the `<table>` element itself, so put the table classes on it
+/// yourself, e.g. `<table class="table is-striped is-hoverable is-fullwidth">`.
+///
+/// Selected rows get Bulma's `is-selected` class. Sortable head cells get the class `sort-asc` or
+/// `sort-desc` when they're sorted and the CSS variable `--sort-icon` is set on them (see
+/// [`DefaultTableHeaderCellRenderer`]) so you can show the sort direction. The sort priority is
+/// rendered as a `<span class="sort-priority">` after the title.
+#[derive(Clone, Copy)]
+pub struct BulmaClassesPreset;
+
+impl TableClassesProvider for BulmaClassesPreset {
+    fn new() -> Self {
+        Self
+    }
+
+    fn thead(&self, prop_class: &str) -> String {
+        prop_class.to_string()
+    }
+
+    fn thead_row(&self, prop_class: &str) -> String {
+        prop_class.to_string()
+    }
+
+    fn thead_cell(&self, sort: ColumnSort, macro_class: &str) -> String {
+        format!(
+            "{} {} {}",
+            "is-clickable is-unselectable",
+            sort.as_class(),
+            macro_class
+        )
+    }
+
+    fn thead_cell_unsortable(&self, macro_class: &str) -> String {
+        macro_class.to_string()
+    }
+
+    fn thead_cell_inner(&self) -> String {
+        "is-flex is-align-items-center".to_string()
+    }
+
+    fn thead_cell_resize_handle(&self) -> String {
+        "has-background-grey-lighter".to_string()
+    }
+
+    fn thead_group_cell(&self) -> String {
+        "has-text-centered".to_string()
+    }
+
+    fn tfoot_cell(&self, _col_index: usize) -> String {
+        "has-text-weight-bold".to_string()
+    }
+
+    fn badge(&self, _value_slug: &str) -> String {
+        "tag".to_string()
+    }
+
+    fn filter_row(&self, prop_class: &str) -> String {
+        prop_class.to_string()
+    }
+
+    fn filter_cell(&self, _col_index: usize) -> String {
+        "py-1".to_string()
+    }
+
+    fn filter_input(&self) -> String {
+        "input is-small".to_string()
+    }
+
+    fn tbody(&self, prop_class: &str) -> String {
+        prop_class.to_string()
+    }
+
+    fn row(&self, _row_index: usize, selected: bool, prop_class: &str) -> String {
+        let selected = if selected { "is-selected" } else { "" };
+
+        format!("{} {}", selected, prop_class)
+    }
+
+    fn row_band(&self, row_index: usize, band_size: usize) -> String {
+        if (row_index / band_size.max(1)) % 2 == 1 {
+            "has-background-light".to_string()
+        } else {
+            "".to_string()
+        }
+    }
+
+    fn row_focused(&self, _row_index: usize) -> String {
+        "has-background-info-light".to_string()
+    }
+
+    fn expanded_row(&self, _row_index: usize) -> String {
+        "has-background-white-ter".to_string()
+    }
+
+    fn highlighted_row(&self) -> String {
+        "has-background-warning-light".to_string()
+    }
+
+    fn row_drop_target(&self) -> String {
+        "has-background-info-light".to_string()
+    }
+
+    fn loading_cell(&self, _row_index: usize, _col_index: usize, prop_class: &str) -> String {
+        prop_class.to_string()
+    }
+
+    fn loading_overlay(&self) -> String {
+        "has-background-white".to_string()
+    }
+
+    fn empty_row(&self) -> String {
+        "has-text-centered has-text-grey py-5".to_string()
+    }
+
+    fn caption(&self) -> String {
+        "has-text-left has-text-weight-semibold py-2".to_string()
+    }
+
+    fn caption_description(&self) -> String {
+        "is-block is-size-7 has-text-weight-normal has-text-grey".to_string()
+    }
+
+    fn loading_cell_inner(&self, _row_index: usize, _col_index: usize, prop_class: &str) -> String {
+        format!("{} {}", "skeleton-block mb-0", prop_class)
+    }
+
+    fn cell(&self, macro_class: &str) -> String {
+        format!("{} {}", "is-vcentered", macro_class)
+    }
+
+    fn sticky_cell(&self, _col_index: usize, _is_head: bool) -> String {
+        "has-background-white".to_string()
+    }
+
+    fn active_filters_bar(&self, prop_class: &str) -> String {
+        format!("{} {}", "tags py-2", prop_class)
+    }
+
+    fn active_filter_chip(&self) -> String {
+        "tag is-rounded".to_string()
+    }
+
+    fn active_filter_chip_remove(&self) -> String {
+        "delete is-small".to_string()
+    }
+}
@@ -1,8 +1,10 @@
 mod bootstrap;
+mod bulma;
 mod tailwind;
 
 use crate::ColumnSort;
 pub use bootstrap::*;
+pub use bulma::*;
 pub use tailwind::*;
 
 /// A trait for providing classes for the table.
@@ -31,11 +33,57 @@ pub trait TableClassesProvider {
         format!("{} {}", sort.as_class(), macro_class)
     }
 
+    /// Get the classes for the thead cells of columns that can't be sorted (e.g. because of `skip_sort`).
+    /// Use this to render them without the clickable affordance of sortable columns.
+    /// The `macro_class` parameter contains the classes specified in the `head_class` macro attribute of the field.
+    fn thead_cell_unsortable(&self, macro_class: &str) -> String {
+        macro_class.to_string()
+    }
+
+    /// Get the classes for the filter row below the head row that is rendered if the `filterable`
+    /// prop of the [`TableContent`] component is set.
+    /// The `prop_class` parameter contains the classes specified in the `filter_row_class` prop of the [`TableContent`] component.
+    fn filter_row(&self, prop_class: &str) -> String {
+        prop_class.to_string()
+    }
+
+    /// Get the classes for the cells of the filter row.
+    #[allow(unused_variables)]
+    fn filter_cell(&self, col_index: usize) -> String {
+        "".to_string()
+    }
+
+    /// Get the classes for the filter inputs inside the cells of the filter row.
+    fn filter_input(&self) -> String {
+        "".to_string()
+    }
+
     /// Get the classes for the thead cells' inner element.
     fn thead_cell_inner(&self) -> String {
         "".to_string()
     }
 
+    /// Get the classes for the sort priority number (1, 2, 3, ...) that [`DefaultTableHeaderCellRenderer`]
+    /// shows next to the sort direction when the table is sorted by several columns.
+    ///
+    /// By default this is `sort-priority`.
+    fn thead_cell_sort_priority(&self) -> String {
+        "sort-priority".to_string()
+    }
+
+    /// Get the classes for the resize handle on the right edge of the thead cells if the
+    /// `column_resize_controller` prop of the [`TableContent`] component is set.
+    /// The handle is positioned with inline styles, so use this for its look, e.g. a hover color.
+    fn thead_cell_resize_handle(&self) -> String {
+        "".to_string()
+    }
+
+    /// Get the classes for the header cells of the column groups if the `column_groups` prop of
+    /// the [`TableContent`] component is set.
+    fn thead_group_cell(&self) -> String {
+        "".to_string()
+    }
+
     /// Get the classes for the tbody.
     /// The `prop_class` parameter contains the classes specified in the
     /// `tbody_class` prop of the [`TableContent`] component.
@@ -48,11 +96,64 @@ pub trait TableClassesProvider {
     /// The `row_index` parameter contains the index of the row. The first row has index 0.
     /// The `selected` parameter indicates whether the row is selected.
     /// The `prop_class` parameter contains the classes specified in the `row_class`
-    /// prop of the [`TableContent`] component.
+    /// prop of the [`TableContent`] component followed by the classes returned by its `row_class_fn` prop.
     fn row(&self, row_index: usize, selected: bool, prop_class: &str) -> String {
         prop_class.to_string() + if selected { " selected" } else { "" }
     }
 
+    /// Get the additional classes for the body rows when the `band_size` prop of the [`TableContent`]
+    /// component is set. These are added to the classes returned by [`TableClassesProvider::row`].
+    /// The `row_index` parameter contains the absolute index of the row, so the bands stay the same
+    /// while scrolling. The `band_size` parameter contains the number of rows per band.
+    ///
+    /// By default every second band gets the class `band`.
+    ///
+    /// ```
+    /// # use leptos_struct_table::*;
+    /// let class_provider = DummyTableClassesProvider::new();
+    ///
+    /// let bands = (0..12)
+    ///     .map(|row_index| class_provider.row_band(row_index, 5))
+    ///     .collect::<Vec<_>>();
+    ///
+    /// assert!(bands[0..5].iter().all(|class| class.is_empty()));
+    /// assert!(bands[5..10].iter().all(|class| class == "band"));
+    /// assert!(bands[10..12].iter().all(|class| class.is_empty()));
+    /// ```
+    fn row_band(&self, row_index: usize, band_size: usize) -> String {
+        if (row_index / band_size.max(1)) % 2 == 1 {
+            "band".to_string()
+        } else {
+            "".to_string()
+        }
+    }
+
+    #[allow(unused_variables)]
+    /// Get the additional classes for the body row that has the keyboard focus (see the `focused_row`
+    /// prop of the [`TableContent`] component). These are added to the classes returned by [`TableClassesProvider::row`].
+    ///
+    /// By default this is `focused`.
+    fn row_focused(&self, row_index: usize) -> String {
+        "focused".to_string()
+    }
+
+    /// Get the classes for the row below an expanded row that shows its details (see the
+    /// `expanded_row_renderer` prop of the [`TableContent`] component).
+    ///
+    /// By default this is `expanded-row`.
+    #[allow(unused_variables)]
+    fn expanded_row(&self, row_index: usize) -> String {
+        "expanded-row".to_string()
+    }
+
+    /// Get the classes that are added to the row that has been scrolled to with
+    /// [`TableController::scroll_to_row`] while it's highlighted.
+    ///
+    /// By default this is `highlighted-row`.
+    fn highlighted_row(&self) -> String {
+        "highlighted-row".to_string()
+    }
+
     #[allow(unused_variables)]
     /// Get the classes for the elements inside of the cells of rows that are currently
     /// being loaded.
@@ -62,6 +163,22 @@ pub trait TableClassesProvider {
         prop_class.to_string()
     }
 
+    /// Get the classes for the loading overlay that is rendered over the body during the first load
+    /// (see the `loading_overlay_renderer` prop of the [`TableContent`] component).
+    ///
+    /// By default this is `loading-overlay`.
+    fn loading_overlay(&self) -> String {
+        "loading-overlay".to_string()
+    }
+
+    /// Get the classes for the cell of the row that is shown when the table has no rows
+    /// (see the `empty_row_renderer` prop of the [`TableContent`] component).
+    ///
+    /// By default this is `empty-row`.
+    fn empty_row(&self) -> String {
+        "empty-row".to_string()
+    }
+
     #[allow(unused_variables)]
     /// Get the classes for the elements inside of the cells of rows that are currently
     /// being loaded. Usually this will be some loading indicator like a sceleton bar.
@@ -71,11 +188,121 @@ pub trait TableClassesProvider {
         prop_class.to_string()
     }
 
+    /// Get the class attribute for the tfoot that is rendered if the `aggregates` prop of the
+    /// [`TableContent`] component is set. The `prop_class` parameter contains the classes specified
+    /// in the `tfoot_class` prop.
+    fn tfoot(&self, prop_class: &str) -> String {
+        prop_class.to_string()
+    }
+
+    #[allow(unused_variables)]
+    /// Get the classes for the cells of the tfoot row that display the aggregates.
+    fn tfoot_cell(&self, col_index: usize) -> String {
+        "".to_string()
+    }
+
+    /// Get the classes for the badge `<span>` of [`DefaultEnumTableCellRenderer`]. The `value_slug`
+    /// parameter contains the displayed value as returned by [`badge_slug`]. The stable class
+    /// `badge-<value_slug>` is always added, so use this for the common look of the badges.
+    #[allow(unused_variables)]
+    fn badge(&self, value_slug: &str) -> String {
+        "".to_string()
+    }
+
+    /// Get the classes for the cells of the leading selection column with the checkboxes or radio
+    /// buttons (see the `selection_checkboxes` prop of the [`TableContent`] component). `is_head` is
+    /// `true` for the header cell.
+    ///
+    /// By default these are the classes of [`TableClassesProvider::thead_cell_unsortable`] and
+    /// [`TableClassesProvider::cell`] without macro classes.
+    fn selection_cell(&self, is_head: bool) -> String {
+        if is_head {
+            self.thead_cell_unsortable("")
+        } else {
+            self.cell("")
+        }
+    }
+
+    /// Get the classes for the cells of the leading column with the drag handles (see the
+    /// `row_drag_handle` prop of the [`TableContent`] component). `is_head` is `true` for the header cell.
+    ///
+    /// By default these are the same as [`TableClassesProvider::selection_cell`].
+    fn row_drag_handle_cell(&self, is_head: bool) -> String {
+        self.selection_cell(is_head)
+    }
+
+    /// Get the classes that are added to the row that a dragged row is over (see the
+    /// `row_reorderable` prop of the [`TableContent`] component). Dropping the dragged row moves
+    /// it to the position of this row.
+    ///
+    /// By default this is `row-drop-target`.
+    fn row_drop_target(&self) -> String {
+        "row-drop-target".to_string()
+    }
+
     /// Get the classes for the body cells.
     /// The `macro_class` parameter contains the classes specified in the `class` macro attribute of the field.
     fn cell(&self, macro_class: &str) -> String {
         macro_class.to_string()
     }
+
+    /// Get the classes for the cells of sticky columns (see the `sticky_columns` prop of [`TableContent`]).
+    /// These are added to the other classes of the cell together with the stable class `sticky-col`.
+    /// `position: sticky` and the `left` offset are set as inline styles, so use this for the
+    /// background and `z-index` which have to cover the cells that scroll underneath.
+    /// The `is_head` parameter is `true` for the cells of the head and filter rows.
+    #[allow(unused_variables)]
+    fn sticky_cell(&self, col_index: usize, is_head: bool) -> String {
+        "".to_string()
+    }
+
+    /// Get the classes for the cells of columns that are pinned to the right (see the
+    /// `sticky_right_columns` prop of [`TableContent`]). Defaults to [`TableClassesProvider::sticky_cell`].
+    /// Use this e.g. for a shadow on the left edge that separates them from the scrolling cells.
+    /// In right-to-left tables these columns are pinned to the left instead.
+    fn sticky_right_cell(&self, col_index: usize, is_head: bool) -> String {
+        self.sticky_cell(col_index, is_head)
+    }
+
+    /// Get the additional classes for the thead, tbody and tfoot if the `direction` prop of the
+    /// [`TableContent`] component is [`Direction::Rtl`]. These are added to the classes returned by
+    /// [`TableClassesProvider::thead`], [`TableClassesProvider::tbody`] and [`TableClassesProvider::tfoot`].
+    ///
+    /// By default this is `rtl`.
+    fn rtl(&self) -> String {
+        "rtl".to_string()
+    }
+
+    /// Get the classes for the `<caption>` element (see the `caption` prop of the [`TableContent`] component).
+    ///
+    /// By default this is `caption`.
+    fn caption(&self) -> String {
+        "caption".to_string()
+    }
+
+    /// Get the classes for the description inside of the `<caption>` element (see the `description`
+    /// prop of the [`TableContent`] component).
+    ///
+    /// By default this is `caption-description`.
+    fn caption_description(&self) -> String {
+        "caption-description".to_string()
+    }
+
+    /// Get the classes for the bar of [`ActiveFiltersBar`].
+    /// The `prop_class` parameter contains the classes specified in the `class` prop of the [`ActiveFiltersBar`] component.
+    fn active_filters_bar(&self, prop_class: &str) -> String {
+        prop_class.to_string()
+    }
+
+    /// Get the classes for a chip inside of [`ActiveFiltersBar`] that shows one sorted column or filter.
+    fn active_filter_chip(&self) -> String {
+        "".to_string()
+    }
+
+    /// Get the classes for the ✕ button inside of a chip of [`ActiveFiltersBar`].
+    fn active_filter_chip_remove(&self) -> String {
+        "".to_string()
+    }
 }
 
 #[derive(Copy, Clone)]
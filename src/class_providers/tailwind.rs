@@ -28,8 +28,44 @@ impl TableClassesProvider for TailwindClassesPreset {
         )
     }
 
+    fn thead_cell_unsortable(&self, template_classes: &str) -> String {
+        format!("{} {}", "px-5 py-2", template_classes)
+    }
+
+    fn filter_row(&self, prop_class: &str) -> String {
+        format!("{} {}", "bg-gray-100 dark:bg-gray-800", prop_class)
+    }
+
+    fn filter_cell(&self, _col_index: usize) -> String {
+        "px-5 py-1".to_string()
+    }
+
+    fn filter_input(&self) -> String {
+        "w-full rounded border border-gray-300 bg-white px-2 py-1 text-xs font-normal normal-case dark:border-gray-600 dark:bg-gray-900 dark:text-gray-300".to_string()
+    }
+
     fn thead_cell_inner(&self) -> String {
-        "flex items-center after:content-[--sort-icon] after:pl-1 after:opacity-40 before:content-[--sort-priority] before:order-last before:pl-0.5 before:font-light before:opacity-40".to_string()
+        "flex items-center after:content-[--sort-icon] after:ps-1 after:opacity-40".to_string()
+    }
+
+    fn thead_cell_sort_priority(&self) -> String {
+        "order-last ps-0.5 text-[0.625rem] font-light opacity-60".to_string()
+    }
+
+    fn thead_cell_resize_handle(&self) -> String {
+        "hover:bg-gray-300 active:bg-gray-400".to_string()
+    }
+
+    fn thead_group_cell(&self) -> String {
+        "px-5 py-2 text-center border-b border-gray-200 dark:border-gray-700".to_string()
+    }
+
+    fn tfoot_cell(&self, _col_index: usize) -> String {
+        "px-5 py-2 font-semibold text-gray-900 dark:text-white".to_string()
+    }
+
+    fn badge(&self, _value_slug: &str) -> String {
+        "inline-flex items-center rounded px-2 py-0.5 text-xs font-medium bg-gray-100 text-gray-800 dark:bg-gray-700 dark:text-gray-300".to_string()
     }
 
     fn row(&self, row_index: usize, selected: bool, template_classes: &str) -> String {
@@ -55,6 +91,23 @@ impl TableClassesProvider for TailwindClassesPreset {
         format!("{} {}", "px-5 py-2", prop_class)
     }
 
+    fn loading_overlay(&self) -> String {
+        "bg-white/75 text-gray-500 dark:bg-gray-900/75 dark:text-gray-400".to_string()
+    }
+
+    fn empty_row(&self) -> String {
+        "px-5 py-8 text-center text-gray-500 dark:text-gray-400".to_string()
+    }
+
+    fn caption(&self) -> String {
+        "caption-top px-5 py-3 text-left rtl:text-right font-semibold text-gray-900 dark:text-white"
+            .to_string()
+    }
+
+    fn caption_description(&self) -> String {
+        "block mt-1 text-sm font-normal text-gray-500 dark:text-gray-400".to_string()
+    }
+
     fn loading_cell_inner(&self, row_index: usize, _col_index: usize, prop_class: &str) -> String {
         let width = match row_index % 4 {
             0 => "w-[calc(85%-2.5rem)]",
@@ -71,4 +124,47 @@ impl TableClassesProvider for TailwindClassesPreset {
     fn cell(&self, template_classes: &str) -> String {
         format!("{} {}", "px-5 py-2", template_classes)
     }
+
+    fn row_focused(&self, _row_index: usize) -> String {
+        "outline outline-2 -outline-offset-2 outline-sky-500".to_string()
+    }
+
+    fn expanded_row(&self, _row_index: usize) -> String {
+        "bg-gray-50 dark:bg-gray-900".to_string()
+    }
+
+    fn highlighted_row(&self) -> String {
+        "bg-yellow-100 dark:bg-yellow-900/50".to_string()
+    }
+
+    fn row_drop_target(&self) -> String {
+        "bg-blue-50 dark:bg-blue-950".to_string()
+    }
+
+    fn sticky_cell(&self, _col_index: usize, is_head: bool) -> String {
+        if is_head {
+            "z-20 bg-inherit".to_string()
+        } else {
+            "z-10 bg-inherit".to_string()
+        }
+    }
+
+    fn sticky_right_cell(&self, col_index: usize, is_head: bool) -> String {
+        format!(
+            "{} shadow-[-4px_0_4px_-4px_rgba(0,0,0,0.2)] rtl:shadow-[4px_0_4px_-4px_rgba(0,0,0,0.2)]",
+            self.sticky_cell(col_index, is_head)
+        )
+    }
+
+    fn active_filters_bar(&self, prop_class: &str) -> String {
+        format!("{} {}", "flex flex-wrap gap-2 py-2", prop_class)
+    }
+
+    fn active_filter_chip(&self) -> String {
+        "inline-flex items-center gap-1 rounded-full px-3 py-1 text-xs bg-gray-200 text-gray-700 dark:bg-gray-700 dark:text-gray-300".to_string()
+    }
+
+    fn active_filter_chip_remove(&self) -> String {
+        "opacity-60 hover:opacity-100".to_string()
+    }
 }
@@ -0,0 +1,77 @@
+/// A group of columns whose header cells get a common header cell in an extra head row above them,
+/// e.g. "Q1" above "Jan", "Feb" and "Mar". Pass the groups to the `column_groups` prop of [`TableContent`].
+///
+/// `columns` are the indices of the columns in the struct (see [`TableRow::col_name`]). Skipped fields
+/// don't have a column index, so they can't be part of a group. The group header cell spans the
+/// columns of the group that are displayed next to each other. If the columns of a group are
+/// separated (e.g. by reordering them), the group gets one header cell for every consecutive part.
+/// Columns that aren't in any group get empty header cells.
+///
+/// ```
+/// # use leptos::*;
+/// # use leptos_struct_table::*;
+/// #
+/// #[derive(TableRow, Clone)]
+/// #[table(impl_vec_data_provider)]
+/// pub struct Revenue {
+///     product: String,
+///     jan: f64,
+///     feb: f64,
+///     mar: f64,
+///     apr: f64,
+/// }
+///
+/// #[component]
+/// fn App() -> impl IntoView {
+///     let rows: Vec<Revenue> = vec![];
+///
+///     let column_groups = vec![
+///         ColumnGroup::new("Q1", [1, 2, 3]),
+///         ColumnGroup::new("Q2", [4]),
+///     ];
+///
+///     view! {
+///         <table>
+///             <TableContent rows column_groups />
+///         </table>
+///     }
+/// }
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct ColumnGroup {
+    /// The title that is displayed in the group header cell.
+    pub title: String,
+    /// The indices of the columns of this group.
+    pub columns: Vec<usize>,
+}
+
+impl ColumnGroup {
+    pub fn new(title: impl Into<String>, columns: impl IntoIterator<Item = usize>) -> Self {
+        Self {
+            title: title.into(),
+            columns: columns.into_iter().collect(),
+        }
+    }
+}
+
+/// Splits the columns in the display `order` into the cells of the group header row. Every cell is
+/// the index of its group in `groups` (or `None` for columns without group) and its colspan.
+pub(crate) fn group_header_spans(
+    groups: &[ColumnGroup],
+    order: &[usize],
+) -> Vec<(Option<usize>, usize)> {
+    let mut spans: Vec<(Option<usize>, usize)> = vec![];
+
+    for col_index in order {
+        let group = groups
+            .iter()
+            .position(|group| group.columns.contains(col_index));
+
+        match spans.last_mut() {
+            Some((last_group, colspan)) if *last_group == group => *colspan += 1,
+            _ => spans.push((group, 1)),
+        }
+    }
+
+    spans
+}
@@ -0,0 +1,313 @@
+use crate::cell_click::CellClickContext;
+use crate::column_virtualization::ColumnVirtualizationContext;
+use crate::expansion::ExpansionColumnContext;
+use crate::flatten::flatten_cells;
+use crate::row_reorder::RowReorderContext;
+use crate::selection::SelectionColumnContext;
+use crate::sticky_columns::StickyColumnsContext;
+use crate::{
+    CellTooltipFn, ChangeEvent, ColumnCellRenderers, DefaultExpandRowCellRenderer,
+    DefaultRowDragHandleCellRenderer, DefaultSelectRowCellRenderer,
+    DefaultSelectRowRadioCellRenderer, EventHandler, GetterContext, RowSelectableFn,
+    TableClassesProvider, TableRow,
+};
+use leptos::*;
+
+/// You can pass this to a [`TableContent`] component's `column_reorder_controller` prop to let the
+/// user reorder the columns by dragging the header cells onto each other.
+///
+/// The order itself is stored in the `column_order` prop of [`TableContent`].
+///
+/// See the [column_reorder example](https://github.com/Synphonyte/leptos-struct-table/blob/master/examples/column_reorder/src/main.rs)
+/// for how to use.
+#[derive(Copy, Clone)]
+pub struct ColumnReorderController {
+    pub(crate) move_request: RwSignal<Option<(usize, usize)>>,
+    dragged_col: RwSignal<Option<usize>>,
+}
+
+impl Default for ColumnReorderController {
+    fn default() -> Self {
+        Self {
+            move_request: create_rw_signal(None),
+            dragged_col: create_rw_signal(None),
+        }
+    }
+}
+
+impl ColumnReorderController {
+    /// Moves the column `col_index` to the current position of the column `target_col_index`.
+    /// The columns in between shift by one position. Both are indices of the columns in the struct
+    /// (see [`TableRow::col_name`]) and not their positions.
+    pub fn move_column(&self, col_index: usize, target_col_index: usize) {
+        self.move_request.set(Some((col_index, target_col_index)));
+    }
+
+    /// Call this when the user starts dragging the header cell of the column `col_index`.
+    /// Used by [`DefaultTableHeaderCellRenderer`].
+    pub fn drag_start(&self, col_index: usize) {
+        self.dragged_col.set(Some(col_index));
+    }
+
+    /// Call this when the user drops a header cell onto the header cell of the column `col_index`.
+    /// Moves the dragged column there. Used by [`DefaultTableHeaderCellRenderer`].
+    pub fn drop_on(&self, col_index: usize) {
+        if let Some(dragged_col) = self.dragged_col.get_untracked() {
+            self.move_column(dragged_col, col_index);
+        }
+        self.drag_end();
+    }
+
+    /// Call this when the user stops dragging a header cell without dropping it onto another one.
+    pub fn drag_end(&self) {
+        self.dragged_col.set(None);
+    }
+
+    /// The index of the column whose header cell is being dragged, if any.
+    pub fn dragged_column(&self) -> Option<usize> {
+        self.dragged_col.get()
+    }
+}
+
+/// Provided as context by [`TableContent`] so that the renderers can put the cells in order and
+/// leave out the hidden ones.
+#[derive(Copy, Clone)]
+pub(crate) struct ColumnOrderContext {
+    pub order: RwSignal<Vec<usize>>,
+    pub visibility: RwSignal<Vec<bool>>,
+}
+
+impl ColumnOrderContext {
+    /// The normalized order of the visible columns out of `col_count` columns. Tracks the order and
+    /// the visibility signals.
+    pub fn get(self, col_count: usize) -> Vec<usize> {
+        let order = self
+            .order
+            .with(|column_order| normalize_column_order(column_order, col_count));
+
+        self.visibility.with(|visibility| {
+            order
+                .into_iter()
+                .filter(|col_index| is_column_visible(visibility, *col_index))
+                .collect()
+        })
+    }
+}
+
+/// Whether the column `col_index` is visible according to `column_visibility` (see the prop of
+/// [`TableContent`]). Columns that aren't contained are visible.
+pub(crate) fn is_column_visible(column_visibility: &[bool], col_index: usize) -> bool {
+    column_visibility.get(col_index).copied().unwrap_or(true)
+}
+
+/// Computes the initial value of the `column_visibility` prop of [`TableContent`] by calling
+/// `is_visible` with the index and the name (see [`TableRow::col_name`]) of every column.
+///
+/// ```
+/// # use leptos::*;
+/// # use leptos_struct_table::*;
+/// #
+/// #[derive(TableRow, Clone)]
+/// pub struct Employee {
+///     name: String,
+///     department: String,
+///     salary: f64,
+/// }
+///
+/// let is_admin = false;
+///
+/// assert_eq!(
+///     column_visibility::<Employee>(|_, col_name| is_admin || col_name != "salary"),
+///     vec![true, true, false],
+/// );
+/// ```
+pub fn column_visibility<Row: TableRow>(
+    is_visible: impl Fn(usize, &'static str) -> bool,
+) -> Vec<bool> {
+    (0..Row::column_count())
+        .map(|col_index| is_visible(col_index, Row::col_name(col_index)))
+        .collect()
+}
+
+/// Returns the complete display order of `col_count` columns from a possibly incomplete `column_order`.
+/// Indices that are out of bounds or appear twice are dropped. Missing columns are appended
+/// in their natural order.
+///
+/// ```
+/// # use leptos_struct_table::*;
+/// assert_eq!(normalize_column_order(&[], 3), vec![0, 1, 2]);
+/// assert_eq!(normalize_column_order(&[2, 0], 3), vec![2, 0, 1]);
+/// assert_eq!(normalize_column_order(&[1, 5, 1, 0], 3), vec![1, 0, 2]);
+/// ```
+pub fn normalize_column_order(column_order: &[usize], col_count: usize) -> Vec<usize> {
+    let mut normalized = Vec::with_capacity(col_count);
+
+    for col_index in column_order.iter().copied().chain(0..col_count) {
+        if col_index < col_count && !normalized.contains(&col_index) {
+            normalized.push(col_index);
+        }
+    }
+
+    normalized
+}
+
+/// Moves `col_index` to the position of `target_col_index` in the normalized `column_order`.
+pub(crate) fn move_column(
+    column_order: &[usize],
+    col_count: usize,
+    col_index: usize,
+    target_col_index: usize,
+) -> Vec<usize> {
+    let mut order = normalize_column_order(column_order, col_count);
+
+    let from = order.iter().position(|col| *col == col_index);
+    let to = order.iter().position(|col| *col == target_col_index);
+
+    if let (Some(from), Some(to)) = (from, to) {
+        let col = order.remove(from);
+        order.insert(to, col);
+    }
+
+    order
+}
+
+/// The display order of the columns of the surrounding [`TableContent`] or the natural order if there is none.
+pub fn use_column_order(col_count: usize) -> Signal<Vec<usize>> {
+    let column_order = use_context::<ColumnOrderContext>();
+
+    Signal::derive(move || match column_order {
+        Some(column_order) => column_order.get(col_count),
+        None => (0..col_count).collect(),
+    })
+}
+
+/// Puts the cells of a row (as rendered by [`TableRow::render_row`] or [`TableRow::render_head_row`])
+/// in the given order and leaves out the columns that aren't contained. Views that don't consist of
+/// one node per column are returned unchanged.
+pub(crate) fn reorder_cells(cells: View, order: &[usize], col_count: usize) -> View {
+    match cells {
+        // a fragment is converted into a nameless component with one child per cell
+        View::Component(mut fragment)
+            if fragment.children.len() == col_count
+                && (order.len() != col_count
+                    || order.iter().enumerate().any(|(i, col)| i != *col)) =>
+        {
+            let mut nodes = std::mem::take(&mut fragment.children)
+                .into_iter()
+                .map(Some)
+                .collect::<Vec<_>>();
+
+            order
+                .iter()
+                .filter_map(|col_index| nodes[*col_index].take())
+                .collect::<Fragment>()
+                .into_view()
+        }
+        cells => cells,
+    }
+}
+
+/// Renders the cells of `row` in the column order of the surrounding [`TableContent`] and pins the
+/// cells of its sticky columns. With `Selection::Multiple` the cell with the selection checkbox
+/// (or with `Selection::Single` the radio button) is prepended, followed by the disclosure cell of expandable tables. Use this instead of [`TableRow::render_row`] in custom row renderers
+/// to support reordering, hiding and sticky columns. With `row_drag_handle` the drag handle cell
+/// comes first.
+pub fn render_row_cells<Row>(
+    row: Row,
+    index: usize,
+    on_change: EventHandler<ChangeEvent<Row>>,
+) -> impl IntoView
+where
+    Row: TableRow + Clone + 'static,
+{
+    let column_order = use_column_order(Row::column_count());
+    let sticky_columns = use_context::<StickyColumnsContext>();
+
+    let drag_handle_cell = use_context::<RowReorderContext>()
+        .filter(|reorder| reorder.drag_handle)
+        .map(|reorder| {
+            DefaultRowDragHandleCellRenderer(
+                reorder.enabled,
+                Callback::new(move |pressed: bool| {
+                    reorder.armed_row.set(pressed.then_some(index));
+                }),
+                Row::ClassesProvider::new().row_drag_handle_cell(false),
+            )
+        });
+
+    let selection_cell = use_context::<SelectionColumnContext>().map(|selection_column| {
+        let selected = Signal::derive(move || selection_column.selection.is_selected(index));
+        let on_toggle = Callback::new(move |_| (selection_column.toggle_row)(index));
+        let class = Row::ClassesProvider::new().selection_cell(false);
+        let disabled = use_context::<RowSelectableFn<Row>>()
+            .is_some_and(|row_selectable_fn| !row_selectable_fn.run(&row, index));
+
+        match selection_column.radio_name() {
+            Some(name) => {
+                DefaultSelectRowRadioCellRenderer(selected, on_toggle, class, name, disabled)
+                    .into_view()
+            }
+            None => DefaultSelectRowCellRenderer(selected, on_toggle, class, disabled).into_view(),
+        }
+    });
+
+    let expand_cell = use_context::<ExpansionColumnContext>().map(|expansion_column| {
+        DefaultExpandRowCellRenderer(
+            Signal::derive(move || expansion_column.is_expanded(index)),
+            Callback::new(move |_| expansion_column.toggle(index)),
+            Row::ClassesProvider::new().cell(""),
+        )
+    });
+
+    let cell_tooltip_fn = use_context::<CellTooltipFn<Row>>();
+    let cell_click = use_context::<CellClickContext<Row>>();
+    let column_virtualization = use_context::<ColumnVirtualizationContext>();
+    let getter_context = use_context::<GetterContext>();
+    let cell_renderers = use_context::<MaybeSignal<ColumnCellRenderers<Row>>>();
+
+    let cells = move || {
+        // the getters read the context untracked
+        if let Some(getter_context) = getter_context {
+            getter_context.track();
+        }
+
+        let cells = untrack(|| row.render_row(index, on_change.clone()).into_view());
+        let cells = flatten_cells(cells, Row::column_count());
+        let cells = match &cell_renderers {
+            Some(cell_renderers) => cell_renderers.with(|cell_renderers| {
+                let class = Row::ClassesProvider::new().cell("");
+                untrack(|| cell_renderers.apply(cells, &row, index, &class, &on_change))
+            }),
+            None => cells,
+        };
+        let cells = match &cell_tooltip_fn {
+            Some(cell_tooltip_fn) => cell_tooltip_fn.apply(cells, &row),
+            None => cells,
+        };
+        let cells = match &cell_click {
+            Some(cell_click) => cell_click.apply(cells, &row, index),
+            None => cells,
+        };
+        let cells = match sticky_columns {
+            Some(sticky_columns) => sticky_columns.stick_cells(cells, false),
+            None => cells,
+        };
+        // after pinning because the swapped cells don't have a root element anymore
+        let cells = match column_virtualization {
+            Some(column_virtualization) => {
+                let class = Row::ClassesProvider::new().cell("");
+                column_virtualization.virtualize_cells(cells, &class)
+            }
+            None => cells,
+        };
+
+        column_order.with(|column_order| reorder_cells(cells, column_order, Row::column_count()))
+    };
+
+    view! {
+        {drag_handle_cell}
+        {selection_cell}
+        {expand_cell}
+        {cells}
+    }
+}
@@ -0,0 +1,203 @@
+use crate::TableRow;
+use leptos::*;
+use std::collections::HashMap;
+
+/// The minimum width in pixels of resizable columns that don't have their own minimum width.
+pub const DEFAULT_MIN_COLUMN_WIDTH: f64 = 24.0;
+
+/// You can pass this to a [`TableContent`] component's `column_resize_controller` prop to let the
+/// user resize the columns by dragging a handle on the right edge of the header cells.
+///
+/// The widths are applied to the `<col>` elements of a `<colgroup>` that [`TableContent`] renders
+/// before the `<thead>`. Give the table `table-layout: fixed` so the widths are respected exactly.
+/// Otherwise the browser treats them as minimum widths.
+///
+/// See the [column_resize example](https://github.com/Synphonyte/leptos-struct-table/blob/master/examples/column_resize/src/main.rs)
+/// for how to use.
+#[derive(Copy, Clone)]
+pub struct ColumnResizeController {
+    /// The widths of the columns in pixels by column index. `None` means the column has its natural
+    /// width. Read and set this to persist the widths.
+    pub widths: RwSignal<Vec<Option<f64>>>,
+    limits: StoredValue<HashMap<usize, ColumnWidthLimits>>,
+    resizing: StoredValue<Option<ResizeStart>>,
+}
+
+#[derive(Copy, Clone)]
+struct ColumnWidthLimits {
+    resizable: bool,
+    min_width: f64,
+    max_width: f64,
+}
+
+impl Default for ColumnWidthLimits {
+    fn default() -> Self {
+        Self {
+            resizable: true,
+            min_width: DEFAULT_MIN_COLUMN_WIDTH,
+            max_width: f64::INFINITY,
+        }
+    }
+}
+
+#[derive(Copy, Clone)]
+struct ResizeStart {
+    col_index: usize,
+    client_x: f64,
+    width: f64,
+}
+
+impl Default for ColumnResizeController {
+    fn default() -> Self {
+        Self {
+            widths: create_rw_signal(vec![]),
+            limits: store_value(HashMap::new()),
+            resizing: store_value(None),
+        }
+    }
+}
+
+impl ColumnResizeController {
+    /// The column `col_index` can't be made narrower than `min_width` pixels.
+    /// Defaults to [`DEFAULT_MIN_COLUMN_WIDTH`].
+    pub fn min_width(self, col_index: usize, min_width: f64) -> Self {
+        self.update_limits(col_index, |limits| limits.min_width = min_width);
+        self
+    }
+
+    /// The column `col_index` can't be made wider than `max_width` pixels.
+    pub fn max_width(self, col_index: usize, max_width: f64) -> Self {
+        self.update_limits(col_index, |limits| limits.max_width = max_width);
+        self
+    }
+
+    /// The column `col_index` can't be resized by the user. All columns are resizable by default.
+    pub fn fixed(self, col_index: usize) -> Self {
+        self.update_limits(col_index, |limits| limits.resizable = false);
+        self
+    }
+
+    fn update_limits(&self, col_index: usize, f: impl FnOnce(&mut ColumnWidthLimits)) {
+        self.limits
+            .update_value(|limits| f(limits.entry(col_index).or_default()));
+    }
+
+    fn limits(&self, col_index: usize) -> ColumnWidthLimits {
+        self.limits
+            .with_value(|limits| limits.get(&col_index).copied().unwrap_or_default())
+    }
+
+    /// Whether the user can resize the column `col_index`.
+    pub fn is_resizable(&self, col_index: usize) -> bool {
+        self.limits(col_index).resizable
+    }
+
+    /// The width of the column `col_index` in pixels or `None` if it has its natural width.
+    pub fn width(&self, col_index: usize) -> Option<f64> {
+        self.widths
+            .with(|widths| widths.get(col_index).copied().flatten())
+    }
+
+    /// Sets the width of the column `col_index` to `width` pixels within its minimum and maximum width.
+    pub fn set_width(&self, col_index: usize, width: f64) {
+        let limits = self.limits(col_index);
+        let width = width.min(limits.max_width).max(limits.min_width);
+
+        self.widths.update(|widths| {
+            if widths.len() <= col_index {
+                widths.resize(col_index + 1, None);
+            }
+            widths[col_index] = Some(width);
+        });
+    }
+
+    /// Gives the column `col_index` its natural width again.
+    pub fn reset_width(&self, col_index: usize) {
+        self.widths.update(|widths| {
+            if let Some(width) = widths.get_mut(col_index) {
+                *width = None;
+            }
+        });
+    }
+
+    /// Call this when the user starts dragging the resize handle of the column `col_index`.
+    /// `width` is the current width of the column in pixels. Used by [`DefaultTableHeaderCellRenderer`].
+    pub fn resize_start(&self, col_index: usize, client_x: f64, width: f64) {
+        if self.is_resizable(col_index) {
+            self.resizing.set_value(Some(ResizeStart {
+                col_index,
+                client_x,
+                width,
+            }));
+        }
+    }
+
+    /// Call this when the pointer that drags a resize handle moves to `client_x`.
+    pub fn resize_to(&self, client_x: f64) {
+        if let Some(start) = self.resizing.get_value() {
+            self.set_width(start.col_index, start.width + client_x - start.client_x);
+        }
+    }
+
+    /// Call this when the user stops dragging a resize handle.
+    pub fn resize_end(&self) {
+        self.resizing.set_value(None);
+    }
+
+    /// Whether a column is being resized right now. Not reactive.
+    pub fn is_resizing(&self) -> bool {
+        self.resizing.with_value(|resizing| resizing.is_some())
+    }
+}
+
+/// Provided as context by [`TableContent`] together with the [`ColumnResizeController`] so that
+/// the header cells can render the resize handles.
+#[derive(Clone)]
+pub(crate) struct ColumnResizeHandleClass(pub String);
+
+/// Computes the value of the `column_widths` prop of [`TableContent`] from column names
+/// (see [`TableRow::col_name`]) and CSS widths like `"12rem"` or `"20%"`. Names that don't belong
+/// to a column, e.g. those of skipped fields, are ignored.
+///
+/// ```
+/// # use leptos::*;
+/// # use leptos_struct_table::*;
+/// # use std::collections::HashMap;
+/// #
+/// #[derive(TableRow, Clone)]
+/// #[table(impl_vec_data_provider)]
+/// pub struct Product {
+///     #[table(skip)]
+///     id: u32,
+///     name: String,
+///     price: f64,
+/// }
+///
+/// let widths = column_widths::<Product>([("name", "12rem"), ("price", "6rem"), ("id", "2rem")]);
+///
+/// assert_eq!(widths, HashMap::from([(0, "12rem".to_string()), (1, "6rem".to_string())]));
+///
+/// #[component]
+/// fn App() -> impl IntoView {
+///     # let rows: Vec<Product> = vec![];
+///     let widths = column_widths::<Product>([("name", "12rem"), ("price", "6rem")]);
+///
+///     view! {
+///         <table style="table-layout: fixed">
+///             <TableContent rows column_widths=widths />
+///         </table>
+///     }
+/// }
+/// ```
+pub fn column_widths<'a, Row: TableRow>(
+    widths: impl IntoIterator<Item = (&'a str, &'a str)>,
+) -> HashMap<usize, String> {
+    widths
+        .into_iter()
+        .filter_map(|(col_name, width)| {
+            (0..Row::column_count())
+                .find(|col_index| Row::col_name(*col_index) == col_name)
+                .map(|col_index| (col_index, width.to_string()))
+        })
+        .collect()
+}
@@ -0,0 +1,172 @@
+use leptos::*;
+use std::ops::Range;
+
+/// Default for the `column_overscan` prop of [`TableContent`].
+pub const DEFAULT_COLUMN_OVERSCAN: usize = 2;
+
+/// Default for the `estimated_column_width` prop of [`TableContent`].
+pub const DEFAULT_ESTIMATED_COLUMN_WIDTH: f64 = 150.0;
+
+/// Provided as context by [`TableContent`] if the `virtualize_columns` prop is set so that the row
+/// renderers only render the cells of the columns inside of the horizontal viewport.
+#[derive(Copy, Clone)]
+pub(crate) struct ColumnVirtualizationContext {
+    /// Whether the body cells of a column are rendered by column index.
+    pub rendered_columns: Memo<Vec<bool>>,
+}
+
+impl ColumnVirtualizationContext {
+    /// Swaps the cells of the columns that aren't rendered in `cells` which is one rendered row
+    /// with one node per column in the natural column order for empty cells with `class`.
+    ///
+    /// Every cell only tracks whether its own column is rendered, so the row isn't rendered again
+    /// when the horizontal viewport changes.
+    pub fn virtualize_cells(self, cells: View, class: &str) -> View {
+        match cells {
+            View::Component(mut fragment) => {
+                for (col_index, cell) in fragment.children.iter_mut().enumerate() {
+                    let rendered_columns = self.rendered_columns;
+                    let is_rendered = create_memo(move |_| {
+                        rendered_columns.with(|rendered_columns| {
+                            rendered_columns.get(col_index).copied().unwrap_or(true)
+                        })
+                    });
+
+                    let rendered_cell = std::mem::take(cell);
+                    let empty_cell =
+                        view! { <td class=class.to_string() role="cell"></td> }.into_view();
+
+                    *cell = (move || {
+                        if is_rendered.get() {
+                            rendered_cell.clone()
+                        } else {
+                            empty_cell.clone()
+                        }
+                    })
+                    .into_view();
+                }
+
+                View::Component(fragment)
+            }
+            cells => cells,
+        }
+    }
+}
+
+/// Computes which of `col_count` columns are rendered by column index. `order` contains the visible
+/// columns in display order, `viewport` is the horizontal range in pixels that is visible, measured
+/// from the start of the first column. `overscan` columns are added on both sides.
+pub(crate) fn rendered_columns(
+    order: &[usize],
+    col_count: usize,
+    width: impl Fn(usize) -> f64,
+    viewport: Range<f64>,
+    overscan: usize,
+) -> Vec<bool> {
+    let mut rendered = vec![false; col_count];
+
+    let mut start = 0.0;
+    let visible_positions = order
+        .iter()
+        .enumerate()
+        .filter_map(|(position, col_index)| {
+            let end = start + width(*col_index);
+            let is_visible = end > viewport.start && start < viewport.end;
+            start = end;
+
+            is_visible.then_some(position)
+        })
+        .collect::<Vec<_>>();
+
+    if let (Some(first), Some(last)) = (visible_positions.first(), visible_positions.last()) {
+        let positions = first.saturating_sub(overscan)..(last + overscan + 1).min(order.len());
+
+        for col_index in &order[positions] {
+            if let Some(rendered) = rendered.get_mut(*col_index) {
+                *rendered = true;
+            }
+        }
+    }
+
+    rendered
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rendered_indices(rendered: Vec<bool>) -> Vec<usize> {
+        rendered
+            .into_iter()
+            .enumerate()
+            .filter_map(|(col_index, rendered)| rendered.then_some(col_index))
+            .collect()
+    }
+
+    #[test]
+    fn columns_in_the_middle_of_the_viewport_are_rendered() {
+        let order = (0..10).collect::<Vec<_>>();
+
+        let rendered = rendered_columns(&order, 10, |_| 100.0, 350.0..550.0, 0);
+        assert_eq!(rendered_indices(rendered), [3, 4, 5]);
+
+        let rendered = rendered_columns(&order, 10, |_| 100.0, 350.0..550.0, 1);
+        assert_eq!(rendered_indices(rendered), [2, 3, 4, 5, 6]);
+    }
+
+    #[test]
+    fn columns_that_only_touch_the_viewport_are_not_rendered() {
+        let order = (0..10).collect::<Vec<_>>();
+
+        let rendered = rendered_columns(&order, 10, |_| 100.0, 300.0..500.0, 0);
+        assert_eq!(rendered_indices(rendered), [3, 4]);
+    }
+
+    #[test]
+    fn overscan_is_clamped_at_both_ends() {
+        let order = (0..10).collect::<Vec<_>>();
+
+        let rendered = rendered_columns(&order, 10, |_| 100.0, 0.0..150.0, 2);
+        assert_eq!(rendered_indices(rendered), [0, 1, 2, 3]);
+
+        let rendered = rendered_columns(&order, 10, |_| 100.0, 850.0..1000.0, 2);
+        assert_eq!(rendered_indices(rendered), [6, 7, 8, 9]);
+
+        let rendered = rendered_columns(&order, 10, |_| 100.0, 0.0..1000.0, 5);
+        assert_eq!(rendered_indices(rendered), (0..10).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn reordered_columns_are_rendered_by_their_position() {
+        let order = (0..10).rev().collect::<Vec<_>>();
+
+        let rendered = rendered_columns(&order, 10, |_| 100.0, 0.0..150.0, 1);
+        assert_eq!(rendered_indices(rendered), [7, 8, 9]);
+    }
+
+    #[test]
+    fn hidden_columns_are_not_rendered() {
+        // the odd columns are hidden
+        let order = [0, 2, 4, 6, 8];
+
+        let rendered = rendered_columns(&order, 10, |_| 100.0, 100.0..250.0, 1);
+        assert_eq!(rendered_indices(rendered), [0, 2, 4, 6]);
+    }
+
+    #[test]
+    fn columns_have_their_own_widths() {
+        let order = (0..5).collect::<Vec<_>>();
+        let widths = [300.0, 50.0, 50.0, 50.0, 300.0];
+
+        let rendered = rendered_columns(&order, 5, |col_index| widths[col_index], 320.0..380.0, 0);
+        assert_eq!(rendered_indices(rendered), [1, 2]);
+    }
+
+    #[test]
+    fn no_columns_are_rendered_outside_of_the_columns() {
+        let order = (0..10).collect::<Vec<_>>();
+
+        let rendered = rendered_columns(&order, 10, |_| 100.0, 2000.0..2500.0, 2);
+        assert_eq!(rendered_indices(rendered), Vec::<usize>::new());
+    }
+}
@@ -0,0 +1,201 @@
+use crate::{ColumnSort, TableClassesProvider, TableRow};
+use leptos::*;
+use std::collections::VecDeque;
+use std::marker::PhantomData;
+use std::rc::Rc;
+
+/// A filter criterion that is currently applied to the table. Displayed as a removable chip by [`ActiveFiltersBar`].
+///
+/// ```
+/// # use leptos::*;
+/// # use leptos_struct_table::*;
+/// # let runtime = create_runtime();
+/// let name = create_rw_signal("jo".to_string());
+///
+/// let filter = ActiveFilter::new(
+///     format!("Name contains '{}'", name.get_untracked()),
+///     move || name.set(String::new()),
+/// );
+/// filter.clear();
+///
+/// assert_eq!(name.get_untracked(), "");
+/// # runtime.dispose();
+/// ```
+#[derive(Clone)]
+pub struct ActiveFilter {
+    /// The text of the chip, e.g. `"Name contains 'jo'"`.
+    pub label: String,
+    clear: Rc<dyn Fn()>,
+}
+
+impl ActiveFilter {
+    /// Creates a new filter chip. `clear` is called when the user removes the chip. It should
+    /// reset the filter state so that the filter isn't applied anymore.
+    pub fn new(label: impl Into<String>, clear: impl Fn() + 'static) -> Self {
+        Self {
+            label: label.into(),
+            clear: Rc::new(clear),
+        }
+    }
+
+    /// Removes this filter by calling the `clear` function given to [`ActiveFilter::new`].
+    pub fn clear(&self) {
+        (self.clear)()
+    }
+}
+
+/// A strip that summarizes the active sorting and filters of a table as removable chips like
+/// "Sorted by Age ↓" or "Name contains 'jo' ✕". Clicking ✕ removes that criterion.
+///
+/// Pass the same `sorting` signal as to [`TableContent`] and derive the `filters` from your filter
+/// state signals. This renders a `<div>`, so place it outside of the `<table>`, e.g. in a sticky
+/// container right above the table. Style it through [`TableClassesProvider::active_filters_bar`],
+/// [`TableClassesProvider::active_filter_chip`] and [`TableClassesProvider::active_filter_chip_remove`].
+/// Nothing is rendered if there are no active criteria.
+///
+/// ```
+/// # use leptos::*;
+/// # use leptos_struct_table::*;
+/// # use std::collections::VecDeque;
+/// #
+/// #[derive(TableRow, Clone)]
+/// #[table(sortable, impl_vec_data_provider)]
+/// pub struct Person {
+///     name: String,
+///     age: u32,
+/// }
+///
+/// #[component]
+/// fn App() -> impl IntoView {
+///     let rows: Vec<Person> = vec![];
+///     let sorting = create_rw_signal(VecDeque::new());
+///     let name_filter = create_rw_signal(String::new());
+///
+///     let filters = Signal::derive(move || {
+///         let name = name_filter.get();
+///
+///         if name.is_empty() {
+///             vec![]
+///         } else {
+///             vec![ActiveFilter::new(format!("Name contains '{name}'"), move || {
+///                 name_filter.set(String::new())
+///             })]
+///         }
+///     });
+///
+///     view! {
+///         <ActiveFiltersBar<Person, _> sorting filters />
+///         <table>
+///             <TableContent rows sorting />
+///         </table>
+///     }
+/// }
+/// ```
+#[component]
+pub fn ActiveFiltersBar<Row, ClsP>(
+    /// The sorting of the table. Each sorted column is shown as a chip. Removing it stops sorting by that column.
+    #[prop(optional, into)]
+    sorting: Option<RwSignal<VecDeque<(usize, ColumnSort)>>>,
+    /// The active filters.
+    #[prop(optional, into)]
+    filters: MaybeSignal<Vec<ActiveFilter>>,
+    /// Returns the title of the column at the given index which is used in the sort chips.
+    /// Defaults to the column name in title case (`this_field` becomes `"This Field"`).
+    #[prop(optional, into)]
+    column_title: Option<Callback<usize, String>>,
+    /// Additional classes to add to the bar
+    #[prop(optional, into)]
+    class: MaybeSignal<String>,
+    #[prop(optional)] _marker: PhantomData<Row>,
+) -> impl IntoView
+where
+    Row: TableRow<ClassesProvider = ClsP> + 'static,
+    ClsP: TableClassesProvider + Copy + 'static,
+{
+    let class_provider = ClsP::new();
+
+    let column_title = column_title
+        .unwrap_or_else(|| Callback::new(|col_index| title_case(Row::col_name(col_index))));
+
+    let sort_chips = move || {
+        let Some(sorting) = sorting else {
+            return vec![];
+        };
+
+        sorting
+            .get()
+            .into_iter()
+            .filter_map(|(col_index, sort)| {
+                let arrow = match sort {
+                    ColumnSort::Ascending => "↑",
+                    ColumnSort::Descending => "↓",
+                    ColumnSort::None => return None,
+                };
+
+                let label = format!("Sorted by {} {arrow}", column_title(col_index));
+
+                Some(ActiveFilter::new(label, move || {
+                    sorting.update(|sorting| sorting.retain(|(col, _)| *col != col_index))
+                }))
+            })
+            .collect::<Vec<_>>()
+    };
+
+    let chips = Signal::derive(move || {
+        let mut chips = sort_chips();
+        chips.extend(filters.get());
+        chips
+    });
+
+    let class = Signal::derive(move || class_provider.active_filters_bar(&class.get()));
+    let chip_class = class_provider.active_filter_chip();
+    let chip_remove_class = class_provider.active_filter_chip_remove();
+
+    view! {
+        <Show when=move || chips.with(|chips| !chips.is_empty())>
+            <div class=class>
+                <For
+                    each=move || chips.get()
+                    key=|chip| chip.label.clone()
+                    children={
+                        let chip_class = chip_class.clone();
+                        let chip_remove_class = chip_remove_class.clone();
+
+                        move |chip: ActiveFilter| {
+                            let label = chip.label.clone();
+
+                            view! {
+                                <span class=chip_class.clone()>
+                                    {label.clone()}
+                                    <button
+                                        type="button"
+                                        class=chip_remove_class.clone()
+                                        aria-label=format!("Remove {label}")
+                                        on:click=move |_| chip.clear()
+                                    >
+                                        "✕"
+                                    </button>
+                                </span>
+                            }
+                        }
+                    }
+                />
+            </div>
+        </Show>
+    }
+}
+
+/// Converts a field name to title case like the `TableRow` derive macro does for default titles.
+fn title_case(name: &str) -> String {
+    name.split('_')
+        .filter(|word| !word.is_empty())
+        .map(|word| {
+            let mut chars = word.chars();
+            match chars.next() {
+                Some(first) => first.to_uppercase().chain(chars).collect::<String>(),
+                None => String::new(),
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
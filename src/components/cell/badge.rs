@@ -0,0 +1,115 @@
+use leptos::*;
+use std::fmt::Display;
+
+/// Provided as context by [`TableContent`] so that [`DefaultEnumTableCellRenderer`] can get the
+/// classes of the badges from the classes provider.
+#[derive(Copy, Clone)]
+pub(crate) struct BadgeClass(pub Callback<String, String>);
+
+/// Cell renderer for status enums and other values with a small set of states. Renders the
+/// `Display` value inside of a `<span>` badge with the class `badge-<slug>` where the slug is derived
+/// from the displayed text by [`badge_slug`], so every state can be styled through CSS.
+/// The classes of [`TableClassesProvider::badge`] are added as well.
+///
+/// Works with any type that implements `Display + Clone`, not only enums.
+///
+/// ```
+/// # use leptos::*;
+/// # use leptos_struct_table::*;
+/// # use std::fmt;
+/// #
+/// #[derive(Clone)]
+/// pub enum Status {
+///     Open,
+///     InProgress,
+///     Done,
+/// }
+///
+/// impl fmt::Display for Status {
+///     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+///         match self {
+///             Status::Open => write!(f, "Open"),
+///             Status::InProgress => write!(f, "In Progress"),  // -> class "badge-in-progress"
+///             Status::Done => write!(f, "Done"),
+///         }
+///     }
+/// }
+///
+/// #[derive(TableRow, Clone)]
+/// #[table(impl_vec_data_provider)]
+/// pub struct Ticket {
+///     title: String,
+///     #[table(renderer = "DefaultEnumTableCellRenderer")]
+///     status: Status,
+/// }
+/// ```
+#[component]
+pub fn DefaultEnumTableCellRenderer<T, F>(
+    /// The class attribute for the cell element. Generated by the classes provider.
+    class: String,
+    /// The value to display.
+    #[prop(into)]
+    value: MaybeSignal<T>,
+    /// Event handler called when the cell is changed. In this default renderer this will never happen.
+    on_change: F,
+    /// The index of the column. Starts at 0.
+    index: usize,
+) -> impl IntoView
+where
+    T: Display + Clone + 'static,
+    F: Fn(T) + 'static,
+{
+    let badge_class = use_context::<BadgeClass>();
+
+    let text = create_memo(move |_| value.with(ToString::to_string));
+
+    let badge_class = move || {
+        let slug = badge_slug(&text.get());
+
+        match badge_class {
+            Some(BadgeClass(badge_class)) => {
+                format!("{} badge-{slug}", badge_class(slug.clone()))
+            }
+            None => format!("badge-{slug}"),
+        }
+    };
+
+    view! {
+        <td class=class role="cell">
+            <span class=badge_class>{text}</span>
+        </td>
+    }
+}
+
+/// Turns `value` into a slug that can safely be used in a class name: ASCII letters and digits
+/// are lowercased, every run of other characters becomes a single `-` and leading or trailing
+/// dashes are removed. An empty slug is returned as `empty`.
+///
+/// ```
+/// # use leptos_struct_table::badge_slug;
+/// assert_eq!(badge_slug("In Progress"), "in-progress");
+/// assert_eq!(badge_slug("  on_hold!! "), "on-hold");
+/// assert_eq!(badge_slug("\"><script>"), "script");
+/// assert_eq!(badge_slug("???"), "empty");
+/// ```
+pub fn badge_slug(value: &str) -> String {
+    let mut slug = String::with_capacity(value.len());
+
+    for c in value.chars() {
+        if c.is_ascii_alphanumeric() {
+            slug.push(c.to_ascii_lowercase());
+        } else if !slug.is_empty() && !slug.ends_with('-') {
+            slug.push('-');
+        }
+    }
+
+    while slug.ends_with('-') {
+        slug.pop();
+    }
+
+    if slug.is_empty() {
+        "empty".to_string()
+    } else {
+        slug
+    }
+}
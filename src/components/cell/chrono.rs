@@ -1,6 +1,10 @@
 #![allow(unused_variables)]
 #![doc(cfg(feature = "chrono"))]
 
+#[cfg(feature = "chrono-locales")]
+use chrono::format::{DelayedFormat, StrftimeItems};
+#[cfg(feature = "chrono-locales")]
+pub use chrono::Locale;
 use chrono::{NaiveDate, NaiveDateTime, NaiveTime};
 use leptos::*;
 use paste::paste;
@@ -27,24 +31,128 @@ macro_rules! date_cell_renderer {
                 index: usize,
                 /// The format string to use for formatting the date. Provided by the `#[table(format(string="..."))]` attribute of the field.
                 /// See [`chrono::format::strftime`] for more information.
+                ///
+                /// With the **crate feature `chrono-locales`** month and day names are localized in the
+                /// locale of the [`DateLocale`] provided as context (if any).
                 #[prop(optional)] format_string: Option<String>,
             ) -> impl IntoView
             where
                 F: Fn($date_type) + 'static,
             {
+                #[cfg(feature = "chrono-locales")]
+                let text = {
+                    let locale = use_context::<DateLocale>();
+
+                    match (format_string, locale) {
+                        (Some(format_string), Some(locale)) => create_memo(move |_| {
+                            format_localized(value().date_and_time(), &format_string, locale.0.get())
+                        }),
+                        (Some(format_string), None) => create_memo( move |_| value().format(&format_string).to_string()),
+                        (None, _) => create_memo( move |_| value().to_string()),
+                    }
+                };
+
+                #[cfg(not(feature = "chrono-locales"))]
                 let text = match format_string {
                     Some(format_string) => create_memo( move |_| value().format(&format_string).to_string()),
                     None => create_memo( move |_| value().to_string()),
                 };
 
                 view! {
-                    <td class=class>{text}</td>
+                    <td class=class role="cell">{text}</td>
                 }
             }
         }
     };
 }
 
+/// Provide this as context to localize the month and day names of the chrono cell renderers
+/// that have a format string (see the `format(string = "...")` attribute). Without it the names are in English.
+///
+/// ```
+/// # use leptos::*;
+/// # use leptos_struct_table::*;
+/// # use chrono::NaiveDate;
+/// #
+/// #[derive(TableRow, Clone)]
+/// #[table(impl_vec_data_provider)]
+/// pub struct Event {
+///     title: String,
+///     #[table(format(string = "%e. %B %Y"))]
+///     date: NaiveDate,
+/// }
+///
+/// #[component]
+/// fn App(#[prop(into)] locale: Signal<Locale>) -> impl IntoView {
+///     // e.g. `Locale::de_DE` shows "1. Oktober 2024"
+///     provide_context(DateLocale::new(locale));
+///
+///     let rows = vec![Event {
+///         title: "Release".to_string(),
+///         date: NaiveDate::from_ymd_opt(2024, 10, 1).unwrap(),
+///     }];
+///
+///     view! {
+///         <table>
+///             <TableContent rows />
+///         </table>
+///     }
+/// }
+/// ```
+///
+/// Since [`Locale`] is an enum, misspelled locales are compile errors instead of runtime panics.
+///
+/// This is only available when the **crate feature `chrono-locales`** is enabled
+#[cfg(feature = "chrono-locales")]
+#[doc(cfg(feature = "chrono-locales"))]
+#[derive(Clone, Copy)]
+pub struct DateLocale(pub MaybeSignal<Locale>);
+
+#[cfg(feature = "chrono-locales")]
+impl DateLocale {
+    pub fn new(locale: impl Into<MaybeSignal<Locale>>) -> Self {
+        Self(locale.into())
+    }
+}
+
+/// The parts of a chrono value that are passed to [`DelayedFormat::new_with_locale`].
+#[cfg(feature = "chrono-locales")]
+trait DateAndTime {
+    fn date_and_time(&self) -> (Option<NaiveDate>, Option<NaiveTime>);
+}
+
+#[cfg(feature = "chrono-locales")]
+impl DateAndTime for NaiveDate {
+    fn date_and_time(&self) -> (Option<NaiveDate>, Option<NaiveTime>) {
+        (Some(*self), None)
+    }
+}
+
+#[cfg(feature = "chrono-locales")]
+impl DateAndTime for NaiveDateTime {
+    fn date_and_time(&self) -> (Option<NaiveDate>, Option<NaiveTime>) {
+        (Some(self.date()), Some(self.time()))
+    }
+}
+
+#[cfg(feature = "chrono-locales")]
+impl DateAndTime for NaiveTime {
+    fn date_and_time(&self) -> (Option<NaiveDate>, Option<NaiveTime>) {
+        (None, Some(*self))
+    }
+}
+
+#[cfg(feature = "chrono-locales")]
+fn format_localized(
+    (date, time): (Option<NaiveDate>, Option<NaiveTime>),
+    format_string: &str,
+    locale: Locale,
+) -> String {
+    let items = StrftimeItems::new_with_locale(format_string, locale);
+
+    DelayedFormat::new_with_locale(date, time, items, locale).to_string()
+}
+
 date_cell_renderer!(
     /// The default cell renderer for [`chrono::NaiveDate`].
     NaiveDate
@@ -59,3 +167,43 @@ date_cell_renderer!(
     /// The default cell renderer for [`chrono::NaiveTime`].
     NaiveTime
 );
+
+/// Editable cell renderer for [`chrono::NaiveDate`]. Renders an `<input type="date">` and emits
+/// `on_change` when the input is changed to a valid date.
+///
+/// This is only available when the **crate feature `chrono`** is enabled
+#[component]
+pub fn NaiveDateInputTableCellRenderer<F>(
+    /// The class attribute for the cell element. Generated by the classes provider.
+    class: String,
+    /// The value to display.
+    #[prop(into)]
+    value: MaybeSignal<NaiveDate>,
+    /// Event handler called when the input is changed to a valid date.
+    on_change: F,
+    /// The index of the column. Starts at 0.
+    index: usize,
+) -> impl IntoView
+where
+    F: Fn(NaiveDate) + 'static,
+{
+    let input_ref = create_node_ref::<leptos::html::Input>();
+    super::input::focus_when_editing(index, input_ref);
+
+    let text = create_memo(move |_| value().format("%Y-%m-%d").to_string());
+
+    view! {
+        <td class=class role="cell">
+            <input
+                type="date"
+                node_ref=input_ref
+                prop:value=text
+                on:change=move |evt| {
+                    if let Ok(date) = NaiveDate::parse_from_str(&event_target_value(&evt), "%Y-%m-%d") {
+                        on_change(date);
+                    }
+                }
+            />
+        </td>
+    }
+}
@@ -0,0 +1,141 @@
+use crate::NONE_CELL_CLASS;
+use leptos::*;
+use std::collections::{BTreeSet, HashSet, VecDeque};
+use std::fmt::Display;
+
+/// Collections whose elements can be displayed in one cell by [`DefaultVecTableCellRenderer`].
+///
+/// Implemented for `Vec`, `VecDeque`, `BTreeSet` and `HashSet` of `Display` elements and for
+/// `Option`s of these. Elements of a `HashSet` are displayed in no particular order.
+pub trait CellCollection {
+    /// Joins the `Display` output of the elements with `separator`. Returns `None` if there are no
+    /// elements, i.e. if the collection is empty or `None`.
+    fn join_cell_items(&self, separator: &str) -> Option<String>;
+}
+
+fn join_items<T: Display>(items: impl Iterator<Item = T>, separator: &str) -> Option<String> {
+    let items = items.map(|item| item.to_string()).collect::<Vec<_>>();
+
+    (!items.is_empty()).then(|| items.join(separator))
+}
+
+impl<T: Display> CellCollection for Vec<T> {
+    fn join_cell_items(&self, separator: &str) -> Option<String> {
+        join_items(self.iter(), separator)
+    }
+}
+
+impl<T: Display> CellCollection for VecDeque<T> {
+    fn join_cell_items(&self, separator: &str) -> Option<String> {
+        join_items(self.iter(), separator)
+    }
+}
+
+impl<T: Display> CellCollection for BTreeSet<T> {
+    fn join_cell_items(&self, separator: &str) -> Option<String> {
+        join_items(self.iter(), separator)
+    }
+}
+
+impl<T: Display, S> CellCollection for HashSet<T, S> {
+    fn join_cell_items(&self, separator: &str) -> Option<String> {
+        join_items(self.iter(), separator)
+    }
+}
+
+impl<C: CellCollection> CellCollection for Option<C> {
+    fn join_cell_items(&self, separator: &str) -> Option<String> {
+        self.as_ref()?.join_cell_items(separator)
+    }
+}
+
+/// The default separator of [`DefaultVecTableCellRenderer`].
+pub const DEFAULT_CELL_ITEM_SEPARATOR: &str = ", ";
+
+/// A cell renderer for collection fields like tags or labels. It joins the `Display` output of the
+/// elements with the `separator` (defaults to [`DEFAULT_CELL_ITEM_SEPARATOR`]). Empty collections
+/// and `None` are displayed as the `none_value` and get the `none_class` like with [`OptionTableCellRenderer`].
+///
+/// [`DefaultTableCellRenderer`] delegates `Vec<String>` fields to this renderer, so they don't need
+/// a `renderer` attribute. For other collections (see [`CellCollection`]) and `Option`s of them set it
+/// as the `renderer` of the field. Since the `none_value` attribute isn't passed to renderers, wrap it
+/// to set the props.
+///
+/// # Sorting
+///
+/// The `TableRow` derive macro compares collection fields of `sortable` structs with their `PartialOrd`
+/// implementation, i.e. element by element like words in a dictionary. `HashSet`s can't be compared,
+/// so their fields need `skip_sort`. Add `skip_sort` to disable sorting of any collection column, or
+/// sort by the number of elements with the `sort_with` method of [`FilteredVecDataProvider`].
+///
+/// ```
+/// # use leptos::*;
+/// # use leptos_struct_table::*;
+/// # use std::collections::BTreeSet;
+/// #
+/// #[derive(TableRow, Clone)]
+/// #[table(sortable, impl_vec_data_provider)]
+/// pub struct Article {
+///     title: String,
+///     #[table(skip_sort)]
+///     tags: Vec<String>,             // -> "rust, web"
+///     #[table(skip_sort, renderer = "LabelsCellRenderer")]
+///     labels: Option<BTreeSet<u32>>, // -> "1 · 2" or "-"
+/// }
+///
+/// #[component]
+/// fn LabelsCellRenderer<F>(
+///     class: String,
+///     #[prop(into)] value: MaybeSignal<Option<BTreeSet<u32>>>,
+///     on_change: F,
+///     index: usize,
+/// ) -> impl IntoView
+/// where
+///     F: Fn(Option<BTreeSet<u32>>) + 'static,
+/// {
+///     view! {
+///         <DefaultVecTableCellRenderer class value on_change index separator=" · " none_value="-" />
+///     }
+/// }
+/// ```
+#[component]
+pub fn DefaultVecTableCellRenderer<T, F>(
+    /// The class attribute for the cell element. Generated by the classes provider.
+    class: String,
+    /// The value to display.
+    #[prop(into)]
+    value: MaybeSignal<T>,
+    /// Event handler called when the cell is changed. In this default renderer this will never happen.
+    on_change: F,
+    /// The index of the column. Starts at 0.
+    index: usize,
+    /// Inserted between the elements. Defaults to [`DEFAULT_CELL_ITEM_SEPARATOR`].
+    #[prop(default = DEFAULT_CELL_ITEM_SEPARATOR.to_string(), into)]
+    separator: String,
+    /// What to display if there are no elements. Defaults to an empty string.
+    #[prop(optional, into)]
+    none_value: String,
+    /// The classes that are added to the cell if there are no elements. Defaults to [`NONE_CELL_CLASS`].
+    #[prop(default = NONE_CELL_CLASS.to_string(), into)]
+    none_class: String,
+) -> impl IntoView
+where
+    T: CellCollection + Clone + 'static,
+    F: Fn(T) + 'static,
+{
+    let text = create_memo(move |_| value.with(|value| value.join_cell_items(&separator)));
+
+    let class = move || {
+        if text.with(Option::is_none) {
+            format!("{class} {none_class}")
+        } else {
+            class.clone()
+        }
+    };
+
+    view! {
+        <td class=class role="cell">
+            {move || text.get().unwrap_or_else(|| none_value.clone())}
+        </td>
+    }
+}
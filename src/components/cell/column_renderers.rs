@@ -0,0 +1,142 @@
+use crate::components::renderer_fn::renderer_fn;
+use crate::{ChangeEvent, EventHandler};
+use leptos::*;
+use std::collections::HashMap;
+use std::rc::Rc;
+
+renderer_fn!(
+    ColumnCellRendererFn<Row>(class: String, value: Row, on_change: Callback<Row>, index: usize)
+    where Row: 'static
+);
+
+/// Maps column indices (see [`TableRow::col_name`]) to cell renderers that replace the renderers
+/// generated by the `TableRow` derive macro at runtime. Pass it to the `cell_renderers` prop of
+/// [`TableContent`], e.g. to switch between a compact and a detailed layout without recompiling.
+///
+/// Columns without a registered renderer keep their derived renderer, so falling back to the
+/// default is just not registering a renderer for that column. If the prop is a signal, the cells
+/// of the rendered rows are rendered again when it changes.
+///
+/// ```
+/// # use leptos::*;
+/// # use leptos_struct_table::*;
+/// #
+/// #[derive(TableRow, Clone)]
+/// #[table(impl_vec_data_provider)]
+/// pub struct Contact {
+///     name: String,
+///     #[table(skip)]
+///     email: String,
+///     phone: String,
+/// }
+///
+/// #[component]
+/// fn App() -> impl IntoView {
+///     let (compact, set_compact) = create_signal(false);
+///
+///     let cell_renderers = Signal::derive(move || {
+///         if compact.get() {
+///             ColumnCellRenderers::new()
+///         } else {
+///             // shows the email below the name
+///             ColumnCellRenderers::new().register(0, |class, contact: Contact, _on_change, _index| {
+///                 view! {
+///                     <td class=class>
+///                         <div>{contact.name}</div>
+///                         <small>{contact.email}</small>
+///                     </td>
+///                 }
+///             })
+///         }
+///     });
+///
+///     let rows = vec![Contact {
+///         name: "Ada Lovelace".to_string(),
+///         email: "ada@example.com".to_string(),
+///         phone: "+44 20 1234 5678".to_string(),
+///     }];
+///
+///     view! {
+///         <button on:click=move |_| set_compact.update(|compact| *compact = !*compact)>"Density"</button>
+///         <table>
+///             <TableContent rows cell_renderers />
+///         </table>
+///     }
+/// }
+/// ```
+#[derive(Clone)]
+pub struct ColumnCellRenderers<Row: 'static>(HashMap<usize, ColumnCellRendererFn<Row>>);
+
+impl<Row: 'static> Default for ColumnCellRenderers<Row> {
+    fn default() -> Self {
+        Self(HashMap::new())
+    }
+}
+
+impl<Row: 'static> ColumnCellRenderers<Row> {
+    /// Creates an empty map. All columns use their derived renderers.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers `renderer` for the column `col_index`. Like the derived renderers it receives the
+    /// class of the cell, the value (here the whole row since the columns have different types),
+    /// a callback to report a changed row and the column index. It has to render the cell element
+    /// (e.g. `<td>`). Registering a renderer for the same column again replaces the previous one.
+    ///
+    /// The class is [`TableClassesProvider::cell`] without the `cell_class` of the field.
+    pub fn register<R, V>(mut self, col_index: usize, renderer: R) -> Self
+    where
+        R: Fn(String, Row, Callback<Row>, usize) -> V + 'static,
+        V: IntoView,
+    {
+        self.0
+            .insert(col_index, ColumnCellRendererFn::<Row>::from(renderer));
+        self
+    }
+
+    /// Returns `true` if a renderer has been registered for the column `col_index`.
+    pub fn contains(&self, col_index: usize) -> bool {
+        self.0.contains_key(&col_index)
+    }
+
+    /// Replaces the cells of the registered columns in `cells` which is one rendered row with one
+    /// node per column in the natural column order.
+    pub(crate) fn apply(
+        &self,
+        cells: View,
+        row: &Row,
+        row_index: usize,
+        class: &str,
+        on_change: &EventHandler<ChangeEvent<Row>>,
+    ) -> View
+    where
+        Row: Clone,
+    {
+        if self.0.is_empty() {
+            return cells;
+        }
+
+        match cells {
+            View::Component(mut fragment) => {
+                for (col_index, cell) in fragment.children.iter_mut().enumerate() {
+                    if let Some(renderer) = self.0.get(&col_index) {
+                        let on_change = on_change.clone();
+                        let on_change = Callback::new(move |changed_row| {
+                            on_change.run(ChangeEvent {
+                                row_index,
+                                col_index,
+                                changed_row,
+                            })
+                        });
+
+                        *cell = renderer.run(class.to_string(), row.clone(), on_change, col_index);
+                    }
+                }
+
+                View::Component(fragment)
+            }
+            cells => cells,
+        }
+    }
+}
@@ -0,0 +1,172 @@
+use leptos::*;
+use std::time::Duration;
+
+/// How [`DefaultDurationTableCellRenderer`] formats durations.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub enum DurationStyle {
+    /// The largest unit and the next smaller one if it isn't zero, e.g. `"2h 15m"`, `"1m"`, `"3d 4h"`
+    /// or `"12s 500ms"`. Durations below a second are shown in `ms`, `µs` or `ns`.
+    #[default]
+    Short,
+    /// Like `Short` but with the units written out, e.g. `"2 hours 15 minutes"` or `"350 milliseconds"`.
+    Long,
+    /// Hours, minutes and seconds like a stopwatch, e.g. `"2:15:00"` or `"0:00:00.350"`.
+    /// The hours aren't wrapped, so very large durations show `"1234:56:07"`.
+    Clock,
+}
+
+const UNITS: [(u128, &str, &str); 7] = [
+    (86_400_000_000_000, "d", "day"),
+    (3_600_000_000_000, "h", "hour"),
+    (60_000_000_000, "m", "minute"),
+    (1_000_000_000, "s", "second"),
+    (1_000_000, "ms", "millisecond"),
+    (1_000, "µs", "microsecond"),
+    (1, "ns", "nanosecond"),
+];
+
+impl DurationStyle {
+    /// Formats `duration` in this style.
+    ///
+    /// ```
+    /// # use leptos_struct_table::*;
+    /// # use std::time::Duration;
+    /// #
+    /// let duration = Duration::from_secs(2 * 3600 + 15 * 60 + 7);
+    ///
+    /// assert_eq!(DurationStyle::Short.format(duration), "2h 15m");
+    /// assert_eq!(DurationStyle::Long.format(duration), "2 hours 15 minutes");
+    /// assert_eq!(DurationStyle::Clock.format(duration), "2:15:07");
+    ///
+    /// assert_eq!(DurationStyle::Short.format(Duration::from_millis(350)), "350ms");
+    /// assert_eq!(DurationStyle::Short.format(Duration::ZERO), "0s");
+    /// assert_eq!(DurationStyle::Short.format(Duration::MAX), "213503982334601d 7h");
+    /// ```
+    pub fn format(&self, duration: Duration) -> String {
+        let nanos = duration.as_nanos();
+
+        if *self == DurationStyle::Clock {
+            let secs = duration.as_secs();
+            let clock = format!("{}:{:02}:{:02}", secs / 3600, secs / 60 % 60, secs % 60);
+
+            return match duration.subsec_millis() {
+                0 => clock,
+                millis => format!("{clock}.{millis:03}"),
+            };
+        }
+
+        let unit = |value: u128, (_, short, long): (u128, &str, &str)| match self {
+            DurationStyle::Long if value == 1 => format!("{value} {long}"),
+            DurationStyle::Long => format!("{value} {long}s"),
+            _ => format!("{value}{short}"),
+        };
+
+        // the largest unit that isn't zero, or seconds for zero durations
+        let Some(largest) = UNITS.iter().position(|(size, _, _)| nanos >= *size) else {
+            return unit(0, UNITS[3]);
+        };
+
+        let (size, _, _) = UNITS[largest];
+        let mut text = unit(nanos / size, UNITS[largest]);
+
+        // fractions of seconds are only shown down to the unit after seconds
+        if let Some(&next) = UNITS.get(largest + 1).filter(|_| largest <= 3) {
+            let value = nanos % size / next.0;
+            if value > 0 {
+                text = format!("{text} {}", unit(value, next));
+            }
+        }
+
+        text
+    }
+}
+
+/// Durations that can be displayed by [`DefaultDurationTableCellRenderer`].
+///
+/// Implemented for [`std::time::Duration`] and, with the **crate feature `chrono`**, for
+/// [`chrono::Duration`] whose negative values are prefixed with `-`.
+pub trait CellDuration {
+    /// Formats the duration in `style`.
+    fn format_duration(&self, style: DurationStyle) -> String;
+}
+
+impl CellDuration for Duration {
+    fn format_duration(&self, style: DurationStyle) -> String {
+        style.format(*self)
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl CellDuration for chrono::Duration {
+    fn format_duration(&self, style: DurationStyle) -> String {
+        let text = style.format(self.abs().to_std().unwrap_or_default());
+
+        if *self < chrono::Duration::zero() {
+            format!("-{text}")
+        } else {
+            text
+        }
+    }
+}
+
+/// Cell renderer for elapsed times. Formats [`std::time::Duration`]s (and with the **crate feature
+/// `chrono`** [`chrono::Duration`]s) in the [`DurationStyle`] `style`, by default like `"2h 15m"`.
+///
+/// Durations are `Ord`, so `sortable` structs sort these columns by the actual duration and not by
+/// the displayed text. Since only the standard props are passed to renderers, wrap it to set `style`.
+///
+/// ```
+/// # use leptos::*;
+/// # use leptos_struct_table::*;
+/// # use std::time::Duration;
+/// #
+/// #[derive(TableRow, Clone)]
+/// #[table(sortable, impl_vec_data_provider)]
+/// pub struct Job {
+///     name: String,
+///     #[table(renderer = "DefaultDurationTableCellRenderer")]
+///     elapsed: Duration,                // -> "2h 15m"
+///     #[table(renderer = "ClockCellRenderer")]
+///     cpu_time: Duration,               // -> "2:15:07"
+/// }
+///
+/// #[component]
+/// fn ClockCellRenderer<F>(
+///     class: String,
+///     #[prop(into)] value: MaybeSignal<Duration>,
+///     on_change: F,
+///     index: usize,
+/// ) -> impl IntoView
+/// where
+///     F: Fn(Duration) + 'static,
+/// {
+///     view! {
+///         <DefaultDurationTableCellRenderer class value on_change index style=DurationStyle::Clock />
+///     }
+/// }
+/// ```
+#[component]
+pub fn DefaultDurationTableCellRenderer<T, F>(
+    /// The class attribute for the cell element. Generated by the classes provider.
+    class: String,
+    /// The value to display.
+    #[prop(into)]
+    value: MaybeSignal<T>,
+    /// Event handler called when the cell is changed. In this default renderer this will never happen.
+    on_change: F,
+    /// The index of the column. Starts at 0.
+    index: usize,
+    /// How the duration is formatted. Defaults to [`DurationStyle::Short`].
+    #[prop(optional)]
+    style: DurationStyle,
+) -> impl IntoView
+where
+    T: CellDuration + Clone + 'static,
+    F: Fn(T) + 'static,
+{
+    let text = create_memo(move |_| value.with(|value| value.format_duration(style)));
+
+    view! {
+        <td class=class role="cell">{text}</td>
+    }
+}
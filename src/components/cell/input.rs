@@ -0,0 +1,225 @@
+use crate::{use_cell_editing, use_cell_input};
+use leptos::html::{ElementDescriptor, Input, Select};
+use leptos::*;
+use std::fmt::Display;
+use std::ops::Deref;
+use std::str::FromStr;
+
+/// Focuses the input referenced by `input_ref` when the cell is opened through [`crate::TableController::edit_cell`].
+pub(crate) fn focus_when_editing<El>(col_index: usize, input_ref: NodeRef<El>)
+where
+    El: ElementDescriptor + Deref + Clone + 'static,
+    El::Target: AsRef<web_sys::HtmlElement>,
+{
+    let editing = use_cell_editing(col_index);
+
+    create_effect(move |_| {
+        if editing.get() {
+            if let Some(input) = input_ref.get() {
+                let _ = input.as_ref().focus();
+            }
+        }
+    });
+}
+
+/// Editable cell renderer for text. Renders an `<input type="text">` and emits `on_change` when the input is changed,
+/// i.e. on blur or Enter. Every keystroke emits the `on_cell_input` event of [`TableContent`] (see [`use_cell_input`]).
+#[component]
+pub fn TextInputTableCellRenderer<F>(
+    /// The class attribute for the cell element. Generated by the classes provider.
+    class: String,
+    /// The value to display.
+    #[prop(into)]
+    value: MaybeSignal<String>,
+    /// Event handler called when the input is changed.
+    on_change: F,
+    /// The index of the column. Starts at 0.
+    index: usize,
+) -> impl IntoView
+where
+    F: Fn(String) + 'static,
+{
+    let input_ref = create_node_ref::<Input>();
+    focus_when_editing(index, input_ref);
+    let on_input = use_cell_input(index);
+
+    view! {
+        <td class=class role="cell">
+            <input
+                type="text"
+                node_ref=input_ref
+                prop:value=value
+                on:input=move |evt| on_input(event_target_value(&evt))
+                on:change=move |evt| on_change(event_target_value(&evt))
+            />
+        </td>
+    }
+}
+
+/// Editable cell renderer for numbers. Renders an `<input type="number">` and emits `on_change` when
+/// the input is changed to a value that can be parsed as `T`. Other input is ignored. Every keystroke emits
+/// the `on_cell_input` event of [`TableContent`] with the raw text, e.g. to show that it isn't a valid number.
+#[component]
+pub fn NumberInputTableCellRenderer<T, F>(
+    /// The class attribute for the cell element. Generated by the classes provider.
+    class: String,
+    /// The value to display.
+    #[prop(into)]
+    value: MaybeSignal<T>,
+    /// Event handler called when the input is changed to a valid number.
+    on_change: F,
+    /// The index of the column. Starts at 0.
+    index: usize,
+    /// The number of digits to display after the decimal point. Provided by the `#[table(format(precision=X))]` attribute of the field.
+    #[prop(optional)]
+    precision: Option<usize>,
+) -> impl IntoView
+where
+    T: Display + FromStr + Clone + 'static,
+    F: Fn(T) + 'static,
+{
+    let input_ref = create_node_ref::<Input>();
+    focus_when_editing(index, input_ref);
+    let on_input = use_cell_input(index);
+
+    let text = create_memo(move |_| match precision {
+        Some(precision) => format!("{:.precision$}", value()),
+        None => format!("{}", value()),
+    });
+
+    view! {
+        <td class=class role="cell">
+            <input
+                type="number"
+                node_ref=input_ref
+                prop:value=text
+                on:input=move |evt| on_input(event_target_value(&evt))
+                on:change=move |evt| {
+                    if let Ok(value) = event_target_value(&evt).parse::<T>() {
+                        on_change(value);
+                    }
+                }
+            />
+        </td>
+    }
+}
+
+/// Editable cell renderer for `bool`s. Renders an `<input type="checkbox">` and emits `on_change` when it's toggled.
+#[component]
+pub fn CheckboxInputTableCellRenderer<F>(
+    /// The class attribute for the cell element. Generated by the classes provider.
+    class: String,
+    /// The value to display.
+    #[prop(into)]
+    value: MaybeSignal<bool>,
+    /// Event handler called when the checkbox is toggled.
+    on_change: F,
+    /// The index of the column. Starts at 0.
+    index: usize,
+) -> impl IntoView
+where
+    F: Fn(bool) + 'static,
+{
+    let input_ref = create_node_ref::<Input>();
+    focus_when_editing(index, input_ref);
+
+    view! {
+        <td class=class role="cell">
+            <input
+                type="checkbox"
+                node_ref=input_ref
+                prop:checked=value
+                on:change=move |evt| on_change(event_target_checked(&evt))
+            />
+        </td>
+    }
+}
+
+/// The allowed values of a field that is edited with a [`SelectTableCellRenderer`].
+///
+/// ```
+/// # use leptos_struct_table::*;
+/// # use std::fmt::{Display, Formatter};
+/// #
+/// #[derive(Clone, PartialEq, Debug)]
+/// pub enum Status {
+///     Open,
+///     Closed,
+/// }
+///
+/// impl Display for Status {
+///     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+///         write!(f, "{:?}", self)
+///     }
+/// }
+///
+/// impl SelectOptions for Status {
+///     fn options() -> Vec<Self> {
+///         vec![Status::Open, Status::Closed]
+///     }
+/// }
+/// ```
+pub trait SelectOptions: Sized {
+    /// The values to choose from in the order they appear in the `<select>`.
+    fn options() -> Vec<Self>;
+}
+
+/// Editable cell renderer for fields with a fixed set of values. Renders a `<select>` with
+/// one `<option>` per value of [`SelectOptions::options`], labeled with its `Display` output.
+///
+/// `on_change` is only ever emitted with one of these values.
+#[component]
+pub fn SelectTableCellRenderer<T, F>(
+    /// The class attribute for the cell element. Generated by the classes provider.
+    class: String,
+    /// The value to display.
+    #[prop(into)]
+    value: MaybeSignal<T>,
+    /// Event handler called when another option is selected.
+    on_change: F,
+    /// The index of the column. Starts at 0.
+    index: usize,
+) -> impl IntoView
+where
+    T: SelectOptions + Display + PartialEq + Clone + 'static,
+    F: Fn(T) + 'static,
+{
+    let select_ref = create_node_ref::<Select>();
+    focus_when_editing(index, select_ref);
+
+    let options = store_value(T::options());
+
+    view! {
+        <td class=class role="cell">
+            <select
+                node_ref=select_ref
+                on:change=move |evt| {
+                    let option = event_target_value(&evt)
+                        .parse::<usize>()
+                        .ok()
+                        .and_then(|i| options.with_value(|options| options.get(i).cloned()));
+
+                    if let Some(option) = option {
+                        on_change(option);
+                    }
+                }
+            >
+                {options
+                    .get_value()
+                    .into_iter()
+                    .enumerate()
+                    .map(|(i, option)| {
+                        let label = option.to_string();
+                        let value = value.clone();
+
+                        view! {
+                            <option value=i.to_string() prop:selected=move || value.with(|v| *v == option)>
+                                {label}
+                            </option>
+                        }
+                    })
+                    .collect_view()}
+            </select>
+        </td>
+    }
+}
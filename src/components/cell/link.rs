@@ -0,0 +1,237 @@
+use leptos::*;
+
+/// Cell renderer for URLs. Renders the value as a link that opens in a new tab
+/// (`target="_blank" rel="noopener"`).
+///
+/// The href is percent-encoded. Only `http` and `https` URLs as well as relative URLs are linked,
+/// values with any other scheme (like `javascript:`) are displayed as plain text.
+///
+/// ```
+/// # use leptos::*;
+/// # use leptos_struct_table::*;
+/// #
+/// #[derive(TableRow, Clone)]
+/// #[table(impl_vec_data_provider)]
+/// pub struct Bookmark {
+///     title: String,
+///     #[table(renderer = "DefaultUrlTableCellRenderer")]
+///     url: String,
+///     #[table(renderer = "DefaultEmailTableCellRenderer")]
+///     author: String,
+/// }
+/// ```
+///
+/// To shorten long URLs set `max_len` in a wrapping renderer. The full URL is still used for the
+/// href and shown as tooltip.
+///
+/// ```
+/// # use leptos::*;
+/// # use leptos_struct_table::*;
+/// #
+/// #[component]
+/// fn ShortUrlCellRenderer<F>(
+///     class: String,
+///     #[prop(into)] value: MaybeSignal<String>,
+///     on_change: F,
+///     index: usize,
+/// ) -> impl IntoView
+/// where
+///     F: Fn(String) + 'static,
+/// {
+///     view! { <DefaultUrlTableCellRenderer class value on_change index max_len=30 /> }
+/// }
+/// ```
+#[component]
+pub fn DefaultUrlTableCellRenderer<F>(
+    /// The class attribute for the cell element. Generated by the classes provider.
+    class: String,
+    /// The URL to display.
+    #[prop(into)]
+    value: MaybeSignal<String>,
+    /// Event handler called when the cell is changed. In this default renderer this will never happen.
+    on_change: F,
+    /// The index of the column. Starts at 0.
+    index: usize,
+    /// The maximum number of characters of the displayed label. Longer URLs are truncated with `…`.
+    #[prop(optional)]
+    max_len: Option<usize>,
+) -> impl IntoView
+where
+    F: Fn(String) + 'static,
+{
+    let link = move || {
+        let url = value.get();
+        let href = encode_href(url.trim());
+
+        if !is_safe_href(&href) {
+            return url.into_view();
+        }
+
+        let label = truncate_label(&url, max_len);
+
+        view! {
+            <a href=href target="_blank" rel="noopener" title=url>
+                {label}
+            </a>
+        }
+        .into_view()
+    };
+
+    view! {
+        <td class=class role="cell">{link}</td>
+    }
+}
+
+/// Cell renderer for email addresses. Renders the value as a `mailto:` link.
+///
+/// The address is percent-encoded in the href. Values without an `@` are displayed as plain text.
+/// See [`DefaultUrlTableCellRenderer`] for an example.
+#[component]
+pub fn DefaultEmailTableCellRenderer<F>(
+    /// The class attribute for the cell element. Generated by the classes provider.
+    class: String,
+    /// The email address to display.
+    #[prop(into)]
+    value: MaybeSignal<String>,
+    /// Event handler called when the cell is changed. In this default renderer this will never happen.
+    on_change: F,
+    /// The index of the column. Starts at 0.
+    index: usize,
+    /// The maximum number of characters of the displayed label. Longer addresses are truncated with `…`.
+    #[prop(optional)]
+    max_len: Option<usize>,
+) -> impl IntoView
+where
+    F: Fn(String) + 'static,
+{
+    let link = move || {
+        let email = value.get();
+        let address = email.trim();
+
+        if !address.contains('@') {
+            return email.into_view();
+        }
+
+        let href = format!("mailto:{}", encode_component(address));
+        let label = truncate_label(&email, max_len);
+
+        view! {
+            <a href=href title=email>
+                {label}
+            </a>
+        }
+        .into_view()
+    };
+
+    view! {
+        <td class=class role="cell">{link}</td>
+    }
+}
+
+/// Percent-encodes all characters that aren't allowed in a URL as well as quotes. Reserved
+/// characters like `/`, `?` and `&` as well as existing escapes are kept.
+fn encode_href(url: &str) -> String {
+    encode(url, |byte| {
+        byte.is_ascii_alphanumeric() || b"-._~:/?#[]@!$&()*+,;=%".contains(&byte)
+    })
+}
+
+/// Percent-encodes everything but unreserved characters and `@`.
+fn encode_component(component: &str) -> String {
+    encode(component, |byte| {
+        byte.is_ascii_alphanumeric() || b"-._~@".contains(&byte)
+    })
+}
+
+fn encode(value: &str, keep: impl Fn(u8) -> bool) -> String {
+    let mut encoded = String::with_capacity(value.len());
+
+    for byte in value.bytes() {
+        if keep(byte) {
+            encoded.push(byte as char);
+        } else {
+            encoded.push_str(&format!("%{byte:02X}"));
+        }
+    }
+
+    encoded
+}
+
+/// Whether the (encoded) href is a relative URL or has the scheme `http` or `https`.
+fn is_safe_href(href: &str) -> bool {
+    let scheme_end = href.find([':', '/', '?', '#']);
+
+    match scheme_end {
+        Some(end) if href[end..].starts_with(':') => {
+            let scheme = &href[..end];
+            scheme.eq_ignore_ascii_case("http") || scheme.eq_ignore_ascii_case("https")
+        }
+        _ => true,
+    }
+}
+
+fn truncate_label(label: &str, max_len: Option<usize>) -> String {
+    match max_len {
+        Some(max_len) if label.chars().count() > max_len => {
+            let mut truncated = label
+                .chars()
+                .take(max_len.saturating_sub(1))
+                .collect::<String>();
+            truncated.push('…');
+            truncated
+        }
+        _ => label.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Same check as in [`DefaultUrlTableCellRenderer`].
+    fn is_linked(url: &str) -> bool {
+        is_safe_href(&encode_href(url.trim()))
+    }
+
+    #[test]
+    fn rejects_other_schemes() {
+        assert!(!is_linked("javascript:alert(1)"));
+        assert!(!is_linked("JavaScript:alert(1)"));
+        assert!(!is_linked("  javascript:alert(1)"));
+        assert!(!is_linked("\u{1}javascript:alert(1)"));
+        assert!(!is_linked("java\tscript:alert(1)"));
+        assert!(!is_linked("java\nscript:alert(1)"));
+        assert!(!is_linked("data:text/html,<script>alert(1)</script>"));
+    }
+
+    #[test]
+    fn accepts_relative_and_http_urls() {
+        assert!(is_linked("/docs/index.html"));
+        assert!(is_linked("docs?page=1"));
+        assert!(is_linked("#section"));
+        assert!(is_linked("/time/12:00"));
+        assert!(is_linked("http://example.com"));
+        assert!(is_linked("HTTPS://example.com/path?q=1#top"));
+    }
+
+    #[test]
+    fn encodes_unsafe_characters() {
+        assert_eq!(
+            encode_href(r#"https://example.com/a b"c'd<e>"#),
+            "https://example.com/a%20b%22c%27d%3Ce%3E"
+        );
+        assert_eq!(
+            encode_href("https://example.com/?q=a%20b&r=ä"),
+            "https://example.com/?q=a%20b&r=%C3%A4"
+        );
+    }
+
+    #[test]
+    fn truncates_multi_byte_labels() {
+        assert_eq!(truncate_label("äöüß", Some(4)), "äöüß");
+        assert_eq!(truncate_label("äöüßé", Some(4)), "äöü…");
+        assert_eq!(truncate_label("😀😀😀", Some(2)), "😀…");
+        assert_eq!(truncate_label("😀😀😀", None), "😀😀😀");
+        assert_eq!(truncate_label("äöü", Some(0)), "…");
+    }
+}
@@ -1,14 +1,37 @@
 #![allow(unused_variables)]
 
+mod badge;
+pub use self::badge::*;
 #[cfg(feature = "chrono")]
 mod chrono;
 #[cfg(feature = "chrono")]
 pub use self::chrono::*;
+mod collection;
+pub use self::collection::*;
+mod column_renderers;
+pub use self::column_renderers::*;
+mod duration;
+pub use self::duration::*;
+mod input;
+pub use self::input::*;
+mod link;
+pub use self::link::*;
+mod number;
+pub use self::number::*;
+mod progress;
+pub use self::progress::*;
+mod type_renderers;
+pub use self::type_renderers::*;
 
 use core::fmt::Display;
 use leptos::*;
+use std::any::Any;
 
 /// The default cell renderer. Uses the `<td>` element.
+///
+/// If a renderer has been registered for the type `T` in the [`TypeCellRenderers`] provided as
+/// context, that renderer is used instead. Otherwise `bool`s are rendered with [`DefaultBoolTableCellRenderer`]
+/// and `Vec<String>`s with [`DefaultVecTableCellRenderer`].
 #[component]
 pub fn DefaultTableCellRenderer<T, F>(
     /// The class attribute for the cell element. Generated by the classes provider.
@@ -25,12 +48,135 @@ where
     T: IntoView + Clone + 'static,
     F: Fn(T) + 'static,
 {
+    if let Some(view) = use_context::<TypeCellRenderers>()
+        .and_then(|renderers| renderers.render(class.clone(), value.clone(), index))
+    {
+        return view;
+    }
+
+    if let Some(value) = (&value as &dyn Any).downcast_ref::<MaybeSignal<bool>>() {
+        let on_change = move |value: bool| {
+            if let Ok(value) = (Box::new(value) as Box<dyn Any>).downcast::<T>() {
+                on_change(*value);
+            }
+        };
+
+        return view! {
+            <DefaultBoolTableCellRenderer class value=*value on_change index />
+        }
+        .into_view();
+    }
+
+    if let Some(value) = (&value as &dyn Any).downcast_ref::<MaybeSignal<Vec<String>>>() {
+        let value = value.clone();
+        let on_change = |_: Vec<String>| {};
+
+        return view! {
+            <DefaultVecTableCellRenderer class value on_change index />
+        }
+        .into_view();
+    }
+
     view! {
-        <td class=class>{value}</td>
+        <td class=class role="cell">{value}</td>
+    }
+    .into_view()
+}
+
+/// The default cell renderer for `bool`s. Renders a disabled `<input type="checkbox">` inside of a `<td>`.
+///
+/// The `TableRow` derive macro uses [`DefaultTableCellRenderer`] for `bool` fields (and `Option<bool>`
+/// fields where `None` is rendered as the `none_value`) which delegates to this renderer.
+///
+/// To be able to toggle the checkbox set `editable` to `true`, e.g. in a custom renderer that wraps
+/// this one, or use [`CheckboxInputTableCellRenderer`] through the `renderer` attribute of the field.
+/// Then `on_change` is called when the checkbox is toggled.
+///
+/// ```
+/// # use leptos::*;
+/// # use leptos_struct_table::*;
+/// #
+/// #[derive(TableRow, Clone)]
+/// #[table(impl_vec_data_provider)]
+/// pub struct Task {
+///     title: String,
+///     done: bool,                    // -> disabled checkbox
+///     #[table(none_value = "-")]
+///     reviewed: Option<bool>,        // -> disabled checkbox or "-"
+/// }
+/// ```
+#[component]
+pub fn DefaultBoolTableCellRenderer<F>(
+    /// The class attribute for the cell element. Generated by the classes provider.
+    class: String,
+    /// The value to display.
+    #[prop(into)]
+    value: MaybeSignal<bool>,
+    /// Event handler called when the checkbox is toggled. Only happens if `editable` is `true`.
+    on_change: F,
+    /// The index of the column. Starts at 0.
+    index: usize,
+    /// If `true` the checkbox can be toggled. Defaults to `false`.
+    #[prop(optional)]
+    editable: bool,
+) -> impl IntoView
+where
+    F: Fn(bool) + 'static,
+{
+    view! {
+        <td class=class role="cell">
+            <input
+                type="checkbox"
+                prop:checked=value
+                disabled=!editable
+                on:change=move |evt| on_change(event_target_checked(&evt))
+            />
+        </td>
     }
 }
 
 /// The default number cell renderer. Uses the `<td>` element.
+///
+/// Besides the `precision` the digits can be grouped and a prefix and suffix can be added, e.g. for
+/// money columns. See [`NumberFormat`] for how these options are combined. Set them in a wrapping
+/// renderer:
+///
+/// ```
+/// # use leptos::*;
+/// # use leptos_struct_table::*;
+/// #
+/// #[derive(TableRow, Clone)]
+/// #[table(impl_vec_data_provider)]
+/// pub struct Invoice {
+///     id: u32,
+///     #[table(renderer = "UsdCellRenderer")]
+///     total: f64,
+/// }
+///
+/// #[component]
+/// fn UsdCellRenderer<F>(
+///     class: String,
+///     #[prop(into)] value: MaybeSignal<f64>,
+///     on_change: F,
+///     index: usize,
+/// ) -> impl IntoView
+/// where
+///     F: Fn(f64) + 'static,
+/// {
+///     view! {
+///         <DefaultNumberTableCellRenderer
+///             class
+///             value
+///             on_change
+///             index
+///             precision=2
+///             thousands_separator=","
+///             prefix="$"
+///             suffix=" USD"
+///         />
+///     }
+/// }
+/// ```
 #[component]
 pub fn DefaultNumberTableCellRenderer<T, F>(
     /// The class attribute for the cell element. Generated by the classes provider.
@@ -45,17 +191,126 @@ pub fn DefaultNumberTableCellRenderer<T, F>(
     /// The number of digits to display after the decimal point. Provided by the `#[table(format(precision=X))]` attribute of the field.
     #[prop(optional)]
     precision: Option<usize>,
+    /// Inserted between each group of three digits before the decimal point, e.g. `","`.
+    #[prop(optional, into)]
+    thousands_separator: Option<String>,
+    /// Displayed in front of the number but after the minus sign, e.g. `"$"`.
+    #[prop(optional, into)]
+    prefix: Option<String>,
+    /// Displayed after the number, e.g. `" USD"`.
+    #[prop(optional, into)]
+    suffix: Option<String>,
 ) -> impl IntoView
 where
     T: Display + Clone + 'static,
     F: Fn(T) + 'static,
 {
-    let text = create_memo(move |_| match precision {
-        Some(precision) => format!("{:.precision$}", value()),
-        None => format!("{}", value()),
-    });
+    let mut format = NumberFormat::new();
+    if let Some(precision) = precision {
+        format = format.precision(precision);
+    }
+    if let Some(thousands_separator) = thousands_separator {
+        format = format.thousands_separator(thousands_separator);
+    }
+    if let Some(prefix) = prefix {
+        format = format.prefix(prefix);
+    }
+    if let Some(suffix) = suffix {
+        format = format.suffix(suffix);
+    }
+
+    let text = create_memo(move |_| format.format(value()));
+
+    view! {
+        <td class=class role="cell">{text}</td>
+    }
+}
+
+/// The class that [`OptionTableCellRenderer`] adds to the `<td>` if the value is `None`.
+pub const NONE_CELL_CLASS: &str = "none-cell";
+
+/// A cell renderer for `Option` fields that styles empty cells differently. If the value is `None`,
+/// the `none_value` is displayed and `none_class` is added to the class of the `<td>`. Both follow
+/// the value, so an edited cell switches between the two states.
+///
+/// Use it with the `renderer` attribute of the field. Since the `none_value` attribute isn't passed
+/// to renderers, wrap it to set the props:
+///
+/// ```
+/// # use leptos::*;
+/// # use leptos_struct_table::*;
+/// #
+/// #[derive(TableRow, Clone)]
+/// #[table(impl_vec_data_provider)]
+/// pub struct Person {
+///     name: String,
+///     #[table(renderer = "MutedOptionCellRenderer")]
+///     nickname: Option<String>,
+/// }
+///
+/// #[component]
+/// fn MutedOptionCellRenderer<F>(
+///     class: String,
+///     #[prop(into)] value: MaybeSignal<Option<String>>,
+///     on_change: F,
+///     index: usize,
+/// ) -> impl IntoView
+/// where
+///     F: Fn(Option<String>) + 'static,
+/// {
+///     view! {
+///         <OptionTableCellRenderer
+///             class
+///             value
+///             on_change
+///             index
+///             none_value="-"
+///             none_class="text-gray-400"
+///         />
+///     }
+/// }
+/// ```
+#[component]
+pub fn OptionTableCellRenderer<T, F>(
+    /// The class attribute for the cell element. Generated by the classes provider.
+    class: String,
+    /// The value to display.
+    #[prop(into)]
+    value: MaybeSignal<Option<T>>,
+    /// Event handler called when the cell is changed. In this default renderer this will never happen.
+    on_change: F,
+    /// The index of the column. Starts at 0.
+    index: usize,
+    /// What to display if the value is `None`. Defaults to an empty string.
+    #[prop(optional, into)]
+    none_value: String,
+    /// The classes that are added to the cell if the value is `None`. Defaults to [`NONE_CELL_CLASS`].
+    #[prop(default = NONE_CELL_CLASS.to_string(), into)]
+    none_class: String,
+) -> impl IntoView
+where
+    T: IntoView + Clone + 'static,
+    F: Fn(Option<T>) + 'static,
+{
+    let is_none = {
+        let value = value.clone();
+        create_memo(move |_| value.with(Option::is_none))
+    };
+
+    let class = move || {
+        if is_none.get() {
+            format!("{class} {none_class}")
+        } else {
+            class.clone()
+        }
+    };
+
+    let text = move || match value.get() {
+        Some(value) => value.into_view(),
+        None => none_value.clone().into_view(),
+    };
 
     view! {
-        <td class=class>{text}</td>
+        <td class=class role="cell">{text}</td>
     }
 }
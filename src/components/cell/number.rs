@@ -0,0 +1,102 @@
+use core::fmt::Display;
+
+/// Formatting options of numbers as used by [`DefaultNumberTableCellRenderer`]. Formatting only
+/// depends on these options, there is no global locale.
+///
+/// The value is first formatted with `precision` digits after the decimal point. Then the digits
+/// before the decimal point are grouped in threes by the `thousands_separator` and the `prefix`
+/// and `suffix` are added. The minus sign of negative numbers is placed in front of the prefix.
+///
+/// ```
+/// # use leptos_struct_table::*;
+/// #
+/// let format = NumberFormat::new()
+///     .precision(2)
+///     .thousands_separator(",")
+///     .prefix("$")
+///     .suffix(" USD");
+///
+/// assert_eq!(format.format(-1234567.5), "-$1,234,567.50 USD");
+/// assert_eq!(format.format(999.999), "$1,000.00 USD");
+/// assert_eq!(format.format(0.5), "$0.50 USD");
+///
+/// let format = NumberFormat::new().thousands_separator("'");
+///
+/// assert_eq!(format.format(-1234567), "-1'234'567");
+/// assert_eq!(format.format(123), "123");
+/// assert_eq!(format.format(f64::NAN), "NaN");
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct NumberFormat {
+    precision: Option<usize>,
+    thousands_separator: Option<String>,
+    prefix: Option<String>,
+    suffix: Option<String>,
+}
+
+impl NumberFormat {
+    /// Creates a format that displays numbers like [`Display`] does.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The number of digits to display after the decimal point.
+    pub fn precision(mut self, precision: usize) -> Self {
+        self.precision = Some(precision);
+        self
+    }
+
+    /// Inserted between each group of three digits before the decimal point.
+    pub fn thousands_separator(mut self, separator: impl Into<String>) -> Self {
+        self.thousands_separator = Some(separator.into());
+        self
+    }
+
+    /// Displayed in front of the number but after the minus sign, e.g. a currency symbol.
+    pub fn prefix(mut self, prefix: impl Into<String>) -> Self {
+        self.prefix = Some(prefix.into());
+        self
+    }
+
+    /// Displayed after the number, e.g. a unit.
+    pub fn suffix(mut self, suffix: impl Into<String>) -> Self {
+        self.suffix = Some(suffix.into());
+        self
+    }
+
+    /// Formats `value` with these options.
+    pub fn format(&self, value: impl Display) -> String {
+        let text = match self.precision {
+            Some(precision) => format!("{value:.precision$}"),
+            None => value.to_string(),
+        };
+
+        let (sign, number) = match text.strip_prefix('-') {
+            Some(number) => ("-", number),
+            None => ("", text.as_str()),
+        };
+
+        let (integer, fraction) = number.split_at(number.find('.').unwrap_or(number.len()));
+
+        let mut formatted = String::with_capacity(text.len() + 8);
+        formatted.push_str(sign);
+        formatted.push_str(self.prefix.as_deref().unwrap_or_default());
+
+        match &self.thousands_separator {
+            Some(separator) if integer.bytes().all(|byte| byte.is_ascii_digit()) => {
+                for (i, digit) in integer.chars().enumerate() {
+                    if i > 0 && (integer.len() - i) % 3 == 0 {
+                        formatted.push_str(separator);
+                    }
+                    formatted.push(digit);
+                }
+            }
+            _ => formatted.push_str(integer),
+        }
+
+        formatted.push_str(fraction);
+        formatted.push_str(self.suffix.as_deref().unwrap_or_default());
+
+        formatted
+    }
+}
@@ -0,0 +1,106 @@
+use crate::NumberFormat;
+use leptos::*;
+
+/// The default `max` of [`DefaultProgressTableCellRenderer`], i.e. values are percentages.
+pub const DEFAULT_PROGRESS_MAX: f64 = 100.0;
+
+/// Cell renderer for completion values. Renders a `<progress>` element that is filled to `value / max`.
+/// Values below 0 are shown as empty and values above `max` as full. `NaN` is treated as 0.
+///
+/// The element has the ARIA attributes `aria-valuemin`, `aria-valuemax` and `aria-valuenow` with the
+/// clamped value, so screen readers announce the progress. With `show_label` the percentage is
+/// displayed next to the bar (with `precision` digits after the decimal point, by default none).
+///
+/// ```
+/// # use leptos::*;
+/// # use leptos_struct_table::*;
+/// #
+/// #[derive(TableRow, Clone)]
+/// #[table(impl_vec_data_provider)]
+/// pub struct Task {
+///     title: String,
+///     #[table(renderer = "DefaultProgressTableCellRenderer")]
+///     percent_done: u8,                // -> 0..100
+///     #[table(renderer = "RatioProgressCellRenderer")]
+///     ratio_done: f64,                 // -> 0..1 with the percentage as label
+/// }
+///
+/// #[component]
+/// fn RatioProgressCellRenderer<F>(
+///     class: String,
+///     #[prop(into)] value: MaybeSignal<f64>,
+///     on_change: F,
+///     index: usize,
+/// ) -> impl IntoView
+/// where
+///     F: Fn(f64) + 'static,
+/// {
+///     view! {
+///         <DefaultProgressTableCellRenderer class value on_change index max=1.0 show_label=true />
+///     }
+/// }
+/// ```
+#[component]
+pub fn DefaultProgressTableCellRenderer<T, F>(
+    /// The class attribute for the cell element. Generated by the classes provider.
+    class: String,
+    /// The value to display.
+    #[prop(into)]
+    value: MaybeSignal<T>,
+    /// Event handler called when the cell is changed. In this default renderer this will never happen.
+    on_change: F,
+    /// The index of the column. Starts at 0.
+    index: usize,
+    /// The value of a full bar. Defaults to [`DEFAULT_PROGRESS_MAX`]. Use `1.0` for ratios.
+    #[prop(default = DEFAULT_PROGRESS_MAX)]
+    max: f64,
+    /// If `true` the percentage is displayed next to the bar. Defaults to `false`.
+    #[prop(optional)]
+    show_label: bool,
+    /// The number of digits after the decimal point of the label. Provided by the `#[table(format(precision=X))]` attribute of the field.
+    #[prop(optional)]
+    precision: Option<usize>,
+) -> impl IntoView
+where
+    T: Into<f64> + Clone + 'static,
+    F: Fn(T) + 'static,
+{
+    let clamped = create_memo(move |_| {
+        let value: f64 = value.get().into();
+
+        if value.is_nan() {
+            0.0
+        } else {
+            value.clamp(0.0, max.max(0.0))
+        }
+    });
+
+    let label = show_label.then(|| {
+        let format = NumberFormat::new()
+            .precision(precision.unwrap_or(0))
+            .suffix("%");
+
+        move || {
+            let percent = if max > 0.0 {
+                clamped.get() / max * 100.0
+            } else {
+                0.0
+            };
+
+            format.format(percent)
+        }
+    });
+
+    view! {
+        <td class=class role="cell">
+            <progress
+                value=clamped
+                max=max
+                aria-valuemin="0"
+                aria-valuemax=max
+                aria-valuenow=clamped
+            ></progress>
+            {label.map(|label| view! { <span>" " {label}</span> })}
+        </td>
+    }
+}
@@ -0,0 +1,113 @@
+use crate::components::renderer_fn::renderer_fn;
+use leptos::*;
+use std::any::{Any, TypeId};
+use std::collections::HashMap;
+use std::rc::Rc;
+
+renderer_fn!(
+    TypeCellRendererFn<T>(class: String, value: MaybeSignal<T>, index: usize)
+    where T: 'static
+);
+
+/// Maps field types to the cell renderers that are used for them by default. This way you don't have
+/// to add a `renderer` attribute to every field of a domain type like `Money`.
+///
+/// Register the renderers once and provide them as context (`provide_context(renderers)`) around
+/// all [`TableContent`]s that should use them, e.g. in your app's root component.
+///
+/// ```
+/// # use leptos::*;
+/// # use leptos_struct_table::*;
+/// #
+/// #[derive(Clone, Copy)]
+/// pub struct Money(i64);
+///
+/// impl IntoView for Money {
+///     fn into_view(self) -> View {
+///         format!("{}.{:02}", self.0 / 100, self.0 % 100).into_view()
+///     }
+/// }
+///
+/// #[derive(TableRow, Clone)]
+/// #[table(impl_vec_data_provider)]
+/// pub struct Invoice {
+///     id: u32,
+///     total: Money,
+/// }
+///
+/// #[component]
+/// fn App() -> impl IntoView {
+///     provide_context(TypeCellRenderers::new().register(
+///         |class, value: MaybeSignal<Money>, _index| {
+///             view! { <td class=class style="text-align: right">{value} " €"</td> }
+///         },
+///     ));
+///
+///     let rows = vec![Invoice { id: 1, total: Money(12050) }];
+///
+///     view! {
+///         <table>
+///             <TableContent rows />
+///         </table>
+///     }
+/// }
+/// ```
+///
+/// # Resolution
+///
+/// 1. A `renderer` attribute on the field always wins. The registered renderers are never consulted for it.
+/// 2. Otherwise the `TableRow` derive macro picks the built-in default renderer for the field's type:
+///    [`DefaultNumberTableCellRenderer`] for numbers, the chrono renderers for chrono types and
+///    [`DefaultTableCellRenderer`] for everything else (this applies to the inner type of `Option`s as well).
+/// 3. [`DefaultTableCellRenderer`] looks up the renderer registered for the type of its value in the
+///    context and falls back to [`DefaultBoolTableCellRenderer`] for `bool`s and to rendering the value
+///    with [`IntoView`] for everything else.
+///
+/// So renderers can be registered for any type that is rendered by [`DefaultTableCellRenderer`] but
+/// not for numbers or chrono types. Registered types still have to implement [`IntoView`].
+#[derive(Clone, Default)]
+pub struct TypeCellRenderers(HashMap<TypeId, Rc<dyn Any>>);
+
+impl TypeCellRenderers {
+    /// Creates an empty registry.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers `renderer` as the default cell renderer for fields of type `T`. It receives the
+    /// class, the value and the column index and has to render the cell element (e.g. `<td>`).
+    /// Registering a renderer for the same type again replaces the previous one.
+    pub fn register<T, R, V>(mut self, renderer: R) -> Self
+    where
+        T: 'static,
+        R: Fn(String, MaybeSignal<T>, usize) -> V + 'static,
+        V: IntoView,
+    {
+        self.0.insert(
+            TypeId::of::<T>(),
+            Rc::new(TypeCellRendererFn::<T>::from(renderer)),
+        );
+        self
+    }
+
+    /// Returns `true` if a renderer has been registered for the type `T`.
+    pub fn contains<T: 'static>(&self) -> bool {
+        self.0.contains_key(&TypeId::of::<T>())
+    }
+
+    /// Renders the cell with the renderer that is registered for the type `T` or returns `None` if
+    /// there is none.
+    pub(crate) fn render<T: 'static>(
+        &self,
+        class: String,
+        value: MaybeSignal<T>,
+        index: usize,
+    ) -> Option<View> {
+        let renderer = self
+            .0
+            .get(&TypeId::of::<T>())?
+            .downcast_ref::<TypeCellRendererFn<T>>()?;
+
+        Some(renderer.run(class, value, index))
+    }
+}
@@ -1,14 +1,18 @@
+mod active_filters_bar;
 mod cell;
 mod renderer_fn;
 mod row;
 mod table_content;
 mod tbody;
+mod tfoot;
 mod thead;
 
+pub use active_filters_bar::*;
 pub use cell::*;
 pub use row::*;
 pub use table_content::*;
 pub use tbody::*;
+pub use tfoot::*;
 pub use thead::*;
 
 #[macro_export]
@@ -17,12 +21,17 @@ macro_rules! wrapper_render_fn {
         #[$doc_name:meta]
         $name:ident,
         $tag:ident,
+        $($role:literal,)?
         $(#[$additional_doc:meta])*
     ) => {
         /// Default
         #[$doc_name]
         /// renderer. Please note that this is **NOT** a `#[component]`.
         ///
+        $(
+        #[doc = concat!("Renders `role=\"", $role, "\"` on the root element. Custom renderers are responsible for this themselves.")]
+        ///
+        )?
         /// # Arguments
         ///
         /// * `content` - The content of the renderer. It's like the children of this view.
@@ -31,7 +40,7 @@ macro_rules! wrapper_render_fn {
         #[allow(non_snake_case)]
         pub fn $name(content: View, class: Signal<String>) -> impl IntoView {
             view! {
-                <$tag class=class>
+                <$tag class=class $(role=$role)?>
                     {content}
                 </$tag>
             }
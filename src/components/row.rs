@@ -1,10 +1,13 @@
+use crate::row_reorder::RowReorderContext;
 use crate::table_row::TableRow;
-use crate::{ChangeEvent, EventHandler};
+use crate::{render_row_cells, ChangeEvent, EventHandler};
 use leptos::*;
 
-/// The default table row renderer. Uses the `<tr>` element. Please note that this
-/// is **NOT** a `#[component]`.
-#[allow(unused_variables)]
+/// The default table row renderer. Uses the `<tr>` element and renders the cells in the column
+/// order of the table (see [`render_row_cells`]). Please note that this is **NOT** a `#[component]`.
+///
+/// If the `row_reorderable` prop of [`TableContent`] is set, the row is `draggable` and dropping
+/// another row onto it moves that row here.
 pub fn DefaultTableRowRenderer<Row>(
     // The class attribute for the row element. Generated by the classes provider.
     class: Signal<String>,
@@ -22,25 +25,239 @@ pub fn DefaultTableRowRenderer<Row>(
 where
     Row: TableRow + Clone + 'static,
 {
+    // only provided if rows can be reordered
+    let reorder = use_context::<RowReorderContext>();
+
     view! {
-        <tr class=class on:click=move |mouse_event| on_select.run(mouse_event)>
-            {row.render_row(index, on_change)}
+        <tr
+            class=class
+            role="row"
+            aria-selected=move || selected.get().to_string()
+            on:click=move |mouse_event| on_select.run(mouse_event)
+            draggable=move || reorder.filter(|reorder| reorder.is_draggable(index)).map(|_| "true")
+            on:dragstart=move |evt| {
+                if let Some(reorder) = reorder {
+                    reorder.drag_start(index);
+
+                    if let Some(data_transfer) = evt.data_transfer() {
+                        // Firefox only starts dragging if there is some data
+                        let _ = data_transfer.set_data("text/plain", &index.to_string());
+                        data_transfer.set_effect_allowed("move");
+                    }
+                }
+            }
+            on:dragover=move |evt| {
+                if let Some(reorder) = reorder.filter(|reorder| reorder.is_dragging()) {
+                    // allows dropping
+                    evt.prevent_default();
+                    reorder.drag_over(index);
+                }
+            }
+            on:drop=move |evt| {
+                if let Some(reorder) = reorder {
+                    evt.prevent_default();
+                    reorder.drop_on(index);
+                }
+            }
+            on:dragend=move |_| {
+                if let Some(reorder) = reorder {
+                    reorder.drag_end();
+                }
+            }
+        >
+            {render_row_cells(row, index, on_change)}
         </tr>
     }
 }
 
-/// The default row placeholder renderer which is just a div that is set to the
+/// The default renderer of the leading body cell with the handle that rows are dragged by if the
+/// `row_drag_handle` prop of [`TableContent`] is set. Only while the handle is pressed the row is
+/// `draggable`, so the text of the other cells can be selected as usual.
+/// Please note that this is **NOT** a `#[component]`.
+///
+/// # Arguments
+///
+/// * `enabled` - Whether rows can be dragged. `false` while the table is sorted.
+/// * `on_press` - Called with `true` when the handle is pressed and with `false` when it's released
+/// * `class` - The class attribute of the cell. Generated by the classes provider.
+#[allow(non_snake_case)]
+pub fn DefaultRowDragHandleCellRenderer(
+    enabled: Signal<bool>,
+    on_press: Callback<bool>,
+    class: String,
+) -> impl IntoView {
+    view! {
+        <td
+            class=class
+            role="cell"
+            style:cursor=move || if enabled.get() { "grab" } else { "not-allowed" }
+            on:pointerdown=move |_| on_press(true)
+            on:pointerup=move |_| on_press(false)
+        >
+            <span aria-hidden="true">"⠿"</span>
+        </td>
+    }
+}
+
+/// The default renderer of the leading body cell with the checkbox that toggles the selection of
+/// its row. It's shown with `Selection::Multiple` (see the `selection_checkboxes` prop of [`TableContent`]).
+/// Please note that this is **NOT** a `#[component]`.
+///
+/// # Arguments
+///
+/// * `selected` - Whether the row is selected
+/// * `on_toggle` - Toggles the selection of the row
+/// * `class` - The class attribute of the cell. Generated by the classes provider.
+/// * `disabled` - Whether the row can't be selected (see the `row_selectable_fn` prop of [`TableContent`])
+#[allow(non_snake_case)]
+pub fn DefaultSelectRowCellRenderer(
+    selected: Signal<bool>,
+    on_toggle: Callback<()>,
+    class: String,
+    disabled: bool,
+) -> impl IntoView {
+    view! {
+        <td class=class role="cell">
+            <input
+                type="checkbox"
+                aria-label="Select row"
+                prop:checked=selected
+                disabled=disabled
+                on:change=move |_| on_toggle(())
+            />
+        </td>
+    }
+}
+
+/// The default renderer of the leading body cell with the radio button that selects its row with
+/// `Selection::Single` (see the `selection_checkboxes` prop of [`TableContent`]).
+/// Please note that this is **NOT** a `#[component]`.
+///
+/// # Arguments
+///
+/// * `selected` - Whether the row is selected
+/// * `on_select` - Selects the row
+/// * `class` - The class attribute of the cell. Generated by the classes provider.
+/// * `name` - The name of the radio button. It's the same for all rows of a table.
+/// * `disabled` - Whether the row can't be selected (see the `row_selectable_fn` prop of [`TableContent`])
+#[allow(non_snake_case)]
+pub fn DefaultSelectRowRadioCellRenderer(
+    selected: Signal<bool>,
+    on_select: Callback<()>,
+    class: String,
+    name: String,
+    disabled: bool,
+) -> impl IntoView {
+    view! {
+        <td class=class role="cell">
+            <input
+                type="radio"
+                name=name
+                aria-label="Select row"
+                prop:checked=selected
+                disabled=disabled
+                on:change=move |_| on_select(())
+            />
+        </td>
+    }
+}
+
+/// The default renderer of the leading body cell with the disclosure button that expands and collapses
+/// its row. It's shown if the `expanded_row_renderer` prop of [`TableContent`] is set.
+/// Please note that this is **NOT** a `#[component]`.
+///
+/// # Arguments
+///
+/// * `expanded` - Whether the row is expanded
+/// * `on_toggle` - Expands or collapses the row
+/// * `class` - The class attribute of the cell. Generated by the classes provider.
+#[allow(non_snake_case)]
+pub fn DefaultExpandRowCellRenderer(
+    expanded: Signal<bool>,
+    on_toggle: Callback<()>,
+    class: String,
+) -> impl IntoView {
+    view! {
+        <td class=class role="cell">
+            <button
+                type="button"
+                aria-label=move || if expanded.get() { "Collapse row" } else { "Expand row" }
+                aria-expanded=move || expanded.get().to_string()
+                on:click=move |_| on_toggle(())
+            >
+                {move || if expanded.get() { "▾" } else { "▸" }}
+            </button>
+        </td>
+    }
+}
+
+/// The default row placeholder renderer which is just a `<tr>` that is set to the
 /// appropriate height. This is used in place of rows that are not shown
 /// before and after the currently visible rows.
 pub fn DefaultRowPlaceholderRenderer(height: Signal<f64>) -> impl IntoView {
-    view! { <tr style:height=move || format!("{}px", height.get()) style="display: block"></tr> }
+    view! { <tr style:height=move || format!("{}px", height.get()) style="display: block" aria-hidden="true"></tr> }
 }
 
 /// The default error row renderer which just displays the error message when
 /// a row fails to load, i.e. when [`TableDataProvider::get_rows`] returns an `Err(..)`.
+///
+/// `err` is the `Debug` representation of the error. Custom error row renderers can get the error
+/// itself with [`use_row_load_error`], e.g. to render a retry button that calls [`ReloadController::reload_row`].
 #[allow(unused_variables)]
 pub fn DefaultErrorRowRenderer(err: String, index: usize, col_count: usize) -> impl IntoView {
-    view! { <tr><td colspan=col_count>{err}</td></tr> }
+    view! { <tr role="row"><td colspan=col_count role="cell">{err}</td></tr> }
+}
+
+/// The default renderer of the row that shows more rows with [`DisplayStrategy::LoadMore`].
+/// Renders a button that spans all columns and calls `on_load_more` when clicked.
+#[allow(unstable_name_collisions)]
+pub fn DefaultLoadMoreRowRenderer(on_load_more: Callback<()>, col_count: usize) -> impl IntoView {
+    view! {
+        <tr role="row">
+            <td colspan=col_count role="cell">
+                <button type="button" on:click=move |_| on_load_more.call(())>
+                    "Load more"
+                </button>
+            </td>
+        </tr>
+    }
+}
+
+/// The default renderer of the loading overlay (see the `loading_overlay_renderer` prop of [`TableContent`]).
+/// Renders a row that is positioned over the whole body and shows a centered "Loading…" status.
+///
+/// # Arguments
+///
+/// * `class` - The class attribute of the overlay row. Generated by the classes provider.
+/// * `col_count` - The number of columns of the table
+pub fn DefaultLoadingOverlayRenderer(class: String, col_count: usize) -> impl IntoView {
+    view! {
+        <tr
+            class=class
+            style="position: absolute; inset: 0; min-height: 3rem; display: flex; align-items: center; justify-content: center"
+        >
+            <td colspan=col_count role="status">
+                "Loading…"
+            </td>
+        </tr>
+    }
+}
+
+/// The default renderer of the row that is shown when the table has no rows (see the
+/// `empty_row_renderer` prop of [`TableContent`]). Renders "No results" in a cell that spans all columns.
+///
+/// # Arguments
+///
+/// * `class` - The class attribute of the cell. Generated by the classes provider.
+/// * `col_count` - The number of columns of the table
+pub fn DefaultEmptyRowRenderer(class: String, col_count: usize) -> impl IntoView {
+    view! {
+        <tr role="row">
+            <td class=class colspan=col_count role="cell">
+                "No results"
+            </td>
+        </tr>
+    }
 }
 
 /// The default loading row renderer which just displays a loading indicator.
@@ -53,10 +270,10 @@ pub fn DefaultLoadingRowRenderer(
     col_count: usize,
 ) -> impl IntoView {
     view! {
-        <tr class=class>
+        <tr class=class role="row">
             {
                 (0..col_count).map(|col_index| view! {
-                    <td class=get_cell_class.call(col_index)>
+                    <td class=get_cell_class.call(col_index) role="cell">
                         <div class=get_inner_cell_class.call(col_index)></div>
                         " "
                     </td>
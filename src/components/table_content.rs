@@ -1,29 +1,70 @@
+use crate::aggregates::LoadedRowsContext;
+use crate::cell_click::CellClickContext;
+use crate::column_groups::group_header_spans;
+use crate::column_order::{move_column, reorder_cells, ColumnOrderContext};
+use crate::column_resize::ColumnResizeHandleClass;
+use crate::column_virtualization::{rendered_columns, ColumnVirtualizationContext};
+use crate::components::cell::BadgeClass;
 use crate::components::renderer_fn::renderer_fn;
-use crate::loaded_rows::{LoadedRows, RowState};
-use crate::selection::Selection;
+use crate::components::thead::{ColumnNamesContext, ColumnTitlesContext, SortPriorityClass};
+use crate::direction::DirectionContext;
+use crate::display_strategy::{compute_display_range, compute_load_more_range};
+use crate::expansion::{ExpansionColumnContext, EXPANDED_ROW_ATTRIBUTE};
+use crate::flatten::flatten_cells;
+use crate::loaded_rows::{LoadedRows, RowError, RowErrorContext, RowState};
+use crate::provider_cell::ProviderCell;
+use crate::row_height::{row_index_at, row_offset};
+use crate::row_reorder::{RowReorderContext, AUTO_SCROLL_EDGE, AUTO_SCROLL_STEP};
+use crate::selection::{
+    limit_selection, next_radio_group, Selection, SelectionColumnContext, SelectionLimitPolicy,
+};
+use crate::spanning_rows::ColumnCountContext;
+use crate::sticky_columns::StickyColumnsContext;
+use crate::table_controller::{CellInputHandler, RenderedRowIndex};
 use crate::table_row::TableRow;
+use crate::table_state::{update_sorting, TableState};
 use crate::{
-    ChangeEvent, ColumnSort, DefaultErrorRowRenderer, DefaultLoadingRowRenderer,
-    DefaultRowPlaceholderRenderer, DefaultTableBodyRenderer, DefaultTableHeadRenderer,
-    DefaultTableHeadRowRenderer, DefaultTableRowRenderer, DisplayStrategy, EventHandler,
-    ReloadController, ScrollContainer, SelectionChangeEvent, TableClassesProvider,
-    TableDataProvider, TableHeadEvent,
+    use_column_order, CellClickEvent, CellCoordinate, CellEditEvent, CellFocusEvent,
+    CellInputEvent, CellTooltipFn, ChangeEvent, ColumnAggregates, ColumnCellRenderers, ColumnGroup,
+    ColumnReorderController, ColumnReorderEvent, ColumnResizeController, ColumnSort,
+    DefaultColumnGroupHeaderCellRenderer, DefaultEmptyRowRenderer, DefaultErrorRowRenderer,
+    DefaultLoadMoreRowRenderer, DefaultLoadingOverlayRenderer, DefaultLoadingRowRenderer,
+    DefaultRowPlaceholderRenderer, DefaultSelectAllHeaderCellRenderer, DefaultTableBodyRenderer,
+    DefaultTableFilterRowRenderer, DefaultTableFootRenderer, DefaultTableHeadRenderer,
+    DefaultTableHeadRowRenderer, DefaultTableRowRenderer, Direction, DisplayStrategy, EventHandler,
+    FilterChangeEvent, GetterContext, LoadErrorEvent, PageChangeEvent, PageSize, ReloadController,
+    RetryPolicy, RowClassFn, RowClickEvent, RowHeightFn, RowKeyFn, RowMoveEvent, RowMutation,
+    RowMutationController, RowSelectableFn, ScrollAlign, ScrollContainer, SearchChangeEvent,
+    SelectionChangeEvent, SelectionRejectedEvent, SortChangeEvent, TableClassesProvider,
+    TableController, TableDataProvider, TableHeadEvent, VisibleRangeChangeEvent,
+    AUTO_PAGE_SIZE_DEBOUNCE, DEFAULT_COLUMN_OVERSCAN, DEFAULT_ESTIMATED_COLUMN_WIDTH,
+    DEFAULT_ESTIMATED_ROW_HEIGHT, DEFAULT_OVERSCAN, ROW_HIGHLIGHT_DURATION,
 };
 use leptos::html::AnyElement;
+use leptos::leptos_dom::helpers::TimeoutHandle;
 use leptos::leptos_dom::is_browser;
 use leptos::*;
 use leptos_use::{
-    use_debounce_fn, use_element_size_with_options, use_scroll_with_options, UseElementSizeOptions,
-    UseElementSizeReturn, UseScrollOptions, UseScrollReturn,
+    use_debounce_fn, use_element_size_with_options, use_event_listener, use_resize_observer,
+    use_scroll_with_options, UseElementSizeOptions, UseElementSizeReturn, UseScrollOptions,
+    UseScrollReturn,
 };
-use std::cell::RefCell;
-use std::collections::{HashSet, VecDeque};
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet, VecDeque};
 use std::fmt::Debug;
 use std::marker::PhantomData;
+use std::mem::{discriminant, Discriminant};
 use std::ops::Range;
 use std::rc::Rc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::Duration;
+use wasm_bindgen::JsCast;
+
+const INTERACTIVE_ELEMENTS_SELECTOR: &str =
+    "input, button, select, textarea, a, label, [contenteditable]";
 
-const MAX_DISPLAY_ROW_COUNT: usize = 500;
+/// Attribute of the loading overlay. It's skipped when the rendered rows are measured.
+const LOADING_OVERLAY_ATTRIBUTE: &str = "data-loading-overlay";
+const EMPTY_ROW_ATTRIBUTE: &str = "data-empty-row";
 
 renderer_fn!(
     RowRendererFn<Row>(
@@ -38,6 +79,11 @@ renderer_fn!(
     where Row: TableRow + Clone + 'static
 );
 
+renderer_fn!(
+    ExpandedRowRendererFn<Row>(row: Row, index: usize)
+    where Row: TableRow + Clone + 'static
+);
+
 renderer_fn!(
     RowPlaceholderRendererFn(height: Signal<f64>)
     default DefaultRowPlaceholderRenderer
@@ -56,6 +102,35 @@ renderer_fn!(
     default DefaultErrorRowRenderer
 );
 
+renderer_fn!(
+    SuspenseFallbackRendererFn(col_count: usize)
+);
+
+renderer_fn!(
+    LoadingOverlayRendererFn(class: String, col_count: usize)
+    default DefaultLoadingOverlayRenderer
+);
+
+renderer_fn!(
+    EmptyRowRendererFn(class: String, col_count: usize)
+    default DefaultEmptyRowRenderer
+);
+
+renderer_fn!(
+    LoadMoreRowRendererFn(on_load_more: Callback<()>, col_count: usize)
+    default DefaultLoadMoreRowRenderer
+);
+
+renderer_fn!(
+    ColumnGroupHeaderCellRendererFn(title: String, colspan: usize, class: String)
+    default DefaultColumnGroupHeaderCellRenderer
+);
+
+renderer_fn!(
+    FilterRowRendererFn(filters: RwSignal<HashMap<usize, String>>, col_count: usize, class: Signal<String>, get_cell_class: Callback<usize, String>, input_class: String)
+    default DefaultTableFilterRowRenderer
+);
+
 renderer_fn!(
     LoadingRowRendererFn(class: Signal<String>, get_cell_class: Callback<usize, String>, get_cell_inner_class: Callback<usize, String>, index: usize, col_count: usize)
     default DefaultLoadingRowRenderer
@@ -74,11 +149,34 @@ pub fn TableContent<Row, DataP, Err, ClsP>(
     /// Check out the [editable example](https://github.com/Synphonyte/leptos-struct-table/blob/master/examples/editable/src/main.rs).
     #[prop(optional, into)]
     on_change: EventHandler<ChangeEvent<Row>>,
+    /// Event handler for when a cell is edited, with the row from before the edit.
+    /// It's called right away for every change, even with `change_debounce_ms`, so consecutive edits
+    /// of a cell can be undone one by one. See [`CellEditEvent`].
+    #[prop(optional, into)]
+    on_cell_edit: EventHandler<CellEditEvent<Row>>,
+    /// If given, `on_change` is only called once the user stopped editing a cell for this many
+    /// milliseconds, with the latest value of the cell. This is useful if cell renderers emit changes
+    /// on every keystroke and saving is expensive. Pending changes are emitted right away when another
+    /// cell is changed, when the focus leaves the cell and when the table is unmounted, so the final
+    /// value is never dropped. The row itself is updated immediately.
+    #[prop(optional, into)]
+    change_debounce_ms: Option<f64>,
+    /// Event handler for every input in an editable cell, before it is committed with `on_change`.
+    /// Use it e.g. to validate the value while the user is typing and `on_change` to save it.
+    /// Cell renderers emit it through [`use_cell_input`]. The built-in text and number input
+    /// renderers do this on every keystroke and emit `on_change` on blur or Enter.
+    #[prop(optional, into)]
+    on_cell_input: EventHandler<CellInputEvent>,
     /// Selection mode together with the `RwSignal` to hold the selection. Available modes are
     /// - `None` - No selection (default)
     /// - `Single` - Single selection
     /// - `Multiple` - Multiple selection
     ///
+    /// With `Multiple` a click selects only the clicked row, cmd/ctrl-click toggles a row and
+    /// shift-click selects the range from the last clicked row to the clicked one (cmd/ctrl-shift-click
+    /// adds the range to the selection). Rows in the range that aren't loaded yet, e.g. because they
+    /// have been scrolled out of view and evicted, are skipped.
+    ///
     /// Please see [`Selection`] for more information and check out the
     /// [selectable example](https://github.com/Synphonyte/leptos-struct-table/blob/master/examples/selectable/src/main.rs).
     #[prop(optional, into)]
@@ -87,6 +185,82 @@ pub fn TableContent<Row, DataP, Err, ClsP>(
     /// See the [selectable example](https://github.com/Synphonyte/leptos-struct-table/blob/master/examples/selectable/src/main.rs) for details.
     #[prop(optional, into)]
     on_selection_change: EventHandler<SelectionChangeEvent<Row>>,
+    /// If `true` (the default), clicking anywhere on a row selects it according to the `selection` mode
+    /// and the pressed modifier keys (cmd/ctrl to toggle, shift for a range).
+    /// Set this to `false` if rows should only be selected by setting the `selection` signal.
+    ///
+    /// Clicks on interactive elements inside of cells like `<input>`, `<button>` or `<a>` never select the row.
+    #[prop(default = true)]
+    select_on_row_click: bool,
+    /// Event handler callback for when a row is clicked, e.g. to navigate to a detail page.
+    /// It is called independently of the `selection` mode and `select_on_row_click`.
+    /// Clicks on interactive elements inside of cells like `<input>`, `<button>` or `<a>` are ignored.
+    #[prop(optional, into)]
+    on_row_click: EventHandler<RowClickEvent<Row>>,
+    /// Event handler for when a body cell is clicked, e.g. to open a drill-down for its column. The
+    /// `col_index` is the column index of the field, independent of the column order and of hidden
+    /// columns. Like `on_row_click` it's not emitted for clicks on interactive elements inside of the
+    /// cell. It's emitted before `on_row_click`, so calling `stop_propagation()` on the mouse event
+    /// suppresses the row click.
+    ///
+    /// Custom row renderers have to use [`render_row_cells`] instead of [`TableRow::render_row`] for this to work.
+    #[prop(optional, into)]
+    on_cell_click: Option<EventHandler<CellClickEvent<Row>>>,
+    /// The index of the body row that has the keyboard focus. The table body is focusable and
+    /// the focused row is moved with the arrow keys. Space toggles the selection of the focused row
+    /// and Enter acts like a click on it, i.e. it emits `on_row_click` and selects the row if
+    /// `select_on_row_click` is `true`.
+    ///
+    /// Read or set this signal to control the focused row from the outside. A focused row that isn't
+    /// visible is scrolled into view. It's styled through [`TableClassesProvider::row_focused`].
+    #[prop(default = create_rw_signal(None), into)]
+    focused_row: RwSignal<Option<usize>>,
+    /// The maximum number of rows that can be selected at the same time with `Selection::Multiple`.
+    /// Defaults to `None` which means unlimited. What happens when the limit is reached is
+    /// configured with `selection_limit_policy`.
+    #[prop(optional, into)]
+    max_selection: Option<usize>,
+    /// What happens when a row is selected while `max_selection` rows are already selected.
+    /// Defaults to [`SelectionLimitPolicy::Reject`].
+    #[prop(optional)]
+    selection_limit_policy: SelectionLimitPolicy,
+    /// Event handler callback for when a row couldn't be selected because `max_selection` rows are
+    /// already selected. Use this to show a message to the user. If "select all" exceeds the limit,
+    /// it's called once with the first row that couldn't be selected.
+    #[prop(optional, into)]
+    on_selection_rejected: EventHandler<SelectionRejectedEvent<Row>>,
+    /// Returns whether a row can be selected from its data and its index, e.g. to prevent locked
+    /// records from being selected. You can pass a method like `Record::is_selectable` that has the
+    /// signature `fn(&self, index: usize) -> bool`. Clicking such a row doesn't change the selection,
+    /// shift ranges and "select all" skip it and its checkbox or radio button is disabled.
+    /// Defaults to `None` which means all rows can be selected. See [`RowSelectableFn`].
+    #[prop(optional, into)]
+    row_selectable_fn: Option<RowSelectableFn<Row>>,
+    /// If `true` (the default) and `selection` is `Selection::Multiple`, a leading column with
+    /// checkboxes is rendered. The checkbox in the header selects or de-selects all rows at once and
+    /// is indeterminate if only some of them are selected. The checkboxes in the body toggle their row.
+    ///
+    /// With `Selection::Single` the column contains a radio button per row instead that selects its
+    /// row. Clicking the row itself selects it as well. The cells of this column are styled by
+    /// [`TableClassesProvider::selection_cell`].
+    #[prop(default = true)]
+    selection_checkboxes: bool,
+    /// By default the "select all" checkbox only selects the rows that are currently loaded, because
+    /// the data of the other rows isn't known yet. Set this to `true` to select all rows up to the
+    /// row count instead. Rows that aren't loaded are missing from [`SelectionChangeEvent::selected_rows`] then.
+    #[prop(optional)]
+    select_all_including_unloaded: bool,
+    /// Rendered as a `<caption>` before the head of the table, e.g. `caption=|| "Employees"`. It gives
+    /// the table its accessible name and is printed with it. It has the classes of
+    /// [`TableClassesProvider::caption`]. Only use it if `TableContent` is the only child of the `<table>`,
+    /// because the caption has to be its first child.
+    #[prop(optional, into)]
+    caption: Option<ViewFn>,
+    /// A longer description that is rendered inside of the `<caption>` after the `caption` and that is
+    /// referenced by the `aria-describedby` attribute of the `<table>`, so screen readers announce it
+    /// together with the table. It has the classes of [`TableClassesProvider::caption_description`].
+    #[prop(optional, into)]
+    description: Option<ViewFn>,
     /// Renderer function for the table head. Defaults to [`DefaultTableHeadRenderer`]. For a full example see the
     /// [custom_renderers_svg example](https://github.com/Synphonyte/leptos-struct-table/blob/master/examples/custom_renderers_svg/src/main.rs).
     #[prop(default = DefaultTableHeadRenderer.into(), into)]
@@ -99,6 +273,9 @@ pub fn TableContent<Row, DataP, Err, ClsP>(
     /// [custom_renderers_svg example](https://github.com/Synphonyte/leptos-struct-table/blob/master/examples/custom_renderers_svg/src/main.rs).
     #[prop(default = DefaultTableHeadRowRenderer.into(), into)]
     thead_row_renderer: WrapperRendererFn,
+    /// Renderer function for the table foot that displays the `aggregates`. Defaults to [`DefaultTableFootRenderer`].
+    #[prop(default = DefaultTableFootRenderer.into(), into)]
+    tfoot_renderer: WrapperRendererFn,
     /// The row renderer. Defaults to [`DefaultTableRowRenderer`]. For a full example see the
     /// [custom_renderers_svg example](https://github.com/Synphonyte/leptos-struct-table/blob/master/examples/custom_renderers_svg/src/main.rs).
     #[prop(optional, into)]
@@ -113,14 +290,102 @@ pub fn TableContent<Row, DataP, Err, ClsP>(
     /// [custom_renderers_svg example](https://github.com/Synphonyte/leptos-struct-table/blob/master/examples/custom_renderers_svg/src/main.rs).
     #[prop(optional, into)]
     error_row_renderer: ErrorRowRendererFn,
+    /// The renderer of the row after the shown rows with [`DisplayStrategy::LoadMore`] that shows
+    /// more rows when clicked. It receives the callback to call for that and the number of columns.
+    /// Defaults to [`DefaultLoadMoreRowRenderer`].
+    #[prop(optional, into)]
+    load_more_row_renderer: LoadMoreRowRendererFn,
+    /// Makes the table expandable. Every row gets a leading cell with a disclosure button (see
+    /// [`DefaultExpandRowCellRenderer`]) that shows the details of the row in a full-width row below it.
+    /// This renderer receives the row and its index and returns the content of that detail row,
+    /// e.g. a nested table or a form. The detail row is styled by [`TableClassesProvider::expanded_row`].
+    ///
+    /// The heights of expandable rows are measured like with `measure_row_heights`, so the detail rows
+    /// are taken into account by the virtualization.
+    ///
+    /// See the [expandable example](https://github.com/Synphonyte/leptos-struct-table/blob/master/examples/expandable/src/main.rs).
+    #[prop(optional, into)]
+    expanded_row_renderer: Option<ExpandedRowRendererFn<Row>>,
+    /// The indices of the rows that are expanded. Only used with `expanded_row_renderer`. Setting this
+    /// signal from the outside expands and collapses rows, e.g. from `on_row_click`. Like the
+    /// selection it is cleared when the table reloads.
+    #[prop(default = create_rw_signal(HashSet::new()), into)]
+    expanded: RwSignal<HashSet<usize>>,
     /// The row placeholder renderer. Defaults to [`DefaultRowPlaceholderRenderer`].
     /// This is used in place of rows that are not shown
     /// before and after the currently visible rows.
+    ///
+    /// Provide your own if the default `<tr>` doesn't fit your layout, e.g. when the body is a flex
+    /// container. The renderer receives the computed height in pixels and has to render exactly one
+    /// element that is a direct child of the body element and that keeps exactly this height. Otherwise
+    /// the total height of the body and with it the scroll position of the virtualization would be off.
+    /// See the [flex_layout example](https://github.com/Synphonyte/leptos-struct-table/blob/master/examples/flex_layout/src/main.rs).
     #[prop(optional, into)]
     row_placeholder_renderer: RowPlaceholderRendererFn,
+    /// Rendered inside of the tbody instead of the rows until the data provider returns data for the first time.
+    /// This is useful when creating the data source itself takes a while (e.g. waiting for authentication)
+    /// and you want to show a single loading state instead of loading rows. The renderer receives the
+    /// number of columns and has to render table rows, e.g. `<tr><td colspan=col_count>"Loading..."</td></tr>`.
+    ///
+    /// Since data is only loaded in the browser, this is what is rendered during SSR.
+    /// See the [async_provider example](https://github.com/Synphonyte/leptos-struct-table/blob/master/examples/async_provider/src/main.rs).
+    #[prop(optional, into)]
+    suspense_fallback: Option<SuspenseFallbackRendererFn>,
+    /// Rendered over the rows while the table loads for the first time, e.g. a single centered spinner
+    /// instead of a skeleton per row. Rows that are loaded later while scrolling use the
+    /// `loading_row_renderer` as usual. Pass [`DefaultLoadingOverlayRenderer`] or your own renderer
+    /// which receives the classes of [`TableClassesProvider::loading_overlay`] and the number of columns.
+    ///
+    /// The overlay has to be a single element. It's the last child of the body which is positioned
+    /// relatively while the overlay is shown.
+    /// Use [`TableController::is_loading`] to find out if rows are being loaded, e.g. to disable buttons.
+    #[prop(optional, into)]
+    loading_overlay_renderer: Option<LoadingOverlayRendererFn>,
+    /// Shows the loading overlay while `true` even after the first load, e.g. while your app fetches
+    /// data that the data provider depends on. Only used with `loading_overlay_renderer`.
+    #[prop(optional, into)]
+    loading: MaybeSignal<bool>,
+    /// Rendered inside of the body when the table has no rows, e.g. "No results" after a search that
+    /// didn't match anything. It's only shown once the row count is known to be 0, not while the
+    /// rows are loading. Pass [`DefaultEmptyRowRenderer`] or your own renderer which receives the
+    /// classes of [`TableClassesProvider::empty_row`] and the number of columns.
+    ///
+    /// The empty row has to be a single element.
+    #[prop(optional, into)]
+    empty_row_renderer: Option<EmptyRowRendererFn>,
     /// Additional classes to add to rows
     #[prop(optional, into)]
     row_class: MaybeSignal<String>,
+    /// Returns additional classes for a row from its data and its index, e.g. to color rows by
+    /// their status. You can pass a method like `Invoice::row_class` that has the signature
+    /// `fn(&self, index: usize) -> String`. The classes are added to the `row_class` prop before
+    /// they are passed to [`TableClassesProvider::row`] and are recomputed when the row is edited.
+    ///
+    /// See the [row_class example](https://github.com/Synphonyte/leptos-struct-table/blob/master/examples/row_class/src/main.rs).
+    #[prop(optional, into)]
+    row_class_fn: Option<RowClassFn<Row>>,
+    /// Returns the tooltip of a body cell from the data of its row and its column index, e.g. the full
+    /// value of a truncated cell. It's set as the `title` attribute of the cell. See [`CellTooltipFn`].
+    #[prop(optional, into)]
+    cell_tooltip_fn: Option<CellTooltipFn<Row>>,
+    /// Data that getters need besides the row, e.g. a lookup table. Getters read it with
+    /// [`use_getter_context`]. If it's a signal, the cells are rendered again when it changes.
+    /// See [`GetterContext`].
+    #[prop(optional, into)]
+    getter_context: Option<GetterContext>,
+    /// Replaces the derived cell renderers of some columns at runtime, e.g. depending on a density
+    /// toggle. If it's a signal, the cells are rendered again when it changes. See [`ColumnCellRenderers`].
+    #[prop(optional, into)]
+    cell_renderers: Option<MaybeSignal<ColumnCellRenderers<Row>>>,
+    /// Returns a stable unique key of a row, e.g. its database id. Without this, rows are identified
+    /// by their index, so after sorting or reloading a different row can end up at a selected index.
+    ///
+    /// If given, the rendered rows are keyed by it and the selection follows the rows: when the table
+    /// is sorted, filtered or reloaded, the rows that were selected are selected again at their new
+    /// index once they're loaded. `on_selection_change` isn't called for this since the same rows stay
+    /// selected. The keys have to be unique among all rows.
+    #[prop(optional, into)]
+    row_key_fn: Option<RowKeyFn<Row>>,
     /// Additional classes to add to the thead
     #[prop(optional, into)]
     thead_class: MaybeSignal<String>,
@@ -130,6 +395,9 @@ pub fn TableContent<Row, DataP, Err, ClsP>(
     /// Additional classes to add to the tbody
     #[prop(optional, into)]
     tbody_class: MaybeSignal<String>,
+    /// Additional classes to add to the tfoot. Only used if `aggregates` is set.
+    #[prop(optional, into)]
+    tfoot_class: MaybeSignal<String>,
     /// Additional classes to add to the cell inside a row that is being loaded
     #[prop(optional, into)]
     loading_cell_class: MaybeSignal<String>,
@@ -139,8 +407,174 @@ pub fn TableContent<Row, DataP, Err, ClsP>(
     /// The sorting to apply to the table.
     /// For this to work you have add `#[table(sortable)]` to your struct.
     /// Please see the [simple example](https://github.com/Synphonyte/leptos-struct-table/blob/master/examples/simple/src/main.rs).
+    ///
+    /// Changing this signal from the outside sorts the table accordingly. Pass it to [`ActiveFiltersBar`]
+    /// to show the active sorting as removable chips. To make the sorting bookmarkable, store it in the
+    /// URL with [`sorting_to_param`] and initialize this signal with [`sorting_from_param`].
     #[prop(default = create_rw_signal(VecDeque::new()), into)]
     sorting: RwSignal<VecDeque<(usize, ColumnSort)>>,
+    /// Event handler callback for when the sorting has been changed by clicking a header cell.
+    /// It isn't called when the `sorting` signal is set from the outside.
+    #[prop(optional, into)]
+    on_sort_change: EventHandler<SortChangeEvent>,
+    /// If `true`, a row with a filter input for every column is rendered below the head row.
+    /// The entered filters are stored in the `filters` signal and passed to [`TableDataProvider::set_filters`].
+    /// Use [`FilteredVecDataProvider`] to filter local data.
+    ///
+    /// See the [filtering example](https://github.com/Synphonyte/leptos-struct-table/blob/master/examples/filtering/src/main.rs).
+    #[prop(optional)]
+    filterable: bool,
+    /// The filters to apply to the table by column index. Columns without filter text are not contained.
+    /// Changing this signal from the outside filters the table accordingly, even if `filterable` is `false`.
+    #[prop(default = create_rw_signal(HashMap::new()), into)]
+    filters: RwSignal<HashMap<usize, String>>,
+    /// Renderer function for the filter row. Defaults to [`DefaultTableFilterRowRenderer`].
+    /// Only used if `filterable` is `true`.
+    #[prop(optional, into)]
+    filter_row_renderer: FilterRowRendererFn,
+    /// Additional classes to add to the filter row
+    #[prop(optional, into)]
+    filter_row_class: MaybeSignal<String>,
+    /// Event handler callback for when the filter of a column changes.
+    #[prop(optional, into)]
+    on_filter_change: EventHandler<FilterChangeEvent>,
+    /// Text to search for in all columns, e.g. from a search box above the table. It's passed to
+    /// [`TableDataProvider::set_search`] and the table is reloaded when it changes. Use
+    /// [`FilteredVecDataProvider`] to search local data (see [`row_matches_search`]). The search is
+    /// combined with the filters and the sorting.
+    ///
+    /// Once the search is cleared, the scroll position from before the search is restored.
+    #[prop(optional, into)]
+    search: MaybeSignal<String>,
+    /// Event handler callback for when the search changes.
+    #[prop(optional, into)]
+    on_search_change: EventHandler<SearchChangeEvent>,
+    /// The display order of the columns as column indices (see [`TableRow::col_name`]). Columns that
+    /// aren't contained are appended in their natural order, so the default empty `Vec` shows the
+    /// columns in the order of the struct fields. Sorting and filtering always refer to the column index
+    /// and not to the position.
+    ///
+    /// Custom row renderers have to use [`render_row_cells`] instead of [`TableRow::render_row`] to respect the order.
+    #[prop(default = create_rw_signal(vec![]), into)]
+    column_order: RwSignal<Vec<usize>>,
+    /// Which columns are shown by column index (see [`TableRow::col_name`]). Columns that aren't
+    /// contained are visible, so the default empty `Vec` shows all columns. Hidden columns keep their
+    /// index and their place in `column_order`, and sorting, filtering and selection still refer to
+    /// the column index. Use [`column_visibility`] to compute the initial value, e.g. from user roles.
+    ///
+    /// See the [column_visibility example](https://github.com/Synphonyte/leptos-struct-table/blob/master/examples/column_visibility/src/main.rs)
+    /// for a column chooser.
+    #[prop(default = create_rw_signal(vec![]), into)]
+    column_visibility: RwSignal<Vec<bool>>,
+    /// Header titles by column index (see [`TableRow::col_name`]) that replace the `title` of the
+    /// fields at runtime, e.g. translations that update when the locale changes. Missing or empty
+    /// entries fall back to the `title` of the field, so the default empty `Vec` shows the static
+    /// titles. Sorting, filtering and `column_order` still refer to the column index.
+    ///
+    /// Custom header cell renderers get the title from [`use_column_header_info`].
+    #[prop(optional, into)]
+    column_titles: MaybeSignal<Vec<String>>,
+    /// If given, the user can reorder the columns by dragging the header cells onto each other.
+    /// The result is stored in `column_order`.
+    ///
+    /// See the [column_reorder example](https://github.com/Synphonyte/leptos-struct-table/blob/master/examples/column_reorder/src/main.rs)
+    /// for how to use.
+    #[prop(optional, into)]
+    column_reorder_controller: Option<ColumnReorderController>,
+    /// If `true` the user can reorder the rows by dragging them onto each other, e.g. to prioritize
+    /// tasks. The [`DefaultTableRowRenderer`] is `draggable` then and `on_row_move` is called when a
+    /// row has been dropped. The table doesn't reorder the rows itself: update your data accordingly
+    /// and reload the table, which [`SignalVecDataProvider`] does automatically.
+    ///
+    /// A manual order conflicts with sorting, so rows can't be dragged while the table is sorted by
+    /// a column. The row that the dragged row is over is styled by [`TableClassesProvider::row_drop_target`].
+    /// Dragging a row close to the top or bottom edge of the scroll container scrolls it.
+    #[prop(optional)]
+    row_reorderable: bool,
+    /// If `true` (and `row_reorderable` is set) rows can only be dragged by a handle in a leading
+    /// column (see [`DefaultRowDragHandleCellRenderer`]). This keeps the text of the cells selectable.
+    #[prop(optional)]
+    row_drag_handle: bool,
+    /// Event handler callback for when a row has been dragged onto another row with `row_reorderable`.
+    #[prop(optional, into)]
+    on_row_move: EventHandler<RowMoveEvent>,
+    /// If given, the user can resize the columns by dragging a handle on the right edge of the header
+    /// cells. The widths are stored in the controller and applied to the `<col>` elements of a
+    /// `<colgroup>` that is rendered before the `<thead>`. Since they don't depend on the rows, they
+    /// are kept while rows are virtualized.
+    ///
+    /// See the [column_resize example](https://github.com/Synphonyte/leptos-struct-table/blob/master/examples/column_resize/src/main.rs)
+    /// for how to use.
+    #[prop(optional, into)]
+    column_resize_controller: Option<ColumnResizeController>,
+    /// Fixed CSS widths of columns by column index, e.g. `"12rem"`, so the layout doesn't shift while
+    /// the rows are loading. Use [`column_widths`] to compute it from column names. The widths are
+    /// applied to the `<col>` elements of a `<colgroup>` like the ones of the `column_resize_controller`,
+    /// which take precedence once the user has resized a column. Give the table `table-layout: fixed`
+    /// so the widths don't depend on the content.
+    #[prop(optional, into)]
+    column_widths: Option<MaybeSignal<HashMap<usize, String>>>,
+    /// If `true` only the body cells of the columns inside of the horizontal viewport of the scroll
+    /// container (plus `column_overscan` columns on both sides) are rendered. The other cells are
+    /// rendered as empty cells, so this is useful for tables with hundreds of columns. Header cells,
+    /// the cells of sticky columns and the cell that is being edited are always rendered.
+    ///
+    /// The columns are positioned by the widths of the `column_resize_controller` and the pixel
+    /// widths of `column_widths` (e.g. `"120px"`). Other columns are assumed to be
+    /// `estimated_column_width` pixels wide, so give the table `table-layout: fixed` and widths
+    /// for all columns. Custom row renderers have to use [`render_row_cells`] for this to work.
+    #[prop(optional)]
+    virtualize_columns: bool,
+    /// The number of columns that are rendered on both sides of the visible columns if
+    /// `virtualize_columns` is set. Defaults to [`DEFAULT_COLUMN_OVERSCAN`].
+    #[prop(default = DEFAULT_COLUMN_OVERSCAN)]
+    column_overscan: usize,
+    /// The width in pixels that is assumed for columns without a known width if `virtualize_columns`
+    /// is set. Defaults to [`DEFAULT_ESTIMATED_COLUMN_WIDTH`].
+    #[prop(default = DEFAULT_ESTIMATED_COLUMN_WIDTH)]
+    estimated_column_width: f64,
+    /// Aggregates of columns like sums that are computed over the loaded rows and rendered in a row
+    /// of a `<tfoot>` below the body. See [`ColumnAggregates`].
+    #[prop(optional, into)]
+    aggregates: Option<ColumnAggregates<Row>>,
+    /// Groups of columns that get a common header cell in an extra row above the head row, e.g. "Q1"
+    /// above the columns of January to March. See [`ColumnGroup`] for how the header cells are
+    /// computed from the column order. Sorting, filtering and resizing still refer to the single
+    /// columns. No extra row is rendered if this is empty (the default).
+    #[prop(optional, into)]
+    column_groups: MaybeSignal<Vec<ColumnGroup>>,
+    /// Renderer function for the header cells of the `column_groups`. Defaults to
+    /// [`DefaultColumnGroupHeaderCellRenderer`].
+    #[prop(optional, into)]
+    column_group_header_cell_renderer: ColumnGroupHeaderCellRendererFn,
+    /// Event handler callback for when a column has been moved through the `column_reorder_controller`.
+    #[prop(optional, into)]
+    on_column_reorder: EventHandler<ColumnReorderEvent>,
+    /// The indices of the columns that stay pinned to the left while the table is scrolled
+    /// horizontally, e.g. an ID or name column. Their cells get `position: sticky` with a `left`
+    /// offset that is the sum of the widths of the sticky columns displayed before them, the class
+    /// [`STICKY_CELL_CLASS`] and the classes of [`TableClassesProvider::sticky_cell`].
+    ///
+    /// Custom row renderers have to use [`render_row_cells`] instead of [`TableRow::render_row`] for this to work.
+    #[prop(optional, into)]
+    sticky_columns: MaybeSignal<Vec<usize>>,
+    /// The indices of the columns that stay pinned to the right while the table is scrolled
+    /// horizontally, e.g. a column with action buttons. Like `sticky_columns` but the `right` offset is
+    /// the sum of the widths of the right sticky columns displayed after them. Their cells get the
+    /// classes [`STICKY_CELL_CLASS`], [`STICKY_RIGHT_CELL_CLASS`] and those of
+    /// [`TableClassesProvider::sticky_right_cell`], e.g. for a shadow on their left edge.
+    ///
+    /// Clicks on buttons in these cells don't select the row like anywhere else in the row.
+    #[prop(optional, into)]
+    sticky_right_columns: MaybeSignal<Vec<usize>>,
+    /// The text direction of the table. Set this to [`Direction::Rtl`] if the table is laid out right
+    /// to left, i.e. if it or one of its ancestors has the attribute `dir="rtl"`. Then the
+    /// `sticky_columns` are pinned to the right and the `sticky_right_columns` to the left, the
+    /// resize handles are on the left edge of the header cells and the thead, tbody and tfoot get the
+    /// classes of [`TableClassesProvider::rtl`]. Custom renderers can read it with [`use_table_direction`].
+    /// Defaults to [`Direction::Ltr`].
+    #[prop(optional, into)]
+    direction: MaybeSignal<Direction>,
     /// This is called once the number of rows is known.
     /// It will only be executed if [`TableDataProvider::row_count`] returns `Some(...)`.
     ///
@@ -148,206 +582,1797 @@ pub fn TableContent<Row, DataP, Err, ClsP>(
     /// for how to use.
     #[prop(optional, into)]
     on_row_count: EventHandler<usize>,
+    /// The total number of rows if it's known independently of the data provider, e.g. pushed by the
+    /// server. If given, it's used instead of [`TableDataProvider::row_count`] and every change of it
+    /// resizes the table without reloading it: loaded rows, the selection and the scroll position are
+    /// kept. To re-read the row count from the data provider instead, use
+    /// [`ReloadController::reload_row_count`].
+    #[prop(optional, into)]
+    total_row_count: Option<Signal<usize>>,
+    /// Event handler callback for when the data provider returns an error from [`TableDataProvider::get_rows`].
+    /// Use this to log errors or send them to your error tracking. The failed rows are rendered by
+    /// the `error_row_renderer` which can get the error through [`use_row_load_error`].
+    #[prop(optional, into)]
+    on_error: EventHandler<LoadErrorEvent<Err>>,
     /// Allows to manually trigger a reload.
     ///
     /// See the [paginated_rest_datasource example](https://github.com/Synphonyte/leptos-struct-table/blob/master/examples/paginated_rest_datasource/src/main.rs)
     /// for how to use.
     #[prop(optional)]
     reload_controller: ReloadController,
+    /// Allows to push changes of rows into the table while it's shown, e.g. from a websocket.
+    /// Updates are applied in place, inserts and deletes reload the loaded rows while keeping the
+    /// scroll position and the selection. Requires `row_key_fn`. See [`RowMutationController`].
+    #[prop(optional, into)]
+    row_mutations: Option<RowMutationController<Row>>,
+    /// If given, requests for rows that fail are retried with an exponential backoff as defined by
+    /// the [`RetryPolicy`] until they succeed or its `max_attempts` are reached. The rows are shown as
+    /// loading rows meanwhile and only rendered by the `error_row_renderer` after the last attempt.
+    /// Retries of rows that have been scrolled out of view are cancelled.
+    ///
+    /// Every failed attempt is passed to `on_error`. Use [`ReloadController::retry_failed`] to retry
+    /// the failed rows manually.
+    #[prop(optional, into)]
+    retry_policy: Option<RetryPolicy>,
+    /// Allows to control the table from the outside, e.g. to open a cell for editing.
+    ///
+    /// See the [editable example](https://github.com/Synphonyte/leptos-struct-table/blob/master/examples/editable/src/main.rs)
+    /// for how to use.
+    #[prop(optional)]
+    table_controller: TableController,
+    /// Event handler for when a cell has been opened for editing through [`TableController::edit_cell`].
+    /// If the row wasn't loaded yet, this is called once it has been loaded.
+    #[prop(optional, into)]
+    on_cell_focus: EventHandler<CellFocusEvent<Row>>,
     /// The display strategy to use when rendering the table.
     /// Can be one of
     /// - `Virtualization`
     /// - `InfiniteScroll`
+    /// - `LoadMore`
     /// - `Pagination`  
     ///
     /// Please check [`DisplayStrategy`] to see explanations of all available options.
-    #[prop(optional)]
-    display_strategy: DisplayStrategy,
+    ///
+    /// This can be a signal to switch the display strategy at runtime. Sorting, selection and the
+    /// loaded rows are kept. The first visible row stays roughly the same: switching to
+    /// `Pagination` shows the page that contains it and switching from `Pagination` scrolls to the
+    /// first row of the current page.
+    #[prop(optional, into)]
+    display_strategy: MaybeSignal<DisplayStrategy>,
+    /// Event handler callback for when the current page changes with [`DisplayStrategy::Pagination`],
+    /// e.g. through [`PaginationController::go_to`] or [`PaginationController::set_page_size`].
+    #[prop(optional, into)]
+    on_page_change: EventHandler<PageChangeEvent>,
+    /// Event handler callback for when the rows inside of the viewport change, e.g. to mark rows as
+    /// seen. It's debounced while scrolling and called once after the table has been rendered.
+    /// See [`VisibleRangeChangeEvent`].
+    #[prop(optional, into)]
+    on_visible_range_change: EventHandler<VisibleRangeChangeEvent>,
     /// The maximum number of loading rows to display. Defaults to `None` which means unlimited.
     /// Use this if you load a small number of rows and don't want the entire screen to be full of
     /// loading rows.
     #[prop(optional)]
     loading_row_display_limit: Option<usize>,
+    /// How many rows are rendered (and loaded) above and below the visible rows. Defaults to [`DEFAULT_OVERSCAN`].
+    ///
+    /// Higher values reduce blank flashes during fast scrolling at the cost of more DOM nodes and
+    /// more rows being fetched from the data provider. With [`DisplayStrategy::Pagination`] this
+    /// preloads rows of the neighbouring pages.
+    ///
+    /// Rows of the overscan that haven't been loaded yet are requested together with the visible rows
+    /// and rendered with the `loading_row_renderer` meanwhile. No rows are requested beyond the
+    /// row count of the data provider once it's known.
+    #[prop(default = DEFAULT_OVERSCAN)]
+    overscan: usize,
+    /// Whether rows that have been loaded are kept after they have been scrolled out of view. Defaults to `true`.
+    ///
+    /// Set this to `false` for rapidly changing data where correctness beats efficiency: only the
+    /// rows that are currently rendered (including `overscan`) are kept and every row that comes back
+    /// into view is fetched again from the data provider. This results in a lot more requests
+    /// though, especially during scrolling. Rows that are still loading are never requested twice.
+    #[prop(default = true)]
+    cache: bool,
+    /// Groups the body rows into bands of `band_size` rows. Every row gets the additional classes of
+    /// [`TableClassesProvider::row_band`] which by default marks every second band. This is independent
+    /// of the zebra striping of the classes provider so both can be combined.
+    #[prop(optional)]
+    band_size: Option<usize>,
+    /// Returns the height in pixels of a row from its data and its index, or `None` if it's not known.
+    /// Without this all rows that aren't rendered are assumed to have the average height of the
+    /// rendered rows.
+    ///
+    /// Use this if the height of rows varies and can be derived from the data (e.g. the number of
+    /// tags in a row). Rows are positioned with these hints as soon as they are loaded, which reduces
+    /// scroll jitter. Once a row has been rendered its measured height is used instead.
+    #[prop(optional, into)]
+    row_height_fn: Option<RowHeightFn<Row>>,
+    /// The height in pixels that is assumed for rows that haven't been rendered or measured yet.
+    /// Defaults to [`DEFAULT_ESTIMATED_ROW_HEIGHT`]. A good guess reduces the scroll jumps while the first rows are measured.
+    #[prop(default = DEFAULT_ESTIMATED_ROW_HEIGHT)]
+    estimated_row_height: f64,
+    /// If `true`, every rendered row is measured with a `ResizeObserver` and its height is kept after
+    /// it has been scrolled out of view. Rows are then positioned by the sum of the heights of the
+    /// rows before them. Rows that haven't been measured yet are assumed to have the average height of
+    /// the measured ones (starting with `estimated_row_height`) and the scroll position is corrected
+    /// once their actual height is known. Defaults to `false`.
+    ///
+    /// Use this for virtualized tables with rows of arbitrary and unpredictable height, e.g. wrapping
+    /// text or images. If the height can be derived from the data, `row_height_fn` is cheaper.
+    #[prop(optional)]
+    measure_row_heights: bool,
 
     #[prop(optional)] _marker: PhantomData<Err>,
 ) -> impl IntoView
 where
     Row: TableRow<ClassesProvider = ClsP> + Clone + 'static,
     DataP: TableDataProvider<Row, Err> + 'static,
-    Err: Debug,
+    Err: Debug + 'static,
     ClsP: TableClassesProvider + Copy + 'static,
 {
     let on_change = store_value(on_change);
-    let rows = Rc::new(RefCell::new(rows));
+    // the detail rows of expanded rows change the height of their rows
+    let measure_row_heights = measure_row_heights || expanded_row_renderer.is_some();
+    // the latest change per cell (row index, column index) that is waiting for the debounce
+    let pending_changes = store_value(HashMap::<
+        (usize, usize),
+        (ChangeEvent<Row>, Option<TimeoutHandle>),
+    >::new());
+
+    let flush_changes = move |except: Option<(usize, usize)>| {
+        let changes = pending_changes
+            .try_update_value(|pending_changes| {
+                let cells = pending_changes
+                    .keys()
+                    .filter(|cell| Some(**cell) != except)
+                    .copied()
+                    .collect::<Vec<_>>();
+
+                cells
+                    .into_iter()
+                    .filter_map(|cell| pending_changes.remove(&cell))
+                    .collect::<Vec<_>>()
+            })
+            .unwrap_or_default();
+
+        for (evt, timeout) in changes {
+            if let Some(timeout) = timeout {
+                timeout.clear();
+            }
+            on_change.with_value(|on_change| on_change.run(evt));
+        }
+    };
+
+    let emit_change = Callback::new(move |evt: ChangeEvent<Row>| {
+        let Some(debounce_ms) = change_debounce_ms else {
+            on_change.with_value(|on_change| on_change.run(evt));
+            return;
+        };
+
+        let cell = (evt.row_index, evt.col_index);
+
+        // editing another cell means the user is done with the previous one
+        flush_changes(Some(cell));
+
+        let timeout = set_timeout_with_handle(
+            move || {
+                if let Some((evt, _)) = pending_changes
+                    .try_update_value(|pending_changes| pending_changes.remove(&cell))
+                    .flatten()
+                {
+                    on_change.with_value(|on_change| on_change.run(evt));
+                }
+            },
+            Duration::from_millis(debounce_ms as u64),
+        )
+        .ok();
+
+        pending_changes.update_value(|pending_changes| {
+            if let Some((_, Some(previous_timeout))) = pending_changes.insert(cell, (evt, timeout))
+            {
+                previous_timeout.clear();
+            }
+        });
+    });
+
+    on_cleanup(move || flush_changes(None));
+    let on_error = store_value(on_error);
+    let rows = Rc::new(ProviderCell::new(rows));
+    let state = TableState::new(Rc::clone(&rows), sorting, selection);
 
     let class_provider = ClsP::new();
 
     let row_class = Signal::derive(move || row_class.get());
     let loading_cell_inner_class = Signal::derive(move || loading_cell_inner_class.get());
     let loading_cell_class = Signal::derive(move || loading_cell_class.get());
-    let thead_class = Signal::derive(move || class_provider.thead(&thead_class.get()));
+    let direction = Signal::derive(move || direction.get());
+    let direction_class = move || match direction.get() {
+        Direction::Ltr => String::new(),
+        Direction::Rtl => format!(" {}", class_provider.rtl()),
+    };
+    let thead_class = Signal::derive(move || {
+        format!(
+            "{}{}",
+            class_provider.thead(&thead_class.get()),
+            direction_class()
+        )
+    });
     let thead_row_class = Signal::derive(move || class_provider.thead_row(&thead_row_class.get()));
-    let tbody_class = Signal::derive(move || class_provider.tbody(&tbody_class.get()));
+    let filter_row_class =
+        Signal::derive(move || class_provider.filter_row(&filter_row_class.get()));
+    let tbody_class = Signal::derive(move || {
+        format!(
+            "{}{}",
+            class_provider.tbody(&tbody_class.get()),
+            direction_class()
+        )
+    });
+    let tfoot_class = Signal::derive(move || {
+        format!(
+            "{}{}",
+            class_provider.tfoot(&tfoot_class.get()),
+            direction_class()
+        )
+    });
 
-    let loaded_rows = create_rw_signal(LoadedRows::<Row>::new());
+    let row_reorder = row_reorderable.then(|| RowReorderContext {
+        enabled: Signal::derive(move || {
+            sorting.with(|sorting| sorting.iter().all(|(_, sort)| *sort == ColumnSort::None))
+        }),
+        drag_handle: row_drag_handle,
+        dragged_row: create_rw_signal(None),
+        drop_target: create_rw_signal(None),
+        armed_row: create_rw_signal(None),
+        on_move: {
+            let on_row_move = on_row_move.clone();
+
+            Callback::new(move |(from_index, to_index)| {
+                on_row_move.run(RowMoveEvent {
+                    from_index,
+                    to_index,
+                })
+            })
+        },
+    });
+    let row_drag_handle_column = row_reorder.filter(|reorder| reorder.drag_handle);
+
+    let row_classes = move |row_index: usize, selected: bool, data_class: &str| {
+        let class = match data_class {
+            "" => class_provider.row(row_index, selected, &row_class.get()),
+            data_class => class_provider.row(
+                row_index,
+                selected,
+                &format!("{} {data_class}", row_class.get()),
+            ),
+        };
 
-    let first_selected_index = create_rw_signal(None::<usize>);
+        let class = match band_size {
+            Some(band_size) => format!("{class} {}", class_provider.row_band(row_index, band_size)),
+            None => class,
+        };
 
-    let (row_count, set_row_count) = create_signal(None::<usize>);
+        let class = if table_controller.highlighted_row.get() == Some(row_index) {
+            format!("{class} {}", class_provider.highlighted_row())
+        } else {
+            class
+        };
 
-    let set_known_row_count = move |row_count: usize| {
-        set_row_count.set(Some(row_count));
-        loaded_rows.update(|loaded_rows| loaded_rows.resize(row_count));
-        on_row_count.run(row_count);
-        display_strategy.set_row_count(row_count);
+        match row_reorder {
+            Some(row_reorder)
+                if row_reorder.drop_target.get() == Some(row_index)
+                    && row_reorder.dragged_row.get() != Some(row_index) =>
+            {
+                format!("{class} {}", class_provider.row_drop_target())
+            }
+            _ => class,
+        }
     };
 
-    let load_row_count = {
-        let rows = Rc::clone(&rows);
-        let set_known_row_count = set_known_row_count.clone();
+    let loaded_rows = state.loaded_rows;
+    // the number of failed requests of rows that are being retried by index
+    let failed_attempts = store_value(HashMap::<usize, usize>::new());
+    // notified when rows have been reset to be loaded again without reloading the table
+    let load_trigger = create_trigger();
+    // rows whose height is known from `row_height_fn` or from measuring them
+    let row_heights = create_rw_signal(BTreeMap::<usize, f64>::new());
 
-        move || {
-            spawn_local({
-                let rows = Rc::clone(&rows);
-                let set_known_row_count = set_known_row_count.clone();
+    let first_selected_index = create_rw_signal(None::<usize>);
+    // keys of the rows that were selected before a reload and haven't been loaded again yet
+    let pending_selection_keys = store_value(HashSet::<String>::new());
+    let selection_order = store_value(Vec::<usize>::new());
+    let selection_limit =
+        max_selection.map(|max_selection| (max_selection, selection_limit_policy));
 
-                async move {
-                    let row_count = rows.borrow().row_count().await;
+    let row_count = state.row_count;
 
-                    if let Some(row_count) = row_count {
-                        set_known_row_count(row_count);
-                    }
-                }
-            })
+    let (first_data_loaded, set_first_data_loaded) = create_signal(false);
+
+    state.on_row_count(move |row_count| {
+        if row_count == 0 {
+            set_first_data_loaded.set(true);
         }
-    };
+        on_row_count.run(row_count);
+    });
+
+    let load_row_count = move || state.load_row_count(total_row_count);
+
+    // builds the event with the current selection after it has been changed
+    let selection_change_event =
+        move |selected: bool, row: Option<(usize, Row)>, from_select_all: bool| {
+            let selected_indices = selection.selected_indices_untracked();
+
+            let selected_rows = loaded_rows.with_untracked(|loaded_rows| {
+                selected_indices
+                    .iter()
+                    .filter(|i| **i < loaded_rows.len())
+                    .filter_map(|i| match &loaded_rows[*i] {
+                        RowState::Loaded(row) => Some(row.clone()),
+                        _ => None,
+                    })
+                    .collect()
+            });
+
+            let (row_index, row) = row.unzip();
+
+            SelectionChangeEvent {
+                selected,
+                row_index,
+                row,
+                selected_indices,
+                selected_rows,
+                from_select_all,
+            }
+        };
 
-    let (reload_count, set_reload_count) = create_signal(0_usize);
+    let reload_count = state.reload_count;
     let clear = {
-        let load_row_count = load_row_count.clone();
+        let on_selection_change = on_selection_change.clone();
+        let row_key_fn = row_key_fn.clone();
 
         move |clear_row_count: bool| {
+            let selected_indices = selection.selected_indices_untracked();
+
+            // with `row_key_fn` the selected rows are selected again once they're loaded
+            let selected_keys = row_key_fn.as_ref().map(|row_key_fn| {
+                loaded_rows.with_untracked(|loaded_rows| {
+                    selected_indices
+                        .iter()
+                        .filter(|i| **i < loaded_rows.len())
+                        .filter_map(|i| match &loaded_rows[*i] {
+                            RowState::Loaded(row) => Some(row_key_fn.run(row)),
+                            _ => None,
+                        })
+                        .collect::<Vec<_>>()
+                })
+            });
+
             selection.clear();
+            match selected_keys {
+                Some(selected_keys) => {
+                    pending_selection_keys.update_value(|keys| keys.extend(selected_keys))
+                }
+                None if !selected_indices.is_empty() => {
+                    on_selection_change.run(selection_change_event(false, None, false));
+                }
+                None => {}
+            }
             first_selected_index.set(None);
+            selection_order.update_value(|order| order.clear());
+            if expanded.with_untracked(|expanded| !expanded.is_empty()) {
+                expanded.set(HashSet::new());
+            }
+            table_controller.stop_editing();
 
-            loaded_rows.update(|loaded_rows| {
-                loaded_rows.clear();
-            });
+            state.clear_rows();
+            failed_attempts.update_value(|failed_attempts| failed_attempts.clear());
+            row_heights.update(|row_heights| row_heights.clear());
 
             if clear_row_count {
                 let reload = row_count.get_untracked().is_some();
-                set_row_count.set(None);
+                row_count.set(None);
                 if reload {
                     load_row_count();
                 }
             }
 
-            set_reload_count.set(reload_count.get().overflowing_add(1).0);
+            state.discard_requests();
         }
     };
 
-    let on_head_click = {
-        let rows = Rc::clone(&rows);
-        let clear = clear.clone();
+    let on_head_click = move |event: TableHeadEvent| {
+        let col_index = event.index;
+        sorting.update(move |sorting| update_sorting(sorting, col_index));
+
+        on_sort_change.run(SortChangeEvent {
+            col_index,
+            sorting: sorting.get_untracked(),
+        });
+    };
 
-        move |event: TableHeadEvent| {
-            sorting.update(move |sorting| update_sorting_from_event(sorting, event));
+    // the sorting can also be changed from the outside, e.g. by `ActiveFiltersBar`
+    create_effect({
+        let clear = clear.clone();
 
-            rows.borrow_mut().set_sorting(&sorting());
+        move |prev: Option<()>| {
+            state.apply_sorting();
 
-            clear(false);
+            if prev.is_some() {
+                clear(false);
+            }
         }
-    };
+    });
 
     create_effect({
         let rows = Rc::clone(&rows);
+        let clear = clear.clone();
 
-        move |_| {
-            // triggered when `ReloadController::reload()` is called
-            reload_controller.track();
-            rows.borrow().track();
-            clear(true);
-        }
-    });
+        move |prev_filters: Option<HashMap<usize, String>>| {
+            let filters = filters.get();
 
-    let selected_indices = match selection {
-        Selection::None => Signal::derive(|| HashSet::new()),
-        Selection::Single(selected_index) => Signal::derive(move || {
-            selected_index
-                .get()
-                .map(|i| HashSet::from([i]))
-                .unwrap_or_default()
-        }),
-        Selection::Multiple(selected_indices) => selected_indices.into(),
-    };
+            if prev_filters.as_ref() != Some(&filters) {
+                let filters = filters.clone();
+                rows.update(move |rows| rows.set_filters(&filters));
+            }
 
-    let UseScrollReturn { y, set_y, .. } = use_scroll_with_options(
-        scroll_container,
-        UseScrollOptions::default().throttle(100.0),
-    );
+            if let Some(prev_filters) = prev_filters {
+                let changed_col_indices = prev_filters
+                    .keys()
+                    .chain(filters.keys())
+                    .filter(|col_index| prev_filters.get(col_index) != filters.get(col_index))
+                    .copied()
+                    .collect::<HashSet<_>>();
+
+                if !changed_col_indices.is_empty() {
+                    // filtering changes the number of rows
+                    clear(true);
+                }
 
-    let UseElementSizeReturn { height, .. } = use_element_size_with_options(
-        scroll_container,
-        UseElementSizeOptions::default().box_(web_sys::ResizeObserverBoxOptions::ContentBox),
-    );
+                for col_index in changed_col_indices {
+                    on_filter_change.run(FilterChangeEvent {
+                        col_index,
+                        value: filters.get(&col_index).cloned().unwrap_or_default(),
+                        filters: filters.clone(),
+                    });
+                }
+            }
 
-    if is_browser()
-        && matches!(
-            display_strategy,
-            DisplayStrategy::Virtualization | DisplayStrategy::Pagination { .. }
-        )
-    {
-        load_row_count();
-    }
+            filters
+        }
+    });
 
-    let (average_row_height, set_average_row_height) = create_signal(20.0);
+    create_effect({
+        let rows = Rc::clone(&rows);
+        let clear = clear.clone();
+        let search = search.clone();
 
-    let first_visible_row_index = if let DisplayStrategy::Pagination {
-        controller,
-        row_count,
-    } = display_strategy
-    {
-        create_memo(move |_| controller.current_page.get() * row_count)
-    } else {
-        create_memo(move |_| (y.get() / average_row_height.get()).floor() as usize)
-    };
-    let visible_row_count = match display_strategy {
-        DisplayStrategy::Pagination { row_count, .. } => Signal::derive(move || row_count),
+        move |prev_search: Option<String>| {
+            let search = search.get();
 
-        DisplayStrategy::Virtualization | DisplayStrategy::InfiniteScroll => {
-            create_memo(move |_| {
-                ((height.get() / average_row_height.get()).ceil() as usize).max(20)
-            })
-            .into()
-        }
-    };
+            if prev_search.as_ref() != Some(&search) {
+                let search = search.clone();
+                rows.update(move |rows| rows.set_search(&search));
+            }
 
-    let (display_range, set_display_range) = create_signal(0..0);
+            if prev_search.is_some_and(|prev_search| prev_search != search) {
+                // searching changes the number of rows
+                clear(true);
 
-    let placeholder_height_before =
-        if matches!(display_strategy, DisplayStrategy::Pagination { .. }) {
-            Signal::derive(move || 0.0)
-        } else {
-            create_memo(move |_| display_range.get().start as f64 * average_row_height.get()).into()
-        };
+                on_search_change.run(SearchChangeEvent {
+                    search: search.clone(),
+                });
+            }
 
-    let placeholder_height_after = if matches!(display_strategy, DisplayStrategy::Pagination { .. })
-    {
-        Signal::derive(move || 0.0)
-    } else {
-        create_memo(move |_| {
-            let row_count_after = if let Some(row_count) = row_count.get() {
-                (row_count.saturating_sub(display_range.get().end)) as f64
-            } else {
-                0.0
+            search
+        }
+    });
+
+    if let Some(column_reorder_controller) = column_reorder_controller {
+        create_effect(move |_| {
+            let Some((col_index, target_col_index)) = column_reorder_controller.move_request.get()
+            else {
+                return;
             };
 
-            row_count_after * average_row_height.get()
-        })
-        .into()
-    };
+            let order = column_order.with_untracked(|column_order| {
+                move_column(
+                    column_order,
+                    Row::column_count(),
+                    col_index,
+                    target_col_index,
+                )
+            });
+            column_order.set(order.clone());
 
-    let tbody_ref = create_node_ref::<AnyElement>();
+            on_column_reorder.run(ColumnReorderEvent {
+                col_index,
+                target_col_index,
+                column_order: order,
+            });
+        });
+
+        provide_context(column_reorder_controller);
+    }
+
+    create_effect(move |prev: Option<()>| {
+        // triggered when `ReloadController::retry_failed()` is called
+        reload_controller.retry_trigger.track();
+
+        if prev.is_some() {
+            failed_attempts.update_value(|failed_attempts| failed_attempts.clear());
+            loaded_rows.update(|loaded_rows| loaded_rows.reset_errors());
+            load_trigger.notify();
+        }
+    });
+
+    create_effect({
+        let clear = clear.clone();
+
+        move |_| {
+            // triggered when `ReloadController::reload()` is called
+            reload_controller.track();
+            state.track_provider();
+            clear(true);
+        }
+    });
+
+    create_effect({
+        move |prev: Option<()>| {
+            // triggered when `ReloadController::reload_row_count()` is called
+            reload_controller.row_count_trigger.track();
+            if let Some(total_row_count) = total_row_count {
+                total_row_count.track();
+            }
+
+            // the row count of the data provider is only updated if it has been loaded before,
+            // otherwise this happens when it's needed anyway
+            if prev.is_some() && (total_row_count.is_some() || row_count.get_untracked().is_some())
+            {
+                load_row_count();
+            }
+        }
+    });
+
+    // the latest reload request of every row so only its result is used
+    let row_reload_request_ids = store_value(HashMap::<usize, usize>::new());
+    // bumped when a row has been reloaded so it is rendered again
+    let row_versions = store_value(HashMap::<usize, usize>::new());
+
+    create_effect({
+        let rows = Rc::clone(&rows);
+
+        move |_| {
+            reload_controller.row_reload_requests.track();
+            let requested_ranges = reload_controller
+                .row_reload_requests
+                .try_update_untracked(std::mem::take)
+                .unwrap_or_default();
+
+            // only rows that are loaded are reloaded and overlapping ranges are coalesced
+            let indices = loaded_rows.with_untracked(|loaded_rows| {
+                requested_ranges
+                    .into_iter()
+                    .flat_map(|range| range.start..range.end.min(loaded_rows.len()))
+                    .filter(|index| {
+                        matches!(
+                            loaded_rows[*index],
+                            RowState::Loaded(_) | RowState::Error(_)
+                        )
+                    })
+                    .collect::<BTreeSet<_>>()
+            });
+
+            for range in reload_ranges(&indices, DataP::CHUNK_SIZE) {
+                let request_ids = indices
+                    .range(range.clone())
+                    .map(|index| {
+                        let request_id = row_reload_request_ids.with_value(|request_ids| {
+                            request_ids
+                                .get(index)
+                                .map_or(0, |request_id| request_id + 1)
+                        });
+                        row_reload_request_ids.update_value(|request_ids| {
+                            request_ids.insert(*index, request_id);
+                        });
+
+                        (*index, request_id)
+                    })
+                    .collect::<Vec<_>>();
+
+                spawn_local({
+                    let rows = Rc::clone(&rows);
+
+                    async move {
+                        let latest_reload_count = reload_count.get_untracked();
+
+                        let result = rows.get_rows(range.clone()).await;
+
+                        // make sure the loaded data is still valid
+                        if reload_count.get_untracked() != latest_reload_count {
+                            return;
+                        }
+
+                        // rows that have been reloaded again in the meantime use the data of the last call
+                        let request_ids = request_ids
+                            .into_iter()
+                            .filter(|(index, request_id)| {
+                                row_reload_request_ids.with_value(|request_ids| {
+                                    request_ids.get(index) == Some(request_id)
+                                })
+                            })
+                            .collect::<Vec<_>>();
+                        if request_ids.is_empty() {
+                            return;
+                        }
+
+                        let (reloaded_rows, reloaded_range) = match result {
+                            Ok(result) => result,
+                            Err(err) => {
+                                // the rows keep their previous state
+                                on_error.get_value().run(LoadErrorEvent {
+                                    range,
+                                    error: Rc::new(err),
+                                });
+                                return;
+                            }
+                        };
+
+                        let mut reloaded_rows =
+                            reloaded_rows.into_iter().map(Some).collect::<Vec<_>>();
+                        let reloaded_rows = request_ids
+                            .into_iter()
+                            .filter_map(|(index, _)| {
+                                let row = index
+                                    .checked_sub(reloaded_range.start)
+                                    .and_then(|offset| reloaded_rows.get_mut(offset))?
+                                    .take()?;
+
+                                Some((index, row))
+                            })
+                            .collect::<Vec<_>>();
+
+                        row_versions.update_value(|row_versions| {
+                            for (index, _) in &reloaded_rows {
+                                *row_versions.entry(*index).or_default() += 1;
+                            }
+                        });
+                        loaded_rows.update(|loaded_rows| {
+                            for (index, row) in reloaded_rows {
+                                loaded_rows.write_reloaded(index, row);
+                            }
+                        });
+                    }
+                });
+            }
+        }
+    });
+
+    if let Some(row_mutations) = row_mutations {
+        create_effect({
+            let clear = clear.clone();
+            let row_key_fn = row_key_fn.clone();
+
+            move |_| {
+                row_mutations.mutations.track();
+                let mutations = row_mutations
+                    .mutations
+                    .try_update_untracked(std::mem::take)
+                    .unwrap_or_default();
+
+                let mut reload = false;
+
+                for mutation in mutations {
+                    let (RowMutation::Update(row), Some(row_key_fn)) = (mutation, &row_key_fn)
+                    else {
+                        // only the data provider knows where the following rows are now
+                        reload = true;
+                        continue;
+                    };
+
+                    let key = row_key_fn.run(&row);
+                    let Some(index) = loaded_rows.with_untracked(|loaded_rows| {
+                        loaded_rows.position_loaded(|row| row_key_fn.run(row) == key)
+                    }) else {
+                        continue;
+                    };
+
+                    // a pending reload of this row would overwrite the pushed data
+                    row_reload_request_ids.update_value(|request_ids| {
+                        request_ids.remove(&index);
+                    });
+                    row_versions.update_value(|row_versions| {
+                        *row_versions.entry(index).or_default() += 1;
+                    });
+                    loaded_rows.update(|loaded_rows| {
+                        loaded_rows.write_reloaded(index, row);
+                    });
+                }
+
+                if reload {
+                    clear(false);
+
+                    // like `ReloadController::reload_row_count()` this keeps the scrollbar
+                    if total_row_count.is_some() || row_count.get_untracked().is_some() {
+                        load_row_count();
+                    }
+                }
+            }
+        });
+    }
+
+    let selected_indices = match selection {
+        Selection::None => Signal::derive(|| HashSet::new()),
+        Selection::Single(selected_index) => Signal::derive(move || {
+            selected_index
+                .get()
+                .map(|i| HashSet::from([i]))
+                .unwrap_or_default()
+        }),
+        Selection::Multiple(selected_indices) => selected_indices.into(),
+    };
+
+    let row_selectable_fn = store_value(row_selectable_fn);
+    let is_row_selectable = move |row: &Row, index: usize| {
+        row_selectable_fn.with_value(|row_selectable_fn| {
+            row_selectable_fn
+                .as_ref()
+                .is_none_or(|row_selectable_fn| row_selectable_fn.run(row, index))
+        })
+    };
+
+    let select_row = {
+        let on_selection_change = on_selection_change.clone();
+        let on_selection_rejected = on_selection_rejected.clone();
+
+        move |i: usize, row: Row, modifiers: (bool, bool)| {
+            if !is_row_selectable(&row, i) {
+                return;
+            }
+
+            // the user's selection replaces the one from before the reload
+            pending_selection_keys.update_value(HashSet::clear);
+
+            let is_selectable = |index: usize| {
+                loaded_rows.with_untracked(|loaded_rows| {
+                    index < loaded_rows.len()
+                        && matches!(&loaded_rows[index], RowState::Loaded(row) if is_row_selectable(row, index))
+                })
+            };
+
+            let rejected = update_selection(
+                modifiers,
+                selection,
+                first_selected_index,
+                selection_order,
+                selection_limit,
+                is_selectable,
+                i,
+            );
+
+            let selected = untrack(|| selection.is_selected(i));
+
+            if let (true, Some(max_selection)) = (rejected, max_selection) {
+                on_selection_rejected.run(SelectionRejectedEvent {
+                    row_index: i,
+                    row: row.clone(),
+                    max_selection,
+                });
+
+                if !selected {
+                    return;
+                }
+            }
+
+            on_selection_change.run(selection_change_event(selected, Some((i, row)), false));
+        }
+    };
+
+    let selection_column =
+        (selection_checkboxes && !matches!(selection, Selection::None)).then(|| {
+            let selectable_indices = move || {
+                loaded_rows.with(|loaded_rows| {
+                    if select_all_including_unloaded {
+                        let row_count = row_count.get().unwrap_or(loaded_rows.len());
+                        (0..row_count)
+                            // rows that aren't loaded yet can't be checked
+                            .filter(|i| {
+                                *i >= loaded_rows.len()
+                                    || !matches!(&loaded_rows[*i], RowState::Loaded(row) if !is_row_selectable(row, *i))
+                            })
+                            .collect::<Vec<_>>()
+                    } else {
+                        (0..loaded_rows.len())
+                            .filter(|i| match &loaded_rows[*i] {
+                                RowState::Loaded(row) => is_row_selectable(row, *i),
+                                _ => false,
+                            })
+                            .collect()
+                    }
+                })
+            };
+
+            let toggle_all = {
+                let on_selection_change = on_selection_change.clone();
+                let on_selection_rejected = on_selection_rejected.clone();
+
+                move |_| {
+                    let Selection::Multiple(selected_indices) = selection else {
+                        return;
+                    };
+
+                    let indices = untrack(selectable_indices);
+                    // rows that are selected already keep their place in the selection order
+                    let added = indices
+                        .iter()
+                        .copied()
+                        .filter(|i| !untrack(|| selection.is_selected(*i)))
+                        .collect::<Vec<_>>();
+                    let selected = selection.toggle_all(&indices);
+
+                    if selected {
+                        if let Some((max_selection, policy)) = selection_limit {
+                            let rejected = selected_indices
+                                .try_update(|selected_indices| {
+                                    selection_order.try_update_value(|order| {
+                                        limit_selection(
+                                            selected_indices,
+                                            order,
+                                            &added,
+                                            max_selection,
+                                            policy,
+                                        )
+                                    })
+                                })
+                                .flatten()
+                                .unwrap_or_default();
+
+                            // reported once with the first row that couldn't be selected
+                            let rejected_row = rejected
+                                .then(|| {
+                                    added.iter().find_map(|&i| {
+                                        if untrack(|| selection.is_selected(i)) {
+                                            return None;
+                                        }
+
+                                        loaded_rows.with_untracked(|loaded_rows| {
+                                            if i >= loaded_rows.len() {
+                                                return None;
+                                            }
+
+                                            match &loaded_rows[i] {
+                                                RowState::Loaded(row) => Some((i, row.clone())),
+                                                _ => None,
+                                            }
+                                        })
+                                    })
+                                })
+                                .flatten();
+
+                            if let Some((row_index, row)) = rejected_row {
+                                on_selection_rejected.run(SelectionRejectedEvent {
+                                    row_index,
+                                    row,
+                                    max_selection,
+                                });
+                            }
+                        }
+                    } else {
+                        first_selected_index.set(None);
+                    }
+
+                    on_selection_change.run(selection_change_event(selected, None, true));
+                }
+            };
+
+            let toggle_row = {
+                let select_row = select_row.clone();
+
+                move |i: usize| {
+                    let row = loaded_rows.with_untracked(|loaded_rows| match &loaded_rows[i] {
+                        RowState::Loaded(row) => Some(row.clone()),
+                        _ => None,
+                    });
+
+                    if let Some(row) = row {
+                        // like clicking the row while pressing cmd/ctrl
+                        select_row(i, row, (true, false));
+                    }
+                }
+            };
+
+            SelectionColumnContext {
+                selection,
+                select_all_state: Signal::derive(move || {
+                    selection.select_all_state(&selectable_indices())
+                }),
+                toggle_all: Callback::new(toggle_all),
+                toggle_row: Callback::new(toggle_row),
+                radio_group: matches!(selection, Selection::Single(_)).then(next_radio_group),
+            }
+        });
+
+    let expansion_column = expanded_row_renderer
+        .is_some()
+        .then_some(ExpansionColumnContext { expanded });
+
+    // the number of columns including the selection and disclosure columns
+    let column_order_context = ColumnOrderContext {
+        order: column_order,
+        visibility: column_visibility,
+    };
+    let col_count = move || {
+        column_order_context.get(Row::column_count()).len()
+            + usize::from(row_drag_handle_column.is_some())
+            + usize::from(selection_column.is_some())
+            + usize::from(expansion_column.is_some())
+    };
+
+    provide_context(table_controller);
+    provide_context(DirectionContext(direction));
+    provide_context(ColumnNamesContext(Callback::new(Row::col_name)));
+    provide_context(ColumnTitlesContext(Signal::derive(move || {
+        column_titles.get()
+    })));
+    provide_context(LoadedRowsContext::new(loaded_rows));
+    provide_context(BadgeClass(Callback::new(move |value_slug: String| {
+        class_provider.badge(&value_slug)
+    })));
+    provide_context(column_order_context);
+    provide_context(ColumnCountContext(Signal::derive(col_count)));
+    if let Some(selection_column) = selection_column {
+        provide_context(selection_column);
+    }
+    if let Some(expansion_column) = expansion_column {
+        provide_context(expansion_column);
+    }
+    if let Some(row_selectable_fn) = row_selectable_fn.get_value() {
+        provide_context(row_selectable_fn);
+    }
+    if let Some(row_reorder) = row_reorder {
+        provide_context(row_reorder);
+    }
+    if let Some(cell_tooltip_fn) = cell_tooltip_fn {
+        provide_context(cell_tooltip_fn);
+    }
+    if let Some(getter_context) = getter_context {
+        provide_context(getter_context);
+    }
+    provide_context(CellInputHandler(on_cell_input));
+    if let Some(on_cell_click) = on_cell_click {
+        provide_context(CellClickContext(on_cell_click));
+    }
+    if let Some(cell_renderers) = cell_renderers {
+        provide_context(cell_renderers);
+    }
+    provide_context(SortPriorityClass(class_provider.thead_cell_sort_priority()));
+    if let Some(column_resize_controller) = column_resize_controller {
+        provide_context(column_resize_controller);
+        provide_context(ColumnResizeHandleClass(
+            class_provider.thead_cell_resize_handle(),
+        ));
+    }
+
+    let has_colgroup = column_resize_controller.is_some() || column_widths.is_some();
+    let column_widths =
+        column_widths.map(|column_widths| Signal::derive(move || column_widths.get()));
+
+    let colgroup = has_colgroup.then(|| {
+        let cols = move || {
+            column_order_context
+                .get(Row::column_count())
+                .into_iter()
+                .map(|col_index| {
+                    let width = move || {
+                        column_resize_controller
+                            .and_then(|controller| controller.width(col_index))
+                            .map(|width| format!("{width}px"))
+                            .or_else(|| {
+                                column_widths.and_then(|column_widths| {
+                                    column_widths.with(|widths| widths.get(&col_index).cloned())
+                                })
+                            })
+                    };
+
+                    view! { <col style:width=width /> }
+                })
+                .collect_view()
+        };
+
+        view! {
+            <colgroup>
+                {row_drag_handle_column.map(|_| view! { <col /> })}
+                {selection_column.map(|_| view! { <col /> })}
+                {expansion_column.map(|_| view! { <col /> })}
+                {cols}
+            </colgroup>
+        }
+    });
+
+    let sticky_columns = Signal::derive(move || sticky_columns.get());
+    let sticky_right_columns = Signal::derive(move || sticky_right_columns.get());
+    let sticky_widths = create_rw_signal(HashMap::new());
+    provide_context(StickyColumnsContext {
+        columns: sticky_columns,
+        right_columns: sticky_right_columns,
+        widths: sticky_widths,
+        offsets: create_memo(move |_| {
+            let order = column_order_context.get(Row::column_count());
+
+            sticky_columns.with(|sticky_columns| {
+                sticky_widths.with(|widths| {
+                    StickyColumnsContext::compute_offsets(sticky_columns, &order, widths)
+                })
+            })
+        }),
+        right_offsets: create_memo(move |_| {
+            let mut order = column_order_context.get(Row::column_count());
+            order.reverse();
+
+            sticky_right_columns.with(|sticky_columns| {
+                sticky_widths.with(|widths| {
+                    StickyColumnsContext::compute_offsets(sticky_columns, &order, widths)
+                })
+            })
+        }),
+        class: Callback::new(move |(col_index, is_head)| {
+            class_provider.sticky_cell(col_index, is_head)
+        }),
+        right_class: Callback::new(move |(col_index, is_head)| {
+            class_provider.sticky_right_cell(col_index, is_head)
+        }),
+        direction,
+    });
+
+    let UseScrollReturn { x, y, set_y, .. } = use_scroll_with_options(
+        scroll_container,
+        UseScrollOptions::default().throttle(100.0),
+    );
+
+    // the scroll position from before the search to restore once the search is cleared
+    let scroll_before_search = store_value(None::<f64>);
+    let restore_scroll = store_value(None::<f64>);
+
+    create_effect(move |prev_search: Option<bool>| {
+        let is_searching = search.with(|search| !search.is_empty());
+
+        match (prev_search, is_searching) {
+            (Some(false), true) => scroll_before_search.set_value(Some(y.get_untracked())),
+            (Some(true), false) => restore_scroll.set_value(scroll_before_search.get_value()),
+            _ => {}
+        }
+
+        is_searching
+    });
+
+    create_effect({
+        let set_y = set_y.clone();
+
+        move |_| {
+            if row_count.get().is_some() {
+                if let Some(scroll_y) = restore_scroll.try_update_value(Option::take).flatten() {
+                    // wait for the placeholders to grow before scrolling
+                    let set_y = set_y.clone();
+                    request_animation_frame(move || set_y(scroll_y));
+                }
+            }
+        }
+    });
+
+    let UseElementSizeReturn { width, height } = use_element_size_with_options(
+        scroll_container,
+        UseElementSizeOptions::default().box_(web_sys::ResizeObserverBoxOptions::ContentBox),
+    );
+
+    if virtualize_columns {
+        let rendered_columns = create_memo(move |_| {
+            let col_count = Row::column_count();
+            let order = column_order_context.get(col_count);
+
+            // the viewport hasn't been measured yet
+            let mut rendered = if width.get() == 0.0 {
+                vec![true; col_count]
+            } else {
+                let column_width = |col_index: usize| {
+                    column_resize_controller
+                        .and_then(|controller| controller.width(col_index))
+                        .or_else(|| {
+                            column_widths.and_then(|column_widths| {
+                                column_widths.with(|widths| {
+                                    widths
+                                        .get(&col_index)?
+                                        .strip_suffix("px")?
+                                        .trim()
+                                        .parse()
+                                        .ok()
+                                })
+                            })
+                        })
+                        .unwrap_or(estimated_column_width)
+                };
+
+                // the scroll position is negative in right-to-left tables
+                let x = x.get().abs();
+
+                rendered_columns(
+                    &order,
+                    col_count,
+                    column_width,
+                    x..x + width.get(),
+                    column_overscan,
+                )
+            };
+
+            let always_rendered = sticky_columns
+                .get()
+                .into_iter()
+                .chain(sticky_right_columns.get())
+                .chain(
+                    table_controller
+                        .editing_cell
+                        .get()
+                        .map(|cell| cell.col_index),
+                );
+            for col_index in always_rendered {
+                if let Some(rendered) = rendered.get_mut(col_index) {
+                    *rendered = true;
+                }
+            }
+
+            rendered
+        });
+
+        provide_context(ColumnVirtualizationContext { rendered_columns });
+    }
+
+    if is_browser() && display_strategy.get_untracked().needs_row_count() {
+        load_row_count();
+    }
+
+    let (average_row_height, set_average_row_height) = create_signal(estimated_row_height);
+
+    // number of rows that the user has requested to be shown with `DisplayStrategy::LoadMore`
+    let load_more_row_limit = create_rw_signal(0_usize);
+
+    // height that is available for the rows of a page with `PageSize::Auto`. `None` until measured.
+    let (page_height, set_page_height) = create_signal(None::<f64>);
+
+    // the resolved `PageSize` of the pagination. `None` if not paginated or not measured yet.
+    let page_row_count = create_memo(move |_| match display_strategy.get() {
+        DisplayStrategy::Pagination {
+            page_size,
+            controller,
+        } => match (controller.page_size_override.get(), page_size) {
+            (Some(page_size_override), _) => Some(page_size_override),
+            (None, PageSize::Fixed(_)) => Some(page_size.row_count(0.0)),
+            (None, PageSize::Auto { .. }) => page_height
+                .get()
+                .map(|page_height| page_size.row_count(page_height)),
+        },
+        DisplayStrategy::Virtualization
+        | DisplayStrategy::InfiniteScroll
+        | DisplayStrategy::LoadMore { .. } => None,
+    });
+
+    let first_visible_row_index = create_memo(move |_| match display_strategy.get() {
+        DisplayStrategy::Pagination { controller, .. } => {
+            controller.current_page.get() * page_row_count.get().unwrap_or_default()
+        }
+        DisplayStrategy::Virtualization
+        | DisplayStrategy::InfiniteScroll
+        | DisplayStrategy::LoadMore { .. } => row_heights
+            .with(|row_heights| row_index_at(y.get(), average_row_height.get(), row_heights)),
+    });
+    let visible_row_count = create_memo(move |_| match display_strategy.get() {
+        DisplayStrategy::Pagination { .. } => page_row_count.get().unwrap_or_default(),
+        DisplayStrategy::Virtualization | DisplayStrategy::InfiniteScroll => {
+            ((height.get() / average_row_height.get()).ceil() as usize).max(20)
+        }
+        DisplayStrategy::LoadMore { chunk_size } => load_more_row_limit.get().max(chunk_size),
+    });
+
+    create_effect(move |_| {
+        if let DisplayStrategy::Pagination { controller, .. } = display_strategy.get() {
+            controller.page_size_signal.set(page_row_count.get());
+        }
+    });
+
+    create_effect(move |prev_page: Option<Option<usize>>| {
+        let DisplayStrategy::Pagination { controller, .. } = display_strategy.get() else {
+            return None;
+        };
+
+        let page = controller.current_page.get();
+
+        if prev_page
+            .flatten()
+            .is_some_and(|prev_page| prev_page != page)
+        {
+            on_page_change.run(PageChangeEvent {
+                page,
+                page_count: controller.page_count().get_untracked(),
+                page_size: page_row_count.get_untracked(),
+            });
+        }
+
+        Some(page)
+    });
+
+    create_effect(move |_| {
+        if let (Some(row_count), Some(page_row_count)) = (row_count.get(), page_row_count.get()) {
+            display_strategy
+                .get()
+                .set_row_count(row_count, page_row_count);
+        }
+    });
+
+    let (display_range, set_display_range) = create_signal(0..0);
+
+    let placeholder_height_before: Signal<f64> = create_memo(move |_| {
+        if !display_strategy.get().uses_placeholders() {
+            return 0.0;
+        }
+
+        row_heights.with(|row_heights| {
+            row_offset(
+                display_range.get().start,
+                average_row_height.get(),
+                row_heights,
+            )
+        })
+    })
+    .into();
+
+    let placeholder_height_after: Signal<f64> = create_memo(move |_| {
+        if !display_strategy.get().uses_placeholders() {
+            return 0.0;
+        }
+
+        if let Some(row_count) = row_count.get() {
+            let end = display_range.get().end;
+            if end >= row_count {
+                return 0.0;
+            }
+
+            let average_row_height = average_row_height.get();
+
+            row_heights.with(|row_heights| {
+                row_offset(row_count, average_row_height, row_heights)
+                    - row_offset(end, average_row_height, row_heights)
+            })
+        } else {
+            0.0
+        }
+    })
+    .into();
+
+    // makes sure that the rows up to and including `row_index` are shown with `DisplayStrategy::LoadMore`
+    let show_load_more_rows_until = move |row_index: usize, chunk_size: usize| {
+        let chunk_size = chunk_size.max(1);
+        let row_limit = (row_index + 1).div_ceil(chunk_size) * chunk_size;
+
+        if row_limit > load_more_row_limit.get_untracked() {
+            load_more_row_limit.set(row_limit);
+        }
+    };
+
+    let load_more = Callback::new(move |_: ()| {
+        if let DisplayStrategy::LoadMore { chunk_size } = display_strategy.get_untracked() {
+            load_more_row_limit.set(visible_row_count.get_untracked() + chunk_size);
+        }
+    });
+
+    let show_load_more_row = create_memo(move |_| {
+        matches!(display_strategy.get(), DisplayStrategy::LoadMore { .. })
+            && row_count
+                .get()
+                .is_none_or(|row_count| display_range.get().end < row_count)
+    });
+
+    let tbody_ref = create_node_ref::<AnyElement>();
+
+    // the rows inside of the viewport without the overscan
+    let visible_range = create_memo(move |_| {
+        let start = first_visible_row_index.get();
+
+        let end = match display_strategy.get() {
+            DisplayStrategy::Pagination { .. } => start + page_row_count.get().unwrap_or_default(),
+            DisplayStrategy::Virtualization
+            | DisplayStrategy::InfiniteScroll
+            | DisplayStrategy::LoadMore { .. } => {
+                // the rows start below the header
+                let header_height = tbody_ref
+                    .get_untracked()
+                    .map(|tbody| tbody.offset_top() as f64)
+                    .unwrap_or_default();
+                let bottom = y.get() + height.get() - header_height;
+
+                // a row that ends exactly at the bottom isn't followed by a visible row
+                row_heights.with(|row_heights| {
+                    row_index_at(
+                        (bottom - 0.5).max(0.0),
+                        average_row_height.get(),
+                        row_heights,
+                    )
+                }) + 1
+            }
+        };
+
+        let row_count = row_count
+            .get()
+            .unwrap_or_else(|| loaded_rows.with(LoadedRows::len));
+        let end = end.min(row_count);
+
+        start.min(end)..end
+    });
+
+    let last_visible_range_event = store_value(None::<VisibleRangeChangeEvent>);
+    let emit_visible_range_change = use_debounce_fn(
+        move || {
+            // the viewport hasn't been measured yet
+            if height.get_untracked() == 0.0 {
+                return;
+            }
+
+            let event = VisibleRangeChangeEvent {
+                visible_range: visible_range.get_untracked(),
+                rendered_range: display_range.get_untracked(),
+            };
+
+            if last_visible_range_event.with_value(|last| last.as_ref() != Some(&event)) {
+                last_visible_range_event.set_value(Some(event.clone()));
+                on_visible_range_change.run(event);
+            }
+        },
+        100.0,
+    );
+
+    create_effect(move |_| {
+        visible_range.track();
+        display_range.track();
+        emit_visible_range_change();
+    });
+
+    let update_page_height = use_debounce_fn(
+        move || {
+            let DisplayStrategy::Pagination {
+                page_size,
+                controller,
+            } = display_strategy.get_untracked()
+            else {
+                return;
+            };
+
+            // `PaginationController::set_page_size` takes precedence over the measured page size
+            if controller.page_size_override.get_untracked().is_some() {
+                return;
+            }
+
+            // the rows start below the header
+            let header_height = tbody_ref
+                .get_untracked()
+                .map(|tbody| tbody.offset_top() as f64)
+                .unwrap_or_default();
+            let new_page_height = (height.get_untracked() - header_height).max(0.0);
+
+            let first_row_index = first_visible_row_index.get_untracked();
+            let prev_page_row_count = page_row_count.get_untracked();
+            let new_page_row_count = page_size.row_count(new_page_height);
+
+            batch(move || {
+                set_page_height.set(Some(new_page_height));
+
+                // keep the first row of the current page visible
+                if prev_page_row_count.is_some_and(|count| count != new_page_row_count) {
+                    controller
+                        .current_page
+                        .set(first_row_index / new_page_row_count);
+                }
+            });
+        },
+        AUTO_PAGE_SIZE_DEBOUNCE,
+    );
+
+    create_effect(move |_| {
+        if let DisplayStrategy::Pagination {
+            page_size: PageSize::Auto { .. },
+            ..
+        } = display_strategy.get()
+        {
+            // the size of the scroll container is observed with a `ResizeObserver`
+            if height.get() > 0.0 {
+                update_page_height();
+            }
+        }
+    });
+
+    table_controller
+        .row_index_at_offset
+        .set_value(Some(Rc::new(move |y: f64| {
+            row_index_at_offset(
+                tbody_ref,
+                display_range.get_untracked(),
+                average_row_height.get_untracked(),
+                row_count.get_untracked(),
+                y,
+            )
+        })));
+    on_cleanup(move || table_controller.row_index_at_offset.set_value(None));
+
+    table_controller.cached_rows.set(Some(create_memo(move |_| {
+        loaded_rows.with(LoadedRows::cached_ranges)
+    })));
+    on_cleanup(move || table_controller.cached_rows.set(None));
+
+    // notified when a row is scrolled to so its position is corrected if it's already rendered
+    let correct_scroll_trigger = create_trigger();
+
+    let scroll_row_into_view = {
+        let set_y = set_y.clone();
+
+        move |row_index: usize, align: ScrollAlign| match display_strategy.get_untracked() {
+            DisplayStrategy::Pagination { controller, .. } => {
+                if let Some(page_row_count) = page_row_count.get_untracked() {
+                    controller.current_page.set(row_index / page_row_count);
+                }
+            }
+            display_strategy @ (DisplayStrategy::Virtualization
+            | DisplayStrategy::InfiniteScroll
+            | DisplayStrategy::LoadMore { .. }) => {
+                if let DisplayStrategy::LoadMore { chunk_size } = display_strategy {
+                    show_load_more_rows_until(row_index, chunk_size);
+                }
+
+                let average_row_height = average_row_height.get_untracked();
+                let first_visible_row_index = first_visible_row_index.get_untracked();
+                let fully_visible_row_count =
+                    (height.get_untracked() / average_row_height).floor() as usize;
+
+                let (offset, row_height) = row_heights.with_untracked(|row_heights| {
+                    (
+                        row_offset(row_index, average_row_height, row_heights),
+                        row_heights
+                            .get(&row_index)
+                            .copied()
+                            .unwrap_or(average_row_height),
+                    )
+                });
+
+                match align {
+                    ScrollAlign::Nearest => {
+                        if row_index < first_visible_row_index
+                            || row_index >= first_visible_row_index + fully_visible_row_count
+                        {
+                            set_y(offset);
+                        }
+                    }
+                    ScrollAlign::Start => set_y(offset),
+                    ScrollAlign::Center => {
+                        set_y((offset - (height.get_untracked() - row_height) / 2.0).max(0.0))
+                    }
+                }
+            }
+        }
+    };
+
+    create_effect({
+        let scroll_row_into_view = scroll_row_into_view.clone();
+
+        move |_| {
+            if let Some(row_index) = focused_row.get() {
+                scroll_row_into_view(row_index, ScrollAlign::Nearest);
+            }
+        }
+    });
+
+    // emits the debounced changes as soon as the focus leaves the edited cell
+    let _ = use_event_listener(tbody_ref, ev::focusout, move |_| {
+        if change_debounce_ms.is_some() {
+            flush_changes(None);
+        }
+    });
+
+    // scrolls while a row is dragged close to the top or bottom edge of the scroll container
+    if let Some(row_reorder) = row_reorder {
+        let set_y = set_y.clone();
+
+        let _ = use_event_listener(tbody_ref, ev::dragover, move |evt| {
+            if !row_reorder.is_dragging() {
+                return;
+            }
+
+            let Some(element) = scroll_container.element() else {
+                return;
+            };
+
+            let (top, bottom) = if document().body().as_deref() == element.dyn_ref() {
+                let window_height = window()
+                    .inner_height()
+                    .ok()
+                    .and_then(|height| height.as_f64())
+                    .unwrap_or_default();
+
+                (0.0, window_height)
+            } else {
+                let rect = element.get_bounding_client_rect();
+
+                (rect.top(), rect.bottom())
+            };
+
+            let pointer_y = evt.client_y() as f64;
+
+            if pointer_y < top + AUTO_SCROLL_EDGE {
+                set_y((y.get_untracked() - AUTO_SCROLL_STEP).max(0.0));
+            } else if pointer_y > bottom - AUTO_SCROLL_EDGE {
+                set_y(y.get_untracked() + AUTO_SCROLL_STEP);
+            }
+        });
+    }
+
+    let _ = use_event_listener(tbody_ref, ev::keydown, {
+        let select_row = select_row.clone();
+        let on_row_click = on_row_click.clone();
+
+        move |evt: web_sys::KeyboardEvent| {
+            if is_interactive_event_target(&evt) {
+                return;
+            }
+
+            let last_row_index = row_count
+                .get_untracked()
+                .unwrap_or_else(|| loaded_rows.with_untracked(|loaded_rows| loaded_rows.len()))
+                .checked_sub(1);
+            let Some(last_row_index) = last_row_index else {
+                return;
+            };
+
+            let focused = focused_row.get_untracked();
+
+            match evt.key().as_str() {
+                "ArrowDown" => {
+                    evt.prevent_default();
+                    focused_row.set(Some(focused.map_or(0, |i| (i + 1).min(last_row_index))));
+                }
+                "ArrowUp" => {
+                    evt.prevent_default();
+                    focused_row.set(Some(focused.map_or(0, |i| i.saturating_sub(1))));
+                }
+                key @ (" " | "Enter") => {
+                    let Some(i) = focused else {
+                        return;
+                    };
+                    evt.prevent_default();
+
+                    let row = loaded_rows.with_untracked(|loaded_rows| {
+                        if i >= loaded_rows.len() {
+                            return None;
+                        }
+
+                        match &loaded_rows[i] {
+                            RowState::Loaded(row) => Some(row.clone()),
+                            _ => None,
+                        }
+                    });
+                    let Some(row) = row else {
+                        return;
+                    };
+
+                    if key == " " {
+                        select_row(i, row, (true, false));
+                    } else {
+                        on_row_click.run(RowClickEvent {
+                            row_index: i,
+                            row: row.clone(),
+                            mouse_event: click_event_from_keyboard_event(&evt),
+                        });
+
+                        if select_on_row_click {
+                            select_row(i, row, (evt.meta_key() || evt.ctrl_key(), evt.shift_key()));
+                        }
+                    }
+                }
+                _ => {}
+            }
+        }
+    });
+
+    create_effect(move |_| {
+        // makes the body focusable for keyboard navigation
+        if let Some(tbody) = tbody_ref.get() {
+            let _ = tbody.set_attribute("tabindex", "0");
+        }
+    });
+
+    let switch_display_strategy = {
+        let set_y = set_y.clone();
+
+        move |first_visible_row_index: usize| {
+            let display_strategy = display_strategy.get_untracked();
+
+            if row_count.get_untracked().is_none() && display_strategy.needs_row_count() {
+                load_row_count();
+            }
+
+            match display_strategy {
+                DisplayStrategy::Pagination { controller, .. } => {
+                    if let Some(page_row_count) = page_row_count.get_untracked() {
+                        controller
+                            .current_page
+                            .set(first_visible_row_index / page_row_count);
+                    }
+                }
+                DisplayStrategy::Virtualization
+                | DisplayStrategy::InfiniteScroll
+                | DisplayStrategy::LoadMore { .. } => {
+                    if let DisplayStrategy::LoadMore { chunk_size } = display_strategy {
+                        show_load_more_rows_until(first_visible_row_index, chunk_size);
+                    }
+
+                    let set_y = set_y.clone();
+
+                    // wait for the placeholders to be rendered so the table is high enough to scroll
+                    request_animation_frame(move || {
+                        set_y(row_heights.with_untracked(|row_heights| {
+                            row_offset(
+                                first_visible_row_index,
+                                average_row_height.get_untracked(),
+                                row_heights,
+                            )
+                        }));
+                    });
+                }
+            }
+        }
+    };
+
+    // the row of `TableController::scroll_to_row` whose scroll position is corrected once it's rendered
+    let pending_scroll = store_value(None::<(usize, ScrollAlign)>);
+    let highlight_timeout = store_value(None::<TimeoutHandle>);
+
+    create_effect({
+        let scroll_row_into_view = scroll_row_into_view.clone();
+
+        move |_| {
+            let Some((row_index, align)) = table_controller.scroll_request.get() else {
+                return;
+            };
+            let in_bounds = row_count
+                .get_untracked()
+                .is_none_or(|row_count| row_index < row_count);
+            if !in_bounds {
+                return;
+            }
+
+            scroll_row_into_view(row_index, align);
+
+            if !matches!(
+                display_strategy.get_untracked(),
+                DisplayStrategy::Pagination { .. }
+            ) {
+                pending_scroll.set_value(Some((row_index, align)));
+                correct_scroll_trigger.notify();
+            }
+
+            table_controller.highlighted_row.set(Some(row_index));
+            if let Some(timeout) = highlight_timeout.get_value() {
+                timeout.clear();
+            }
+            highlight_timeout.set_value(
+                set_timeout_with_handle(
+                    move || table_controller.highlighted_row.set(None),
+                    ROW_HIGHLIGHT_DURATION,
+                )
+                .ok(),
+            );
+        }
+    });
+
+    // the scroll position has been estimated from the average row height, so the rendered row can
+    // end up somewhere else. This scrolls again by the measured offset once the row is rendered.
+    create_effect({
+        let set_y = set_y.clone();
+
+        move |_| {
+            correct_scroll_trigger.track();
+            let display_range = display_range.get();
+            let Some((row_index, align)) = pending_scroll.get_value() else {
+                return;
+            };
+
+            let is_loaded = loaded_rows.with(|loaded_rows| {
+                row_index < loaded_rows.len()
+                    && matches!(loaded_rows[row_index], RowState::Loaded(_))
+            });
+            if !is_loaded || !display_range.contains(&row_index) {
+                return;
+            }
+            pending_scroll.set_value(None);
+
+            let set_y = set_y.clone();
+
+            // wait for the row to be rendered
+            request_animation_frame(move || {
+                let Some(tbody) = tbody_ref.get_untracked() else {
+                    return;
+                };
+                let tbody: &web_sys::Element = &tbody;
+
+                let Some(row) = rendered_row_bounds(tbody)
+                    .into_iter()
+                    .nth(row_index - display_range.start)
+                else {
+                    return;
+                };
+
+                let offset = row.top - tbody.get_bounding_client_rect().top();
+                let row_height = row.bottom - row.top;
+                let y = y.get_untracked();
+                let height = height.get_untracked();
+
+                let target_y = match align {
+                    ScrollAlign::Nearest if offset >= y && offset + row_height <= y + height => {
+                        return;
+                    }
+                    ScrollAlign::Nearest | ScrollAlign::Start => offset,
+                    ScrollAlign::Center => (offset - (height - row_height) / 2.0).max(0.0),
+                };
+
+                if (target_y - y).abs() >= 1.0 {
+                    set_y(target_y);
+                }
+            });
+        }
+    });
+
+    let focused_cell = store_value(None::<CellCoordinate>);
+
+    create_effect(move |_| {
+        if let Some((row_index, col_name)) = table_controller.edit_request.get() {
+            let col_index = (0..Row::column_count()).find(|i| Row::col_name(*i) == col_name);
+            let in_bounds = row_count
+                .get_untracked()
+                .is_none_or(|row_count| row_index < row_count);
+
+            if let (Some(col_index), true) = (col_index, in_bounds) {
+                scroll_row_into_view(row_index, ScrollAlign::Nearest);
+
+                focused_cell.set_value(None);
+                table_controller.editing_cell.set(Some(CellCoordinate {
+                    row_index,
+                    col_index,
+                }));
+            }
+        }
+    });
+
+    create_effect(move |_| {
+        let Some(cell) = table_controller.editing_cell.get() else {
+            focused_cell.set_value(None);
+            return;
+        };
+
+        if focused_cell.get_value() == Some(cell) {
+            return;
+        }
+
+        let row = loaded_rows.with(|loaded_rows| {
+            if cell.row_index >= loaded_rows.len() {
+                return None;
+            }
+
+            match &loaded_rows[cell.row_index] {
+                RowState::Loaded(row) => Some(row.clone()),
+                _ => None,
+            }
+        });
+
+        if let Some(row) = row {
+            focused_cell.set_value(Some(cell));
+
+            on_cell_focus.run(CellFocusEvent {
+                row_index: cell.row_index,
+                col_index: cell.col_index,
+                row,
+            });
+        }
+    });
 
     let compute_average_row_height = use_debounce_fn(
         move || {
@@ -359,132 +2384,394 @@ where
                 set_average_row_height,
                 placeholder_height_before,
                 loaded_rows,
+                row_heights,
+                measure_row_heights,
             );
         },
         50.0,
     );
 
-    create_effect(move |_| {
-        let first_visible_row_index = first_visible_row_index.get();
-        let visible_row_count = visible_row_count.get();
+    if measure_row_heights {
+        // rows can change their height after they have been rendered, e.g. when images are loaded
+        let compute_average_row_height = compute_average_row_height.clone();
+        let _ = use_resize_observer(tbody_ref, move |_, _| {
+            compute_average_row_height();
+        });
+    }
+
+    // loads the rows in `range` that are neither loaded nor loading yet
+    let load_missing_rows = {
+        let row_key_fn = row_key_fn.clone();
+
+        move |range: Range<usize>| {
+            let compute_average_row_height = compute_average_row_height.clone();
+            let row_height_fn = row_height_fn.clone();
+            let row_key_fn = row_key_fn.clone();
+
+            state.load_missing_rows(range, move |result, missing_range| {
+                let latest_reload_count = reload_count.get_untracked();
+
+                let result = result.map_err(|err| {
+                    on_error.get_value().run(LoadErrorEvent {
+                        range: missing_range.clone(),
+                        error: Rc::clone(&err),
+                    });
+
+                    RowError::new(err)
+                });
+
+                if let (Err(_), Some(retry_policy)) = (&result, retry_policy) {
+                    let attempts = failed_attempts.with_value(|failed_attempts| {
+                        missing_range
+                            .clone()
+                            .filter_map(|index| failed_attempts.get(&index).copied())
+                            .max()
+                            .unwrap_or_default()
+                    }) + 1;
+
+                    if attempts < retry_policy.max_attempts {
+                        failed_attempts.update_value(|failed_attempts| {
+                            for index in missing_range.clone() {
+                                failed_attempts.insert(index, attempts);
+                            }
+                        });
+
+                        // the rows stay loading until they're requested again
+                        set_timeout(
+                            move || {
+                                if reload_count.get_untracked() != latest_reload_count {
+                                    return;
+                                }
+
+                                // rows that have been scrolled away start over once they're displayed again
+                                let display_range = display_range.get_untracked();
+                                failed_attempts.update_value(|failed_attempts| {
+                                    failed_attempts
+                                        .retain(|index, _| display_range.contains(index));
+                                });
+
+                                loaded_rows
+                                    .update(|loaded_rows| loaded_rows.reset_loading(missing_range));
+                                // only the rows that are still displayed are requested
+                                load_trigger.notify();
+                            },
+                            retry_policy.delay(attempts - 1),
+                        );
+
+                        return;
+                    }
 
-        // with this a reload triggers this effect
-        reload_count.track();
+                    // out of attempts, so the rows are rendered as errors
+                    failed_attempts.update_value(|failed_attempts| {
+                        failed_attempts.retain(|index, _| !missing_range.contains(index));
+                    });
+                }
 
-        if visible_row_count == 0 {
-            return;
-        }
+                if let Ok((_, loaded_range)) = &result {
+                    failed_attempts.update_value(|failed_attempts| {
+                        failed_attempts.retain(|index, _| !loaded_range.contains(index));
+                    });
+                }
+                if let (Some(row_height_fn), Ok((rows, range))) = (&row_height_fn, &result) {
+                    row_heights.update(|row_heights| {
+                        for (row, index) in rows.iter().zip(range.clone()) {
+                            if let Some(height) = row_height_fn.run(row, index) {
+                                row_heights.insert(index, height);
+                            }
+                        }
+                    });
+                }
 
-        let mut start = first_visible_row_index.saturating_sub(visible_row_count * 2);
+                if let (Some(row_key_fn), Ok((rows, range))) = (&row_key_fn, &result) {
+                    if pending_selection_keys.with_value(|keys| !keys.is_empty()) {
+                        for (row, index) in rows.iter().zip(range.clone()) {
+                            let key = row_key_fn.run(row);
+                            if pending_selection_keys
+                                .try_update_value(|keys| keys.remove(&key))
+                                .unwrap_or_default()
+                            {
+                                selection.select(index);
+                            }
+                        }
+                    }
+                }
 
-        let mut end = start + visible_row_count * 5;
+                loaded_rows.update(|loaded_rows| loaded_rows.write_loaded(result, missing_range));
 
-        if let Some(chunk_size) = DataP::CHUNK_SIZE {
-            start /= chunk_size;
-            start *= chunk_size;
+                if !first_data_loaded.get_untracked() {
+                    set_first_data_loaded.set(true);
+                }
 
-            end /= chunk_size;
-            end += 1;
-            end *= chunk_size;
+                compute_average_row_height();
+            });
         }
+    };
 
-        if let Some(row_count) = row_count.get() {
-            end = end.min(row_count);
-        }
+    // the display strategy and the first visible row when the effect below ran the last time
+    let last_display_state = store_value(None::<(Discriminant<DisplayStrategy>, usize)>);
+
+    create_effect({
+        let load_missing_rows = load_missing_rows.clone();
 
-        end = end.min(start + MAX_DISPLAY_ROW_COUNT);
+        move |_| {
+            let display_strategy = display_strategy.get();
+            let first_visible_row_index = first_visible_row_index.get();
+            let visible_row_count = visible_row_count.get();
 
-        loaded_rows.update_untracked(|loaded_rows| {
-            if end > loaded_rows.len() {
-                loaded_rows.resize(end);
+            match last_display_state.get_value() {
+                Some((last_strategy, last_first_visible_row_index))
+                    if last_strategy != discriminant(&display_strategy) =>
+                {
+                    // the display strategy has been switched at runtime
+                    switch_display_strategy(last_first_visible_row_index);
+                    last_display_state.set_value(Some((
+                        discriminant(&display_strategy),
+                        last_first_visible_row_index,
+                    )));
+                }
+                _ => last_display_state.set_value(Some((
+                    discriminant(&display_strategy),
+                    first_visible_row_index,
+                ))),
             }
-        });
 
-        let range = start..end;
+            // with this a reload triggers this effect
+            reload_count.track();
+            // and this is notified when failed rows are retried
+            load_trigger.track();
 
-        set_display_range.set(match display_strategy {
-            DisplayStrategy::Virtualization | DisplayStrategy::InfiniteScroll => range.clone(),
-            DisplayStrategy::Pagination { row_count, .. } => {
-                first_visible_row_index..first_visible_row_index + row_count
+            if visible_row_count == 0 {
+                return;
             }
-        });
 
-        let missing_range =
-            loaded_rows.with_untracked(|loaded_rows| loaded_rows.missing_range(range.clone()));
+            let range = match display_strategy {
+                DisplayStrategy::LoadMore { .. } => {
+                    compute_load_more_range(visible_row_count, DataP::CHUNK_SIZE, row_count.get())
+                }
+                _ => compute_display_range(
+                    first_visible_row_index,
+                    visible_row_count,
+                    overscan,
+                    DataP::CHUNK_SIZE,
+                    row_count.get(),
+                ),
+            };
+
+            loaded_rows.update_untracked(|loaded_rows| {
+                if range.end > loaded_rows.len() {
+                    loaded_rows.resize(range.end);
+                }
 
-        if let Some(missing_range) = missing_range {
-            let mut end = missing_range.end;
-            if let Some(row_count) = row_count.get() {
-                end = end.min(row_count);
+                if !cache {
+                    loaded_rows.evict_outside(range.clone());
+                }
+            });
 
-                if end <= missing_range.start {
-                    return;
+            set_display_range.set(match display_strategy {
+                DisplayStrategy::Virtualization | DisplayStrategy::InfiniteScroll => range.clone(),
+                DisplayStrategy::Pagination { .. } => {
+                    first_visible_row_index..first_visible_row_index + visible_row_count
                 }
+                DisplayStrategy::LoadMore { .. } => 0..visible_row_count.min(range.end),
+            });
+
+            load_missing_rows(range);
+        }
+    });
+
+    // triggered when `TableController::prefetch()` is called
+    create_effect(move |_| {
+        table_controller.prefetch_requests.track();
+        let ranges = table_controller
+            .prefetch_requests
+            .try_update_untracked(std::mem::take)
+            .unwrap_or_default();
+
+        for range in ranges {
+            // chunks are only loaded as a whole
+            let range = match DataP::CHUNK_SIZE {
+                Some(chunk_size) => {
+                    range.start / chunk_size * chunk_size
+                        ..range.end.div_ceil(chunk_size) * chunk_size
+                }
+                None => {
+                    range.start
+                        ..range
+                            .end
+                            .min(row_count.get_untracked().unwrap_or(usize::MAX))
+                }
+            };
+            if range.is_empty() {
+                continue;
             }
 
-            loaded_rows.update(|loaded_rows| loaded_rows.write_loading(missing_range.clone()));
+            loaded_rows.update_untracked(|loaded_rows| {
+                if range.end > loaded_rows.len() {
+                    loaded_rows.resize(range.end);
+                }
+            });
+
+            load_missing_rows(range);
+        }
+    });
 
-            let mut loading_ranges = vec![];
-            if let Some(chunk_size) = DataP::CHUNK_SIZE {
-                let start = missing_range.start / chunk_size * chunk_size;
-                let mut current_range = start..start + chunk_size;
-                while current_range.end <= missing_range.end {
-                    loading_ranges.push(current_range.clone());
-                    current_range = current_range.end..current_range.end + chunk_size;
+    let thead_content = {
+        let column_order = use_column_order(Row::column_count());
+        let sticky_columns = use_context::<StickyColumnsContext>().unwrap();
+
+        (move || {
+            let cells =
+                untrack(|| Row::render_head_row(sorting.into(), on_head_click.clone()).into_view());
+            let cells = flatten_cells(cells, Row::column_count());
+            let cells = sticky_columns.stick_cells(cells, true);
+            let cells = column_order
+                .with(|column_order| reorder_cells(cells, column_order, Row::column_count()));
+
+            let selection_cell = selection_column.map(|selection_column| {
+                let class = class_provider.selection_cell(true);
+
+                match selection {
+                    Selection::Multiple(_) => DefaultSelectAllHeaderCellRenderer(
+                        selection_column.select_all_state,
+                        selection_column.toggle_all,
+                        class,
+                    )
+                    .into_view(),
+                    _ => view! { <th class=class role="columnheader"></th> }.into_view(),
                 }
-            } else {
-                loading_ranges.push(missing_range);
+            });
+            let expand_cell = expansion_column.map(|_| {
+                view! { <th class=class_provider.thead_cell_unsortable("") role="columnheader"></th> }
+            });
+            let drag_handle_cell = row_drag_handle_column.map(|_| {
+                view! { <th class=class_provider.row_drag_handle_cell(true) role="columnheader"></th> }
+            });
+
+            view! {
+                {drag_handle_cell}
+                {selection_cell}
+                {expand_cell}
+                {cells}
             }
+            .into_view()
+        })
+        .into_view()
+    };
 
-            // TODO : implement max concurrent requests
-            for missing_range in loading_ranges {
-                let compute_average_row_height = compute_average_row_height.clone();
-                spawn_local({
-                    let rows = Rc::clone(&rows);
-                    let set_known_row_count = set_known_row_count.clone();
+    let group_row = {
+        let thead_row_renderer = thead_row_renderer.clone();
 
-                    async move {
-                        let latest_reload_count = reload_count.get_untracked();
+        move || {
+            column_groups.with(|groups| {
+                if groups.is_empty() {
+                    return None;
+                }
 
-                        let result = rows
-                            .borrow()
-                            .get_rows(missing_range.clone())
-                            .await
-                            .map_err(|err| format!("{err:?}"));
+                let order = column_order_context.get(Row::column_count());
+
+                let cells = group_header_spans(groups, &order)
+                    .into_iter()
+                    .map(|(group, colspan)| match group {
+                        Some(group) => column_group_header_cell_renderer
+                            .run(
+                                groups[group].title.clone(),
+                                colspan,
+                                class_provider.thead_group_cell(),
+                            )
+                            .into_view(),
+                        None => view! { <th colspan=colspan role="columnheader"></th> }.into_view(),
+                    })
+                    .collect_view();
 
-                        // make sure the loaded data is still valid
-                        if reload_count.get_untracked() != latest_reload_count {
-                            return;
-                        }
+                let content = view! {
+                    {row_drag_handle_column.map(|_| view! { <th role="columnheader"></th> })}
+                    {selection_column.map(|_| view! { <th role="columnheader"></th> })}
+                    {expansion_column.map(|_| view! { <th role="columnheader"></th> })}
+                    {cells}
+                };
 
-                        if let Ok((_, loaded_range)) = &result {
-                            if loaded_range.end < missing_range.end {
-                                if let Some(row_count) = row_count.get_untracked() {
-                                    if loaded_range.end < row_count {
-                                        set_known_row_count(loaded_range.end);
-                                    }
-                                } else {
-                                    set_known_row_count(loaded_range.end);
-                                }
-                            }
-                        }
-                        loaded_rows
-                            .update(|loaded_rows| loaded_rows.write_loaded(result, missing_range));
+                Some(thead_row_renderer.run(content.into_view(), thead_row_class))
+            })
+        }
+    };
 
-                        compute_average_row_height();
-                    }
-                });
+    let filter_row = filterable.then(|| {
+        filter_row_renderer.run(
+            filters,
+            Row::column_count(),
+            filter_row_class,
+            Callback::new(move |col_index: usize| class_provider.filter_cell(col_index)),
+            class_provider.filter_input(),
+        )
+    });
+
+    let show_loading_overlay = Signal::derive(move || !first_data_loaded.get() || loading.get());
+
+    create_effect(move |_| {
+        let initial_loading = !first_data_loaded.get();
+        let rows_loading = loaded_rows.with(|loaded_rows| {
+            loaded_rows[0..loaded_rows.len()]
+                .iter()
+                .any(|row| matches!(row, RowState::Loading))
+        });
+        let is_loading = initial_loading || rows_loading || loading.get();
+
+        if table_controller.initial_loading.get_untracked() != initial_loading {
+            table_controller.initial_loading.set(initial_loading);
+        }
+        if table_controller.loading.get_untracked() != is_loading {
+            table_controller.loading.set(is_loading);
+        }
+    });
+
+    let loading_overlay = loading_overlay_renderer.map(|loading_overlay_renderer| {
+        // the overlay is positioned relative to the body
+        create_effect(move |_| {
+            if let Some(tbody) = tbody_ref.get() {
+                let tbody: &web_sys::HtmlElement = &tbody;
+                let style = tbody.style();
+                if show_loading_overlay.get() {
+                    _ = style.set_property("position", "relative");
+                } else {
+                    _ = style.remove_property("position");
+                }
             }
+        });
+
+        move || {
+            show_loading_overlay.get().then(|| {
+                let overlay =
+                    loading_overlay_renderer.run(class_provider.loading_overlay(), col_count());
+
+                match overlay.into_html_element() {
+                    Ok(overlay) => overlay.attr(LOADING_OVERLAY_ATTRIBUTE, "").into_view(),
+                    Err(overlay) => overlay,
+                }
+            })
         }
     });
 
-    let thead_content = Row::render_head_row(sorting.into(), on_head_click).into_view();
+    let empty_row = empty_row_renderer.map(|empty_row_renderer| {
+        let is_empty = create_memo(move |_| first_data_loaded.get() && row_count.get() == Some(0));
+
+        move || {
+            is_empty.get().then(|| {
+                let empty_row = empty_row_renderer.run(class_provider.empty_row(), col_count());
+
+                match empty_row.into_html_element() {
+                    Ok(empty_row) => empty_row.attr(EMPTY_ROW_ATTRIBUTE, "").into_view(),
+                    Err(empty_row) => empty_row,
+                }
+            })
+        }
+    });
 
     let tbody_content = {
         let row_renderer = row_renderer.clone();
         let loading_row_renderer = loading_row_renderer.clone();
         let error_row_renderer = error_row_renderer.clone();
-        let on_selection_change = on_selection_change.clone();
+        let expanded_row_renderer = expanded_row_renderer.clone();
+        let select_row = select_row.clone();
 
         view! {
             {row_placeholder_renderer.run(placeholder_height_before.into())}
@@ -515,11 +2802,25 @@ where
                     })
                 }
 
-                key=|(idx, row)| {
-                    match row {
-                        RowState::Loaded(_) => idx.to_string(),
-                        RowState::Error(_) => format!("error-{idx}"),
-                        RowState::Loading | RowState::Placeholder => format!("loading-{idx}"),
+                key={
+                    let row_key_fn = row_key_fn.clone();
+
+                    move |(idx, row)| {
+                        match row {
+                            RowState::Loaded(row) => {
+                                let key = match &row_key_fn {
+                                    Some(row_key_fn) => format!("key-{}", row_key_fn.run(row)),
+                                    None => idx.to_string(),
+                                };
+
+                                match row_versions.with_value(|row_versions| row_versions.get(idx).copied()) {
+                                    Some(version) => format!("{key}-{version}"),
+                                    None => key,
+                                }
+                            }
+                            RowState::Error(_) => format!("error-{idx}"),
+                            RowState::Loading | RowState::Placeholder => format!("loading-{idx}"),
+                        }
                     }
                 }
 
@@ -527,48 +2828,113 @@ where
                     let row_renderer = row_renderer.clone();
                     let loading_row_renderer = loading_row_renderer.clone();
                     let error_row_renderer = error_row_renderer.clone();
-                    let on_selection_change = on_selection_change.clone();
+                    let expanded_row_renderer = expanded_row_renderer.clone();
+                    let select_row = select_row.clone();
 
                     move |(i, row)| {
                         match row {
                             RowState::Loaded(row) => {
+                                provide_context(RenderedRowIndex(i));
+
                                 let selected_signal = Signal::derive(
                                     move || selected_indices.get().contains(&i)
                                 );
 
-                                let class_signal = Signal::derive(move || {
-                                    class_provider
-                                        .row(
-                                            i,
-                                            selected_signal.get(),
-                                            &row_class.get(),
-                                        )
-                                });
+                                // kept up to date on edits so the classes of `row_class_fn` are recomputed
+                                let row_data = create_rw_signal(row.clone());
+
+                                let class_signal = {
+                                    let row_class_fn = row_class_fn.clone();
+
+                                    Signal::derive(move || {
+                                        let data_class = row_class_fn.as_ref().map(|row_class_fn| {
+                                            row_data.with(|row| row_class_fn.run(row, i))
+                                        });
+
+                                        let class = row_classes(i, selected_signal.get(), data_class.as_deref().unwrap_or_default());
+
+                                        if focused_row.get() == Some(i) {
+                                            format!("{class} {}", class_provider.row_focused(i))
+                                        } else {
+                                            class
+                                        }
+                                    })
+                                };
+
+                                let on_row_change = {
+                                    let on_cell_edit = on_cell_edit.clone();
+
+                                    EventHandler::from(move |evt: ChangeEvent<Row>| {
+                                        // captured before the row is updated
+                                        let previous_row = row_data.get_untracked();
+                                        row_data.set(evt.changed_row.clone());
+
+                                        on_cell_edit.run(CellEditEvent {
+                                            row_index: evt.row_index,
+                                            col_index: evt.col_index,
+                                            previous_row,
+                                            changed_row: evt.changed_row.clone(),
+                                        });
+                                        emit_change(evt);
+                                    })
+                                };
 
                                 let on_select = {
-                                    let on_selection_change = on_selection_change.clone();
+                                    let select_row = select_row.clone();
+                                    let on_row_click = on_row_click.clone();
                                     let row = row.clone();
 
                                     move |evt: web_sys::MouseEvent| {
-                                        update_selection(evt, selection, first_selected_index, i);
+                                        if is_interactive_event_target(&evt) {
+                                            return;
+                                        }
 
-                                        let selection_change_event = SelectionChangeEvent {
+                                        on_row_click.run(RowClickEvent {
+                                            row_index: i,
                                             row: row.clone(),
-                                            row_index:i,
-                                            selected: selected_signal.get_untracked(),
-                                        };
-                                        on_selection_change.run(selection_change_event);
+                                            mouse_event: evt.clone(),
+                                        });
+
+                                        if select_on_row_click {
+                                            select_row(i, row.clone(), get_keyboard_modifiers(&evt));
+                                        }
                                     }
                                 };
 
-                                row_renderer.run(class_signal, row, i, selected_signal, on_select.into(), on_change.get_value())
+                                let row_view = row_renderer.run(class_signal, row.clone(), i, selected_signal, on_select.into(), on_row_change);
+
+                                match expanded_row_renderer.clone() {
+                                    Some(expanded_row_renderer) => {
+                                        let is_expanded = create_memo(move |_| expanded.with(|expanded| expanded.contains(&i)));
+
+                                        let expanded_row = move || is_expanded.get().then(|| {
+                                            let content = expanded_row_renderer.run(row.clone(), i);
+
+                                            view! {
+                                                <tr class=class_provider.expanded_row(i) role="row">
+                                                    <td colspan=col_count() role="cell">{content}</td>
+                                                </tr>
+                                            }
+                                            .attr(EXPANDED_ROW_ATTRIBUTE, "")
+                                        });
+
+                                        view! {
+                                            {row_view}
+                                            {expanded_row}
+                                        }
+                                        .into_view()
+                                    }
+                                    None => row_view,
+                                }
+                            }
+                            RowState::Error(err) => {
+                                provide_context(RowErrorContext(err.error));
+
+                                error_row_renderer.run(err.message, i, col_count())
                             }
-                            RowState::Error(err) => error_row_renderer.run(err, i, Row::COLUMN_COUNT),
                             RowState::Loading | RowState::Placeholder => {
                                 loading_row_renderer.run(
-                                    Signal::derive(
-                                        move || class_provider.row(i, false, &row_class.get())
-                                    ),
+                                    Signal::derive(move || row_classes(i, false, "")),
                                     Callback::new(
                                         move |col_index: usize| class_provider.loading_cell(i, col_index, &loading_cell_class.get())
                                     ),
@@ -576,32 +2942,127 @@ where
                                         move |col_index: usize| class_provider.loading_cell_inner(i, col_index, &loading_cell_inner_class.get())
                                     ),
                                     i,
-                                    Row::COLUMN_COUNT,
+                                    col_count(),
                                 )
                             }
                         }
                     }
                 }
             />
-            {row_placeholder_renderer.run(placeholder_height_after.into())}
+            {move || if show_load_more_row.get() {
+                // takes the place of the "after" placeholder which has no height with `LoadMore`
+                load_more_row_renderer.run(load_more, col_count())
+            } else {
+                row_placeholder_renderer.run(placeholder_height_after)
+            }}
+            {loading_overlay}
+            {empty_row}
+        }
+    };
+
+    let tbody_content = if let Some(suspense_fallback) = suspense_fallback {
+        view! {
+            <Show
+                when=move || first_data_loaded.get()
+                fallback=move || suspense_fallback.run(col_count())
+            >
+                {tbody_content.clone()}
+            </Show>
         }
+        .into()
+    } else {
+        tbody_content
     };
 
     let tbody = tbody_renderer.run(tbody_content, tbody_class, tbody_ref);
 
+    let tfoot = aggregates.map(|aggregates| {
+        create_effect(move |_| {
+            loaded_rows.with(|loaded_rows| aggregates.compute(loaded_rows));
+        });
+
+        let cells = move || {
+            column_order_context
+                .get(Row::column_count())
+                .into_iter()
+                .map(|col_index| {
+                    let text = aggregates
+                        .has_aggregate(col_index)
+                        .then(|| aggregates.text(col_index));
+
+                    view! {
+                        <td class=class_provider.tfoot_cell(col_index) role="cell">
+                            {text}
+                        </td>
+                    }
+                })
+                .collect_view()
+        };
+
+        let content = view! {
+            <tr role="row">
+                {row_drag_handle_column.map(|_| view! { <td role="cell"></td> })}
+                {selection_column.map(|_| view! { <td role="cell"></td> })}
+                {expansion_column.map(|_| view! { <td role="cell"></td> })}
+                {cells}
+            </tr>
+        };
+
+        tfoot_renderer.run(content.into_view(), tfoot_class)
+    });
+
+    let caption = (caption.is_some() || description.is_some()).then(|| {
+        let caption_ref = create_node_ref::<html::Caption>();
+
+        let description = description.map(|description| {
+            let id = format!("table-description-{}", next_description_id());
+
+            caption_ref.on_load({
+                let id = id.clone();
+
+                move |caption| {
+                    if let Some(table) = caption.parent_element() {
+                        let _ = table.set_attribute("aria-describedby", &id);
+                    }
+                }
+            });
+
+            view! {
+                <span id=id class=class_provider.caption_description()>
+                    {description.run()}
+                </span>
+            }
+        });
+
+        view! {
+            <caption node_ref=caption_ref class=class_provider.caption()>
+                {caption.map(|caption| caption.run())}
+                {description}
+            </caption>
+        }
+    });
+
     view! {
+        {caption}
+        {colgroup}
         {thead_renderer.run(
-            thead_row_renderer.run(
-                thead_content,
-                thead_row_class,
-            ).into_view(),
+            view! {
+                {group_row}
+                {thead_row_renderer.run(
+                    thead_content,
+                    thead_row_class,
+                )}
+                {filter_row}
+            }.into_view(),
             thead_class,
         )}
 
         {tbody}
+        {tfoot}
     }
 }
 
+#[allow(clippy::too_many_arguments)]
 fn compute_average_row_height_from_loaded<Row, ClsP>(
     tbody_ref: NodeRef<AnyElement>,
     display_range: ReadSignal<Range<usize>>,
@@ -610,6 +3071,8 @@ fn compute_average_row_height_from_loaded<Row, ClsP>(
     set_average_row_height: WriteSignal<f64>,
     placeholder_height_before: Signal<f64>,
     loaded_rows: RwSignal<LoadedRows<Row>>,
+    row_heights: RwSignal<BTreeMap<usize, f64>>,
+    measure_row_heights: bool,
 ) where
     Row: TableRow<ClassesProvider = ClsP> + Clone + 'static,
 {
@@ -617,51 +3080,73 @@ fn compute_average_row_height_from_loaded<Row, ClsP>(
         let el: &web_sys::Element = &el;
         let display_range = display_range.get_untracked();
         if display_range.end > 0 {
-            let avg_row_height = loaded_rows.with_untracked(|loaded_rows| {
-                let mut loading_row_start_index = None;
-                let mut loading_row_end_index = None;
-
-                for i in display_range.clone() {
-                    if matches!(loaded_rows[i], RowState::Loaded(_) | RowState::Loading) {
-                        if loading_row_start_index.is_none() {
-                            loading_row_start_index = Some(i);
-                        }
-                        loading_row_end_index = Some(i);
-                    } else {
-                        if loading_row_end_index.is_some() {
-                            break;
+            let avg_row_height = if measure_row_heights {
+                measure_rendered_row_heights(el, &display_range, loaded_rows, row_heights)
+            } else {
+                loaded_rows.with_untracked(|loaded_rows| {
+                    let mut loading_row_start_index = None;
+                    let mut loading_row_end_index = None;
+
+                    for i in display_range.clone() {
+                        if matches!(loaded_rows[i], RowState::Loaded(_) | RowState::Loading) {
+                            if loading_row_start_index.is_none() {
+                                loading_row_start_index = Some(i);
+                            }
+                            loading_row_end_index = Some(i);
+                        } else {
+                            if loading_row_end_index.is_some() {
+                                break;
+                            }
                         }
                     }
-                }
 
-                if let (Some(loading_row_start_index), Some(loading_row_end_index)) =
-                    (loading_row_start_index, loading_row_end_index)
-                {
-                    if loading_row_end_index == loading_row_start_index {
-                        return None;
-                    }
+                    if let (Some(loading_row_start_index), Some(loading_row_end_index)) =
+                        (loading_row_start_index, loading_row_end_index)
+                    {
+                        if loading_row_end_index == loading_row_start_index {
+                            return None;
+                        }
+
+                        let row_bounds = rendered_row_bounds(el);
+
+                        let first_loading_row =
+                            row_bounds.get(loading_row_start_index - display_range.start);
+                        let last_loading_row =
+                            row_bounds.get(loading_row_end_index - display_range.start);
+
+                        if let (Some(first_loading_row), Some(last_loaded_row)) =
+                            (first_loading_row, last_loading_row)
+                        {
+                            let mut span = last_loaded_row.top - first_loading_row.top;
+                            let mut span_row_count =
+                                loading_row_end_index - loading_row_start_index;
+
+                            // rows with a known height are replaced by their measured height
+                            // and don't count towards the average
+                            row_heights.update(|row_heights| {
+                                for (index, height) in row_heights
+                                    .range_mut(loading_row_start_index..loading_row_end_index)
+                                {
+                                    if let Some(row) = row_bounds.get(index - display_range.start) {
+                                        *height = row.height();
+                                    }
 
-                    let children = el.children();
+                                    span -= *height;
+                                    span_row_count -= 1;
+                                }
+                            });
 
-                    // skip first element, because it's the "before" placeholder
-                    let first_loading_row = children
-                        .get_with_index((loading_row_start_index + 1 - display_range.start) as u32);
-                    let last_loading_row = children
-                        .get_with_index((loading_row_end_index + 1 - display_range.start) as u32);
+                            if span_row_count == 0 {
+                                return None;
+                            }
 
-                    if let (Some(first_loading_row), Some(last_loaded_row)) =
-                        (first_loading_row, last_loading_row)
-                    {
-                        return Some(
-                            (last_loaded_row.get_bounding_client_rect().top()
-                                - first_loading_row.get_bounding_client_rect().top())
-                                / (loading_row_end_index - loading_row_start_index) as f64,
-                        );
+                            return Some(span / span_row_count as f64);
+                        }
                     }
-                }
 
-                None
-            });
+                    None
+                })
+            };
 
             if let Some(avg_row_height) = avg_row_height {
                 let prev_placeholder_height_before = placeholder_height_before.get_untracked();
@@ -678,29 +3163,148 @@ fn compute_average_row_height_from_loaded<Row, ClsP>(
     }
 }
 
-fn update_sorting_from_event(sorting: &mut VecDeque<(usize, ColumnSort)>, event: TableHeadEvent) {
-    let (i, (_, mut sort)) = sorting
+/// Records the heights of all loaded rows that are currently rendered and returns the average height
+/// of all known rows.
+fn measure_rendered_row_heights<Row>(
+    tbody: &web_sys::Element,
+    display_range: &Range<usize>,
+    loaded_rows: RwSignal<LoadedRows<Row>>,
+    row_heights: RwSignal<BTreeMap<usize, f64>>,
+) -> Option<f64>
+where
+    Row: Clone + 'static,
+{
+    let row_bounds = rendered_row_bounds(tbody);
+
+    let measured = loaded_rows.with_untracked(|loaded_rows| {
+        display_range
+            .clone()
+            .filter(|index| matches!(loaded_rows[*index], RowState::Loaded(_)))
+            .filter_map(|index| {
+                row_bounds
+                    .get(index - display_range.start)
+                    .map(|row| (index, row.height()))
+            })
+            .collect::<Vec<_>>()
+    });
+
+    // only update on actual changes because every update moves the placeholders which triggers
+    // another measurement
+    let changed = row_heights.with_untracked(|row_heights| {
+        measured.iter().any(|(index, height)| {
+            row_heights
+                .get(index)
+                .is_none_or(|known| (known - height).abs() > 0.5)
+        })
+    });
+
+    if changed {
+        row_heights.update(|row_heights| row_heights.extend(measured));
+    }
+
+    row_heights.with_untracked(|row_heights| {
+        if row_heights.is_empty() {
+            None
+        } else {
+            Some(row_heights.values().sum::<f64>() / row_heights.len() as f64)
+        }
+    })
+}
+
+/// The vertical extent of a rendered row in viewport coordinates.
+struct RowBounds {
+    top: f64,
+    bottom: f64,
+}
+
+impl RowBounds {
+    fn height(&self) -> f64 {
+        self.bottom - self.top
+    }
+}
+
+/// The bounds of the rendered rows between the "before" and "after" placeholders in order. The
+/// detail row of an expanded row counts towards the bounds of its row.
+fn rendered_row_bounds(tbody: &web_sys::Element) -> Vec<RowBounds> {
+    let children = body_children(tbody);
+    let mut row_bounds = Vec::<RowBounds>::new();
+
+    for child in children
         .iter()
-        .enumerate()
-        .find(|(_, (col_index, _))| col_index == &event.index)
-        .unwrap_or((0, &(event.index, ColumnSort::None)));
-
-    if i == 0 || sort == ColumnSort::None {
-        sort = match sort {
-            ColumnSort::None => ColumnSort::Ascending,
-            ColumnSort::Ascending => ColumnSort::Descending,
-            ColumnSort::Descending => ColumnSort::None,
-        };
+        .take(children.len().saturating_sub(1))
+        .skip(1)
+    {
+        let rect = child.get_bounding_client_rect();
+
+        match row_bounds.last_mut() {
+            Some(row) if child.has_attribute(EXPANDED_ROW_ATTRIBUTE) => row.bottom = rect.bottom(),
+            _ => row_bounds.push(RowBounds {
+                top: rect.top(),
+                bottom: rect.bottom(),
+            }),
+        }
+    }
+
+    row_bounds
+}
+
+/// The children of the body without the loading overlay and the empty row, i.e. the placeholders
+/// and the rows.
+fn body_children(tbody: &web_sys::Element) -> Vec<web_sys::Element> {
+    let children = tbody.children();
+
+    (0..children.length())
+        .filter_map(|child_index| children.get_with_index(child_index))
+        .filter(|child| {
+            !child.has_attribute(LOADING_OVERLAY_ATTRIBUTE)
+                && !child.has_attribute(EMPTY_ROW_ATTRIBUTE)
+        })
+        .collect()
+}
+
+fn row_index_at_offset(
+    tbody_ref: NodeRef<AnyElement>,
+    display_range: Range<usize>,
+    average_row_height: f64,
+    row_count: Option<usize>,
+    y: f64,
+) -> Option<usize> {
+    let tbody = tbody_ref.get_untracked()?;
+
+    let tbody_rect = tbody.get_bounding_client_rect();
+    if y < tbody_rect.top() || y >= tbody_rect.bottom() {
+        return None;
+    }
+
+    let children = body_children(&tbody);
+    if children.len() < 2 {
+        return None;
+    }
+
+    // the first and the last child are the "before" and "after" placeholders
+    let placeholder_before_rect = children.first()?.get_bounding_client_rect();
+    if y < placeholder_before_rect.bottom() {
+        let rows_above =
+            ((placeholder_before_rect.bottom() - y) / average_row_height).ceil() as usize;
+        return Some(display_range.start.saturating_sub(rows_above.max(1)));
+    }
+
+    let row_bounds = rendered_row_bounds(&tbody);
+
+    for (i, row) in row_bounds.iter().enumerate() {
+        if y >= row.top && y < row.bottom {
+            return Some(display_range.start + i);
+        }
     }
 
-    *sorting = sorting
-        .clone()
-        .into_iter()
-        .filter(|(col_index, sort)| *col_index != event.index && *sort != ColumnSort::None)
-        .collect();
+    let placeholder_after_rect = children.last()?.get_bounding_client_rect();
+    let rows_below =
+        ((y - placeholder_after_rect.top()).max(0.0) / average_row_height).floor() as usize;
+    let row_index = display_range.start + row_bounds.len() + rows_below;
 
-    if sort != ColumnSort::None {
-        sorting.push_front((event.index, sort));
+    match row_count {
+        Some(row_count) if row_index >= row_count => None,
+        _ => Some(row_index),
     }
 }
 
@@ -710,53 +3314,129 @@ fn get_keyboard_modifiers(evt: &web_sys::MouseEvent) -> (bool, bool) {
     (meta_pressed, shift_pressed)
 }
 
+/// Groups the `indices` of the rows to reload into the ranges that are fetched. With a `chunk_size`
+/// these are the chunks that contain the rows, otherwise the runs of consecutive rows.
+fn reload_ranges(indices: &BTreeSet<usize>, chunk_size: Option<usize>) -> Vec<Range<usize>> {
+    let mut ranges: Vec<Range<usize>> = vec![];
+
+    for &index in indices {
+        match (ranges.last_mut(), chunk_size) {
+            (Some(last), Some(_)) if last.contains(&index) => {}
+            (Some(last), None) if last.end == index => last.end += 1,
+            (_, Some(chunk_size)) => {
+                let start = index / chunk_size * chunk_size;
+                ranges.push(start..start + chunk_size);
+            }
+            (_, None) => ranges.push(index..index + 1),
+        }
+    }
+
+    ranges
+}
+
+/// Returns a new number for the id of a table description.
+fn next_description_id() -> usize {
+    static DESCRIPTION_COUNT: AtomicUsize = AtomicUsize::new(0);
+
+    DESCRIPTION_COUNT.fetch_add(1, Ordering::Relaxed)
+}
+
+/// Creates a click event with the modifier keys of `evt` for `on_row_click` when a row is activated
+/// with the keyboard.
+fn click_event_from_keyboard_event(evt: &web_sys::KeyboardEvent) -> web_sys::MouseEvent {
+    let mut init = web_sys::MouseEventInit::new();
+    init.ctrl_key(evt.ctrl_key())
+        .meta_key(evt.meta_key())
+        .shift_key(evt.shift_key())
+        .alt_key(evt.alt_key());
+
+    web_sys::MouseEvent::new_with_mouse_event_init_dict("click", &init)
+        .expect("creating a mouse event should not fail")
+}
+
+/// Returns `true` if the event originated from an interactive element (like an `<input>` or a `<button>`)
+/// inside of the element the handler is attached to.
+pub(crate) fn is_interactive_event_target(evt: &web_sys::Event) -> bool {
+    let (Some(target), Some(current_target)) = (evt.target(), evt.current_target()) else {
+        return false;
+    };
+
+    let (Ok(target), Ok(current_target)) = (
+        target.dyn_into::<web_sys::Element>(),
+        current_target.dyn_into::<web_sys::Node>(),
+    ) else {
+        return false;
+    };
+
+    match target.closest(INTERACTIVE_ELEMENTS_SELECTOR) {
+        Ok(Some(interactive_element)) => current_target.contains(Some(&interactive_element)),
+        _ => false,
+    }
+}
+
 fn update_selection(
-    evt: web_sys::MouseEvent,
+    (meta_pressed, shift_pressed): (bool, bool),
     selection: Selection,
     first_selected_index: RwSignal<Option<usize>>,
+    selection_order: StoredValue<Vec<usize>>,
+    selection_limit: Option<(usize, SelectionLimitPolicy)>,
+    is_selectable: impl Fn(usize) -> bool,
     i: usize,
-) {
+) -> bool {
     match selection {
-        Selection::None => {}
+        Selection::None => false,
         Selection::Single(selected_index) => {
             selected_index.set(Some(i));
+            false
         }
         Selection::Multiple(selected_indices) => {
+            let mut rejected = false;
+
             selected_indices.update(|selected_indices| {
-                let (meta_pressed, shift_pressed) = get_keyboard_modifiers(&evt);
+                let mut added = vec![];
 
-                if meta_pressed {
-                    if selected_indices.contains(&i) {
-                        selected_indices.remove(&i);
-                    } else {
-                        selected_indices.insert(i);
+                // the last clicked row is the anchor of the range that is selected by shift-click
+                let anchor = first_selected_index.get_untracked();
+
+                if let (true, Some(anchor)) = (shift_pressed, anchor) {
+                    if !meta_pressed {
+                        selected_indices.clear();
                     }
-                    match selected_indices.len() {
-                        0 => first_selected_index.set(None),
-                        1 => {
-                            first_selected_index.set(Some(i));
-                        }
-                        _ => {
-                            // do nothing
+
+                    // rows that aren't loaded can't be passed to `on_selection_change`
+                    for i in (anchor.min(i)..=anchor.max(i)).filter(|i| is_selectable(*i)) {
+                        if selected_indices.insert(i) {
+                            added.push(i);
                         }
                     }
-                } else if shift_pressed {
-                    if let Some(first_selected_index) = first_selected_index.get() {
-                        let min = first_selected_index.min(i);
-                        let max = first_selected_index.max(i);
-                        for i in min..=max {
-                            selected_indices.insert(i);
-                        }
+                } else if meta_pressed {
+                    if selected_indices.contains(&i) {
+                        selected_indices.remove(&i);
                     } else {
                         selected_indices.insert(i);
-                        first_selected_index.set(Some(i));
+                        added.push(i);
                     }
+                    first_selected_index.set(Some(i));
                 } else {
                     selected_indices.clear();
                     selected_indices.insert(i);
+                    added.push(i);
                     first_selected_index.set(Some(i));
                 }
+
+                if let Some((max_selection, policy)) = selection_limit {
+                    selection_order.update_value(|order| {
+                        rejected =
+                            limit_selection(selected_indices, order, &added, max_selection, policy);
+                    });
+
+                    if selected_indices.is_empty() {
+                        first_selected_index.set(None);
+                    }
+                }
             });
+
+            rejected
         }
     }
 }
@@ -19,5 +19,5 @@ pub fn DefaultTableBodyRenderer(
     let tbody_ref = create_node_ref::<Tbody>();
     tbody_ref.on_load(move |e| node_ref.load(&e.into_any()));
 
-    view! { <tbody class=class node_ref=tbody_ref>{content}</tbody> }
+    view! { <tbody class=class role="rowgroup" node_ref=tbody_ref>{content}</tbody> }
 }
@@ -0,0 +1,9 @@
+use crate::wrapper_render_fn;
+use leptos::*;
+
+wrapper_render_fn!(
+    /// tfoot
+    DefaultTableFootRenderer,
+    tfoot,
+    "rowgroup",
+);
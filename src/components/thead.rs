@@ -1,17 +1,34 @@
+use crate::column_resize::ColumnResizeHandleClass;
+use crate::expansion::ExpansionColumnContext;
+use crate::flatten::FlattenedTitlePrefix;
+use crate::row_reorder::RowReorderContext;
+use crate::selection::SelectionColumnContext;
+use crate::sticky_columns::StickyColumnsContext;
 use crate::wrapper_render_fn;
-use crate::{ColumnSort, TableHeadEvent};
+use crate::{
+    use_column_order, use_table_direction, ColumnReorderController, ColumnResizeController,
+    ColumnSort, Direction, SelectAllState, TableHeadEvent,
+};
 use leptos::*;
+use std::collections::HashMap;
+use wasm_bindgen::JsCast;
+
+/// Positions the resize handle on the edge of the header cell where the column ends. The side
+/// (`left` or `right`) is appended.
+const RESIZE_HANDLE_STYLE: &str = "position: absolute; top: 0; bottom: 0; width: 6px; cursor: col-resize; user-select: none; touch-action: none;";
 
 wrapper_render_fn!(
     /// thead
     DefaultTableHeadRenderer,
     thead,
+    "rowgroup",
 );
 
 wrapper_render_fn!(
     /// thead row
     DefaultTableHeadRowRenderer,
     tr,
+    "row",
 );
 
 /// The default table header renderer. Renders roughly
@@ -20,6 +37,14 @@ wrapper_render_fn!(
 ///    <span>Title</span>
 /// </th>
 /// ```
+///
+/// If [`TableContent`] has a `column_reorder_controller` the header cell is `draggable` and dropping
+/// another header cell onto it moves that column here.
+///
+/// If [`TableContent`] has a `column_resize_controller` and the column is resizable, a handle is
+/// rendered on the right edge of the header cell (the left edge in right-to-left tables, see
+/// [`Direction`]). Dragging it sets the width of the column and double clicking it resets the width.
+/// Resizing doesn't sort the column.
 #[component]
 pub fn DefaultTableHeaderCellRenderer<F>(
     /// The class attribute for the head element. Generated by the classes provider.
@@ -38,11 +63,102 @@ pub fn DefaultTableHeaderCellRenderer<F>(
     sort_direction: Signal<ColumnSort>,
     /// The event handler for the click event. Has to be called with [`TableHeadEvent`].
     on_click: F,
+    /// Whether the column can be sorted by clicking this header. If `false` no click events are emitted
+    /// and the `aria-sort` attribute is omitted. Defaults to `true`.
+    ///
+    /// Style non-sortable headers through [`TableClassesProvider::thead_cell_unsortable`].
+    #[prop(default = true)]
+    sortable: bool,
     children: Children,
 ) -> impl IntoView
 where
     F: Fn(TableHeadEvent) + 'static,
 {
+    // only provided if columns can be reordered
+    let reorder_controller = use_context::<ColumnReorderController>();
+    // the runtime title replaces the static title of the children
+    let title = use_column_title(index);
+    let children = children().into_view();
+    // only provided for the columns of flattened structs
+    let title_prefix = use_context::<FlattenedTitlePrefix>().map(|prefix| prefix.0);
+    // only provided if columns can be resized
+    let resize_controller = use_context::<ColumnResizeController>()
+        .filter(|resize_controller| resize_controller.is_resizable(index));
+
+    // the resize handle is positioned relative to the cell unless the cell is already sticky
+    let is_sticky = use_context::<StickyColumnsContext>()
+        .is_some_and(|sticky_columns| untrack(|| sticky_columns.is_sticky(index)));
+    let position = if resize_controller.is_some() && !is_sticky {
+        "position: relative;"
+    } else {
+        ""
+    };
+
+    let resize_handle = resize_controller.map(|resize_controller| {
+        let class = use_context::<ColumnResizeHandleClass>()
+            .map(|class| class.0)
+            .unwrap_or_default();
+
+        let direction = use_table_direction();
+        // in right-to-left tables the handle is on the left edge, so moving the pointer to the left
+        // widens the column
+        let pointer_x = move |evt: &web_sys::PointerEvent| match direction.get_untracked() {
+            Direction::Ltr => evt.client_x() as f64,
+            Direction::Rtl => -evt.client_x() as f64,
+        };
+
+        view! {
+            <span
+                class=class
+                style=move || format!("{RESIZE_HANDLE_STYLE} {}: 0;", direction.get().end_side())
+                aria-hidden="true"
+                on:pointerdown=move |evt| {
+                    evt.prevent_default();
+                    evt.stop_propagation();
+
+                    let Some(handle) = evt
+                        .target()
+                        .and_then(|target| target.dyn_into::<web_sys::Element>().ok())
+                    else {
+                        return;
+                    };
+
+                    // keeps sending the pointer events to the handle while the pointer is outside of it
+                    let _ = handle.set_pointer_capture(evt.pointer_id());
+
+                    let width = handle
+                        .parent_element()
+                        .map(|cell| cell.get_bounding_client_rect().width())
+                        .unwrap_or_default();
+
+                    resize_controller.resize_start(index, pointer_x(&evt), width);
+                }
+                on:pointermove=move |evt| resize_controller.resize_to(pointer_x(&evt))
+                on:pointerup=move |_| resize_controller.resize_end()
+                on:pointercancel=move |_| resize_controller.resize_end()
+                // the click at the end of resizing mustn't sort the column
+                on:click=move |evt| evt.stop_propagation()
+                on:dblclick=move |evt| {
+                    evt.stop_propagation();
+                    resize_controller.reset_width(index);
+                }
+            ></span>
+        }
+    });
+
+    // only shown while the table is sorted by several columns
+    let priority_badge = {
+        let class = use_context::<SortPriorityClass>()
+            .map(|class| class.0)
+            .unwrap_or_default();
+
+        move || {
+            sort_priority().map(|priority| {
+                view! { <span class=class.clone()>{priority + 1}</span> }
+            })
+        }
+    };
+
     let style = move || {
         let sort = match sort_direction() {
             ColumnSort::Ascending => "--sort-icon: '▲';",
@@ -50,25 +166,314 @@ where
             ColumnSort::None => "--sort-icon: '';",
         };
 
-        let priority = match sort_priority() {
-            Some(priority) => format!("--sort-priority: '{}';", priority + 1),
-            None => "--sort-priority: '';".to_string(),
-        };
-
-        format!("{} {}", sort, &priority)
+        format!("{} {}", sort, position)
     };
 
     view! {
-        <th class=class
-            on:click=move |mouse_event| on_click(TableHeadEvent {
-                index,
-                mouse_event,
-            })
+        <th class=class role="columnheader"
+            on:click=move |mouse_event| {
+                if sortable {
+                    on_click(TableHeadEvent {
+                        index,
+                        mouse_event,
+                    })
+                }
+            }
             style=style
+            aria-sort=move || sortable.then(|| sort_direction().as_aria_sort())
+            draggable=reorder_controller.map(|_| "true")
+            on:dragstart=move |evt| {
+                if resize_controller.is_some_and(|resize_controller| resize_controller.is_resizing()) {
+                    evt.prevent_default();
+                    return;
+                }
+
+                if let Some(reorder_controller) = reorder_controller {
+                    reorder_controller.drag_start(index);
+
+                    if let Some(data_transfer) = evt.data_transfer() {
+                        // Firefox only starts dragging if there is some data
+                        let _ = data_transfer.set_data("text/plain", &index.to_string());
+                        data_transfer.set_effect_allowed("move");
+                    }
+                }
+            }
+            on:dragover=move |evt| {
+                if reorder_controller.is_some_and(|controller| controller.dragged_column().is_some()) {
+                    // allows dropping
+                    evt.prevent_default();
+                }
+            }
+            on:drop=move |evt| {
+                if let Some(reorder_controller) = reorder_controller {
+                    evt.prevent_default();
+                    reorder_controller.drop_on(index);
+                }
+            }
+            on:dragend=move |_| {
+                if let Some(reorder_controller) = reorder_controller {
+                    reorder_controller.drag_end();
+                }
+            }
         >
             <span class=inner_class>
-                {children()}
+                {title_prefix}
+                {move || title.get().map(IntoView::into_view).unwrap_or_else(|| children.clone())}
+                {priority_badge}
             </span>
+            {resize_handle}
+        </th>
+    }
+}
+
+/// The metadata of the column of a header cell. Returned by [`use_column_header_info`].
+///
+/// New fields may be added in the future, so it can't be constructed outside of this crate.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct ColumnHeaderInfo {
+    /// The index of the column. See [`TableRow::col_name`].
+    pub index: usize,
+    /// The name of the column (= struct field name). The title is rendered by the `children` of the header cell renderer.
+    pub name: &'static str,
+    /// Whether the column can be sorted by clicking its header.
+    pub sortable: bool,
+    /// How the column is currently sorted.
+    pub current_sort: ColumnSort,
+    /// The rank of the column among the sorted columns. `0` is the primary sort column. `None` if the
+    /// column isn't sorted or if it's the only sorted column.
+    pub sort_priority: Option<usize>,
+    /// The runtime title from the `column_titles` prop of [`TableContent`]. `None` if there is none
+    /// for this column, so the `children` of the header cell renderer with the static title are shown.
+    pub title: Option<String>,
+}
+
+/// Provided as context by [`TableContent`] so that header cells can look up the names of their columns.
+#[derive(Copy, Clone)]
+pub(crate) struct ColumnNamesContext(pub Callback<usize, &'static str>);
+
+/// Provided as context by [`TableContent`] with the classes of the sort priority numbers.
+#[derive(Clone)]
+pub(crate) struct SortPriorityClass(pub String);
+
+/// Provided as context by [`TableContent`] with the value of its `column_titles` prop.
+#[derive(Copy, Clone)]
+pub(crate) struct ColumnTitlesContext(pub Signal<Vec<String>>);
+
+/// The runtime title of the column at `index` from the `column_titles` prop of [`TableContent`].
+/// `None` if the entry is missing or empty.
+fn use_column_title(index: usize) -> Signal<Option<String>> {
+    let titles = use_context::<ColumnTitlesContext>();
+
+    Signal::derive(move || {
+        titles?
+            .0
+            .with(|titles| titles.get(index).filter(|title| !title.is_empty()).cloned())
+    })
+}
+
+/// Bundles the props of a header cell renderer with the name of its column into a [`ColumnHeaderInfo`].
+/// Call it in a custom `thead_cell_renderer` (see [`DefaultTableHeaderCellRenderer`] for its props),
+/// e.g. to render the sort rank of multi-column sorting. Pass `true` for `sortable` unless you
+/// know the column can't be sorted.
+///
+/// ```
+/// # use leptos::*;
+/// # use leptos_struct_table::*;
+/// #
+/// #[derive(TableRow, Clone)]
+/// #[table(sortable, impl_vec_data_provider, thead_cell_renderer = "RankedHeaderCellRenderer")]
+/// pub struct Employee {
+///     name: String,
+///     salary: f64,
+/// }
+///
+/// #[component]
+/// fn RankedHeaderCellRenderer<F>(
+///     #[prop(into)] class: Signal<String>,
+///     #[prop(into)] inner_class: String,
+///     index: usize,
+///     #[prop(into)] sort_priority: Signal<Option<usize>>,
+///     #[prop(into)] sort_direction: Signal<ColumnSort>,
+///     on_click: F,
+///     children: Children,
+/// ) -> impl IntoView
+/// where
+///     F: Fn(TableHeadEvent) + 'static,
+/// {
+///     let info = use_column_header_info(index, true, sort_priority, sort_direction);
+///
+///     // e.g. "1↑" for the primary sort column when sorting by several columns
+///     let badge = move || {
+///         let info = info.get();
+///         let arrow = match info.current_sort {
+///             ColumnSort::Ascending => "↑",
+///             ColumnSort::Descending => "↓",
+///             ColumnSort::None => "",
+///         };
+///
+///         match info.sort_priority {
+///             Some(priority) => format!("{}{arrow}", priority + 1),
+///             None => arrow.to_string(),
+///         }
+///     };
+///
+///     view! {
+///         <th class=class on:click=move |mouse_event| on_click(TableHeadEvent { index, mouse_event })>
+///             <span class=inner_class>{children()}</span>
+///             <small>{badge}</small>
+///         </th>
+///     }
+/// }
+/// ```
+pub fn use_column_header_info(
+    index: usize,
+    sortable: bool,
+    sort_priority: Signal<Option<usize>>,
+    sort_direction: Signal<ColumnSort>,
+) -> Signal<ColumnHeaderInfo> {
+    let name = use_context::<ColumnNamesContext>()
+        .map(|names| (names.0)(index))
+        .unwrap_or_default();
+    let title = use_column_title(index);
+
+    Signal::derive(move || ColumnHeaderInfo {
+        index,
+        name,
+        sortable,
+        current_sort: sort_direction.get(),
+        sort_priority: sort_priority.get(),
+        title: title.get(),
+    })
+}
+
+/// The default renderer of the leading head cell with the "select all" checkbox that is shown with
+/// `Selection::Multiple` (see the `selection_checkboxes` prop of [`TableContent`]).
+/// Please note that this is **NOT** a `#[component]`.
+///
+/// # Arguments
+///
+/// * `state` - Whether none, some or all rows are selected
+/// * `on_toggle` - Selects or de-selects all rows
+/// * `class` - The class attribute of the head cell. Generated by the classes provider.
+#[allow(non_snake_case)]
+pub fn DefaultSelectAllHeaderCellRenderer(
+    state: Signal<SelectAllState>,
+    on_toggle: Callback<()>,
+    class: String,
+) -> impl IntoView {
+    view! {
+        <th class=class role="columnheader">
+            <input
+                type="checkbox"
+                aria-label="Select all rows"
+                prop:checked=move || state.get() == SelectAllState::Checked
+                prop:indeterminate=move || state.get() == SelectAllState::Indeterminate
+                on:change=move |_| on_toggle(())
+            />
+        </th>
+    }
+}
+
+/// The default renderer of the header cell of a column group (see the `column_groups` prop of
+/// [`TableContent`]). Please note that this is **NOT** a `#[component]`.
+///
+/// # Arguments
+///
+/// * `title` - The title of the column group
+/// * `colspan` - The number of columns the cell spans
+/// * `class` - The class attribute of the head cell. Generated by the classes provider.
+#[allow(non_snake_case)]
+pub fn DefaultColumnGroupHeaderCellRenderer(
+    title: String,
+    colspan: usize,
+    class: String,
+) -> impl IntoView {
+    view! {
+        <th class=class colspan=colspan scope="colgroup" role="columnheader">
+            {title}
         </th>
     }
 }
+
+/// The default filter row renderer. Renders a row with a search input for every column below the
+/// head row in the column order of the table. Typing into an input sets the filter of that column.
+/// Clearing it removes the filter.
+///
+/// Please note that this is **NOT** a `#[component]`.
+///
+/// # Arguments
+///
+/// * `filters` - The filters of the table by column index
+/// * `col_count` - The number of columns
+/// * `class` - The class attribute of the row. Generated by the classes provider.
+/// * `get_cell_class` - Returns the class attribute of the cell of a column. Generated by the classes provider.
+/// * `input_class` - The class attribute of the inputs. Generated by the classes provider.
+#[allow(non_snake_case)]
+pub fn DefaultTableFilterRowRenderer(
+    filters: RwSignal<HashMap<usize, String>>,
+    col_count: usize,
+    class: Signal<String>,
+    get_cell_class: Callback<usize, String>,
+    input_class: String,
+) -> impl IntoView {
+    let column_order = use_column_order(col_count);
+    let sticky_columns = use_context::<StickyColumnsContext>();
+    // the leading drag handle, selection and disclosure columns have no filter
+    let drag_handle_cell = use_context::<RowReorderContext>()
+        .filter(|reorder| reorder.drag_handle)
+        .map(|_| view! { <th></th> });
+    let selection_cell = use_context::<SelectionColumnContext>().map(|_| view! { <th></th> });
+    let expand_cell = use_context::<ExpansionColumnContext>().map(|_| view! { <th></th> });
+
+    let cells = move || {
+        column_order
+            .get()
+            .into_iter()
+            .map(|col_index| {
+                let value = move || {
+                    filters.with(|filters| filters.get(&col_index).cloned().unwrap_or_default())
+                };
+
+                let cell = view! {
+                    <th class=get_cell_class(col_index)>
+                        <input
+                            type="search"
+                            class=input_class.clone()
+                            prop:value=value
+                            on:input=move |evt| set_filter(filters, col_index, event_target_value(&evt))
+                        />
+                    </th>
+                }
+                .into_view();
+
+                match sticky_columns {
+                    Some(sticky_columns) if sticky_columns.is_sticky(col_index) => {
+                        sticky_columns.stick_cell(cell, col_index, true)
+                    }
+                    _ => cell,
+                }
+            })
+            .collect_view()
+    };
+
+    view! {
+        <tr class=class>
+            {drag_handle_cell}
+            {selection_cell}
+            {expand_cell}
+            {cells}
+        </tr>
+    }
+}
+
+/// Sets the filter of the column `col_index` to `value` or removes it if `value` is empty.
+pub fn set_filter(filters: RwSignal<HashMap<usize, String>>, col_index: usize, value: String) {
+    filters.update(|filters| {
+        if value.is_empty() {
+            filters.remove(&col_index);
+        } else {
+            filters.insert(col_index, value);
+        }
+    });
+}
@@ -1,7 +1,8 @@
 #![allow(async_fn_in_trait)]
 
 use crate::ColumnSort;
-use std::collections::VecDeque;
+use std::cmp::Ordering;
+use std::collections::{HashMap, VecDeque};
 use std::fmt::Debug;
 use std::ops::Range;
 
@@ -35,8 +36,9 @@ pub trait TableDataProvider<Row, Err: Debug = String> {
     /// at least the range that is requested or more. If you return less rows than requested,
     /// it is assumed that the data source is done and there are no more rows to load.
     ///
-    /// In the case of an error the returned error `String` is going to be displayed in a
-    /// in place of the failed rows.
+    /// In the case of an error the `Debug` representation of the returned error is going to be
+    /// displayed in place of the failed rows. The error itself is passed to the `on_error` prop of
+    /// [`TableContent`] and can be accessed by the error row renderer through [`use_row_load_error`].
     async fn get_rows(&self, range: Range<usize>) -> Result<(Vec<Row>, Range<usize>), Err>;
 
     /// The total number of rows in the table. Returns `None` if unknown (which is the default).
@@ -55,6 +57,25 @@ pub trait TableDataProvider<Row, Err: Debug = String> {
         // by default do nothing
     }
 
+    /// Set the filters of the table. This maps column indices to the text that is entered in the
+    /// filter row of [`TableContent`] (see its `filterable` prop). Columns without filter text are not
+    /// contained in the map.
+    /// The default implementation does nothing.
+    /// Please note that after calling this method, the row count and the data will be reloaded.
+    #[allow(unused_variables)]
+    fn set_filters(&mut self, filters: &HashMap<usize, String>) {
+        // by default do nothing
+    }
+
+    /// Set the text of the global search of the table (see the `search` prop of [`TableContent`]).
+    /// Rows should match if any of their columns contains the text. It's empty if nothing is searched.
+    /// The default implementation does nothing.
+    /// Please note that after calling this method, the row count and the data will be reloaded.
+    #[allow(unused_variables)]
+    fn set_search(&mut self, search: &str) {
+        // by default do nothing
+    }
+
     /// Call `.track()` in this method on all signals that loading data relies on.
     /// For example a search of filters. Please check the [paginated_rest_datasource example](https://github.com/Synphonyte/leptos-struct-table/blob/master/examples/paginated_rest_datasource/src/data_provider.rs)
     fn track(&self) {
@@ -103,6 +124,18 @@ pub trait PaginatedTableDataProvider<Row, Err: Debug = String> {
         // by default do nothing
     }
 
+    /// Same as [`TableDataProvider::set_filters`]
+    #[allow(unused_variables)]
+    fn set_filters(&mut self, filters: &HashMap<usize, String>) {
+        // by default do nothing
+    }
+
+    /// Same as [`TableDataProvider::set_search`]
+    #[allow(unused_variables)]
+    fn set_search(&mut self, search: &str) {
+        // by default do nothing
+    }
+
     /// Same as [`TableDataProvider::track`]
     fn track(&self) {
         // by default do nothing
@@ -136,24 +169,115 @@ where
         PaginatedTableDataProvider::<Row, Err>::set_sorting(self, sorting)
     }
 
+    fn set_filters(&mut self, filters: &HashMap<usize, String>) {
+        PaginatedTableDataProvider::<Row, Err>::set_filters(self, filters)
+    }
+
+    fn set_search(&mut self, search: &str) {
+        PaginatedTableDataProvider::<Row, Err>::set_search(self, search)
+    }
+
     fn track(&self) {
         PaginatedTableDataProvider::<Row, Err>::track(self)
     }
 }
 
 /// Return `vec[range.start..range.end]` where `range` is clamped to the length of `vec`.
-pub fn get_vec_range_clamped<T: Clone>(
-    vec: &Vec<T>,
-    range: Range<usize>,
-) -> (Vec<T>, Range<usize>) {
-    if vec.is_empty() {
-        return (vec![], 0..0);
-    }
-
-    let start = range.start.min(vec.len() - 1);
-    let end = range.end.min(vec.len());
+///
+/// Only the rows in the range are cloned, so requesting a chunk is independent of the length of
+/// `vec`. Ranges that start after the end of `vec` return no rows.
+///
+/// ```
+/// # use leptos_struct_table::*;
+/// let rows = vec![1, 2, 3, 4, 5];
+///
+/// assert_eq!(get_vec_range_clamped(&rows, 1..3), (vec![2, 3], 1..3));
+/// assert_eq!(get_vec_range_clamped(&rows, 3..10), (vec![4, 5], 3..5));
+/// assert_eq!(get_vec_range_clamped(&rows, 7..10), (vec![], 5..5));
+/// ```
+pub fn get_vec_range_clamped<T: Clone>(vec: &[T], range: Range<usize>) -> (Vec<T>, Range<usize>) {
+    let start = range.start.min(vec.len());
+    let end = range.end.clamp(start, vec.len());
 
     let return_range = start..end;
 
     (vec[return_range.clone()].to_vec(), return_range)
 }
+
+/// Compares two rows by the value of one column. See [`sort_rows_with`].
+pub type RowComparator<Row> = fn(&Row, &Row) -> Ordering;
+
+/// Sorts `rows` like [`TableDataProvider::set_sorting`] of `Vec<Row>` (generated by `#[table(impl_vec_data_provider)]`)
+/// but uses the given comparators for the columns that have one. All other columns are sorted by
+/// the generated `PartialOrd` comparison.
+///
+/// With multi-column sorting every column is compared with its own comparator, so rows that are
+/// equal in the first sorted column are ordered by the second one and so on.
+///
+/// ```
+/// # use leptos::*;
+/// # use leptos_struct_table::*;
+/// # use std::cmp::Ordering;
+/// # use std::collections::{HashMap, VecDeque};
+/// #
+/// #[derive(TableRow, Clone)]
+/// #[table(sortable, impl_vec_data_provider)]
+/// pub struct Release {
+///     name: String,
+///     version: String,
+/// }
+///
+/// impl Release {
+///     fn cmp_version(a: &Self, b: &Self) -> Ordering {
+///         let parse = |version: &str| {
+///             version
+///                 .split('.')
+///                 .map(|part| part.parse::<u32>().unwrap_or_default())
+///                 .collect::<Vec<_>>()
+///         };
+///
+///         parse(&a.version).cmp(&parse(&b.version))
+///     }
+/// }
+///
+/// let mut rows = ["1.10.0", "1.2.0", "0.9.12", "1.2.3"]
+///     .map(|version| Release { name: "app".to_string(), version: version.to_string() })
+///     .to_vec();
+///
+/// let comparators = HashMap::from([(1, Release::cmp_version as RowComparator<Release>)]);
+///
+/// sort_rows_with(&mut rows, &VecDeque::from([(1, ColumnSort::Ascending)]), &comparators);
+/// assert_eq!(
+///     rows.iter().map(|row| row.version.as_str()).collect::<Vec<_>>(),
+///     vec!["0.9.12", "1.2.0", "1.2.3", "1.10.0"],
+/// );
+///
+/// // sorted by name first and then by version
+/// rows.push(Release { name: "lib".to_string(), version: "0.1.0".to_string() });
+/// sort_rows_with(
+///     &mut rows,
+///     &VecDeque::from([(0, ColumnSort::Descending), (1, ColumnSort::Descending)]),
+///     &comparators,
+/// );
+/// assert_eq!(
+///     rows.iter().map(|row| row.version.as_str()).collect::<Vec<_>>(),
+///     vec!["0.1.0", "1.10.0", "1.2.3", "1.2.0", "0.9.12"],
+/// );
+/// ```
+pub fn sort_rows_with<Row>(
+    rows: &mut Vec<Row>,
+    sorting: &VecDeque<(usize, ColumnSort)>,
+    comparators: &HashMap<usize, RowComparator<Row>>,
+) where
+    Vec<Row>: TableDataProvider<Row>,
+{
+    // sorting is stable so the least significant column is sorted first
+    for (col_index, sort) in sorting.iter().rev() {
+        match (comparators.get(col_index), sort) {
+            (_, ColumnSort::None) => {}
+            (Some(cmp), ColumnSort::Ascending) => rows.sort_by(cmp),
+            (Some(cmp), ColumnSort::Descending) => rows.sort_by(|a, b| cmp(b, a)),
+            (None, sort) => rows.set_sorting(&VecDeque::from([(*col_index, *sort)])),
+        }
+    }
+}
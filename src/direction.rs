@@ -0,0 +1,53 @@
+use leptos::*;
+
+/// The text direction of the table. See the `direction` prop of [`TableContent`].
+///
+/// The direction of the table itself comes from the `dir` attribute (or the CSS `direction`) of the
+/// `<table>` or one of its ancestors. This tells [`TableContent`] about it so it can place the
+/// sticky columns and the resize handles on the correct side.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Direction {
+    /// Left-to-right. Columns start on the left.
+    #[default]
+    Ltr,
+    /// Right-to-left, e.g. for Arabic or Hebrew. Columns start on the right.
+    Rtl,
+}
+
+impl Direction {
+    /// The value of the HTML `dir` attribute, i.e. `"ltr"` or `"rtl"`.
+    pub fn as_dir_attr(&self) -> &'static str {
+        match self {
+            Direction::Ltr => "ltr",
+            Direction::Rtl => "rtl",
+        }
+    }
+
+    /// The physical side (`"left"` or `"right"`) where the rows start.
+    pub(crate) fn start_side(&self) -> &'static str {
+        match self {
+            Direction::Ltr => "left",
+            Direction::Rtl => "right",
+        }
+    }
+
+    /// The physical side (`"left"` or `"right"`) where the rows end.
+    pub(crate) fn end_side(&self) -> &'static str {
+        match self {
+            Direction::Ltr => "right",
+            Direction::Rtl => "left",
+        }
+    }
+}
+
+/// Provided as context by [`TableContent`].
+#[derive(Copy, Clone)]
+pub(crate) struct DirectionContext(pub Signal<Direction>);
+
+/// Returns the `direction` prop of the surrounding [`TableContent`], e.g. for custom renderers that
+/// place something on one side of a cell. Outside of a [`TableContent`] this is [`Direction::Ltr`].
+pub fn use_table_direction() -> Signal<Direction> {
+    use_context::<DirectionContext>()
+        .map(|direction| direction.0)
+        .unwrap_or_else(|| Signal::derive(|| Direction::Ltr))
+}
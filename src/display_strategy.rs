@@ -1,4 +1,18 @@
 use leptos::*;
+use std::ops::Range;
+
+/// Default for the `overscan` prop of [`TableContent`].
+pub const DEFAULT_OVERSCAN: usize = 20;
+
+/// Default for the `estimated_row_height` prop of [`TableContent`].
+pub const DEFAULT_ESTIMATED_ROW_HEIGHT: f64 = 20.0;
+
+/// Maximum number of rows that are displayed at once.
+const MAX_DISPLAY_ROW_COUNT: usize = 500;
+
+/// How long in milliseconds the scroll container has to keep its size before [`PageSize::Auto`]
+/// computes a new number of rows per page.
+pub const AUTO_PAGE_SIZE_DEBOUNCE: f64 = 200.0;
 
 /// The display acceleration strategy. Defaults to `Virtualization`.
 #[derive(Copy, Clone, Default)]
@@ -16,30 +30,104 @@ pub enum DisplayStrategy {
     /// as more and more rows are loaded.
     InfiniteScroll,
 
-    // TODO : LoadMore(usize),
+    /// Like `InfiniteScroll` but more rows are only loaded when the user asks for them instead of
+    /// when they scroll to the bottom. The first `chunk_size` rows are shown followed by a row
+    /// rendered by the `load_more_row_renderer` of [`TableContent`]. Clicking it shows and loads the
+    /// next `chunk_size` rows. Once all rows are shown this row disappears.
+    ///
+    /// Use this where loading on scroll isn't reliable, e.g. on some touch devices.
+    LoadMore { chunk_size: usize },
+
     /// Only the amount of rows specified is shown at a time. You can use the
     /// `controller` to manipulate which page of rows is shown.
     /// Scrolling will have no effect on what rows are loaded.
     ///
     /// > Please note that this will work wether your data source implements
     /// > [`PaginatedTableDataProvider`] or [`TableDataProvider`] directly.
-    /// > Also `row_count` can be different from `PaginatedTableDataProvider::PAGE_ROW_COUNT`.
+    /// > Also `page_size` can be different from `PaginatedTableDataProvider::PAGE_ROW_COUNT`.
     Pagination {
-        row_count: usize,
+        page_size: PageSize,
         controller: PaginationController,
     },
 }
 
+/// The number of rows per page of [`DisplayStrategy::Pagination`].
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum PageSize {
+    /// Always show this number of rows per page.
+    Fixed(usize),
+
+    /// Show as many rows as fit into the height of the scroll container below the table header.
+    /// `row_height` is the height of a row in pixels. Give your rows this fixed height (e.g. with CSS)
+    /// to fill the pages exactly.
+    ///
+    /// The size of the scroll container is observed with a `ResizeObserver`. When it changes, the
+    /// number of rows per page is computed again once the size hasn't changed for
+    /// [`AUTO_PAGE_SIZE_DEBOUNCE`] milliseconds. If the number changes, the missing rows are fetched
+    /// and the current page is changed such that its first row stays visible.
+    ///
+    /// > The scroll container needs a height that doesn't depend on the table (e.g. `height: 100vh`
+    /// > or a sized flex item). With the default scroll container (the `<body>`) the table would
+    /// > grow with every page.
+    Auto { row_height: f64 },
+}
+
+impl PageSize {
+    /// The number of rows per page if `available_height` pixels are available for the rows.
+    /// This is always at least `1`.
+    ///
+    /// ```
+    /// # use leptos_struct_table::PageSize;
+    /// assert_eq!(PageSize::Fixed(10).row_count(500.0), 10);
+    /// assert_eq!(PageSize::Auto { row_height: 40.0 }.row_count(500.0), 12);
+    /// assert_eq!(PageSize::Auto { row_height: 40.0 }.row_count(0.0), 1);
+    /// ```
+    pub fn row_count(&self, available_height: f64) -> usize {
+        match self {
+            Self::Fixed(row_count) => (*row_count).max(1),
+            Self::Auto { row_height } => {
+                if *row_height > 0.0 {
+                    ((available_height / row_height).floor() as usize).max(1)
+                } else {
+                    1
+                }
+            }
+        }
+    }
+}
+
+impl From<usize> for PageSize {
+    fn from(row_count: usize) -> Self {
+        Self::Fixed(row_count)
+    }
+}
+
 impl DisplayStrategy {
-    pub(crate) fn set_row_count(&self, row_count: usize) {
+    /// Whether the total number of rows is loaded from the data provider for this strategy.
+    pub(crate) fn needs_row_count(&self) -> bool {
+        matches!(
+            self,
+            Self::Virtualization | Self::Pagination { .. } | Self::LoadMore { .. }
+        )
+    }
+
+    /// Whether rows that aren't displayed are replaced by placeholders of the same height.
+    pub(crate) fn uses_placeholders(&self) -> bool {
+        matches!(self, Self::Virtualization | Self::InfiniteScroll)
+    }
+
+    /// Updates the page count of the pagination controller. `page_row_count` is the resolved
+    /// [`PageSize`].
+    pub(crate) fn set_row_count(&self, row_count: usize, page_row_count: usize) {
         match self {
-            Self::Pagination {
-                row_count: page_row_count,
-                controller,
-            } => {
-                controller
-                    .page_count_signal
-                    .set(Some(row_count / *page_row_count + 1));
+            Self::Pagination { controller, .. } => {
+                let page_count = row_count.div_ceil(page_row_count).max(1);
+                controller.page_count_signal.set(Some(page_count));
+
+                // e.g. after filtering the current page might not exist anymore
+                if controller.current_page.get_untracked() >= page_count {
+                    controller.current_page.set(page_count - 1);
+                }
             }
             _ => {
                 // do nothing
@@ -48,12 +136,72 @@ impl DisplayStrategy {
     }
 }
 
-/// Allows to control what page is displayed as well as reading the page count and current page
+/// Computes the range of rows that should be loaded and displayed.
+///
+/// These are the visible rows plus `overscan` rows above and below them. If the data provider loads
+/// in chunks, the range is extended to whole chunks. The range never exceeds `row_count` (if known)
+/// or `MAX_DISPLAY_ROW_COUNT` rows.
+pub(crate) fn compute_display_range(
+    first_visible_row_index: usize,
+    visible_row_count: usize,
+    overscan: usize,
+    chunk_size: Option<usize>,
+    row_count: Option<usize>,
+) -> Range<usize> {
+    let mut start = first_visible_row_index.saturating_sub(overscan);
+
+    let mut end = first_visible_row_index + visible_row_count + overscan;
+
+    if let Some(chunk_size) = chunk_size {
+        start /= chunk_size;
+        start *= chunk_size;
+
+        end /= chunk_size;
+        end += 1;
+        end *= chunk_size;
+    }
+
+    if let Some(row_count) = row_count {
+        end = end.min(row_count);
+    }
+
+    end = end.min(start + MAX_DISPLAY_ROW_COUNT);
+
+    start..end
+}
+
+/// Computes the range of rows that is loaded for [`DisplayStrategy::LoadMore`] when the first
+/// `row_limit` rows are shown. If the data provider loads in chunks, the range is extended to whole
+/// chunks. The range never exceeds `row_count` (if known).
+pub(crate) fn compute_load_more_range(
+    row_limit: usize,
+    chunk_size: Option<usize>,
+    row_count: Option<usize>,
+) -> Range<usize> {
+    let mut end = row_limit;
+
+    if let Some(chunk_size) = chunk_size {
+        end = end.div_ceil(chunk_size) * chunk_size;
+    }
+
+    if let Some(row_count) = row_count {
+        end = end.min(row_count);
+    }
+
+    0..end
+}
+
+/// Allows to control what page is displayed as well as reading the page count and current page.
+/// Pass it to [`DisplayStrategy::Pagination`] and use it to render your own pager.
 #[derive(Copy, Clone)]
 pub struct PaginationController {
     /// The current page. The first page is `0`.
     pub current_page: RwSignal<usize>,
     page_count_signal: RwSignal<Option<usize>>,
+    /// The resolved number of rows per page. Set by [`TableContent`].
+    pub(crate) page_size_signal: RwSignal<Option<usize>>,
+    /// The number of rows per page set by [`PaginationController::set_page_size`].
+    pub(crate) page_size_override: RwSignal<Option<usize>>,
 }
 
 impl Default for PaginationController {
@@ -62,14 +210,16 @@ impl Default for PaginationController {
             // the value here doesn't really matter. We'll react only to changes later
             current_page: create_rw_signal(0),
             page_count_signal: create_rw_signal(None),
+            page_size_signal: create_rw_signal(None),
+            page_size_override: create_rw_signal(None),
         }
     }
 }
 
 impl PaginationController {
-    /// Call this to go to the next page
+    /// Call this to go to the next page. Does nothing on the last page if the page count is known.
     pub fn next(&self) {
-        self.current_page.set(self.current_page.get_untracked() + 1);
+        self.go_to(self.current_page.get_untracked() + 1);
     }
 
     /// Call this to go to the previous page
@@ -78,9 +228,83 @@ impl PaginationController {
             .set(self.current_page.get_untracked().saturating_sub(1));
     }
 
+    /// Goes to the page `page`. The first page is `0`. Pages after the last one go to the last page
+    /// if the page count is known.
+    pub fn go_to(&self, page: usize) {
+        let page = match self.page_count_signal.get_untracked() {
+            Some(page_count) => page.min(page_count.saturating_sub(1)),
+            None => page,
+        };
+
+        self.current_page.set(page);
+    }
+
     /// Returns a `Signal` of the page count once loaded. Depending on your table data provider
     /// this might not be available and thus always be `None`.
     pub fn page_count(&self) -> Signal<Option<usize>> {
         self.page_count_signal.into()
     }
+
+    /// Returns a `Signal` of the number of rows per page. It's `None` until the table is rendered
+    /// (and measured for [`PageSize::Auto`]).
+    pub fn page_size(&self) -> Signal<Option<usize>> {
+        self.page_size_signal.into()
+    }
+
+    /// Shows `page_size` rows per page from now on instead of the `page_size` of
+    /// [`DisplayStrategy::Pagination`]. The current page is changed such that its first row stays
+    /// visible and the missing rows are fetched.
+    pub fn set_page_size(&self, page_size: usize) {
+        let page_size = page_size.max(1);
+        let first_row_index =
+            self.current_page.get_untracked() * self.page_size_signal.get_untracked().unwrap_or(0);
+
+        batch(move || {
+            self.page_size_override.set(Some(page_size));
+            self.current_page.set(first_row_index / page_size);
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn display_range_adds_overscan_on_both_sides() {
+        assert_eq!(compute_display_range(50, 10, 20, None, None), 30..80);
+        assert_eq!(compute_display_range(50, 10, 0, None, None), 50..60);
+    }
+
+    #[test]
+    fn display_range_is_clamped_at_zero() {
+        assert_eq!(compute_display_range(5, 10, 20, None, None), 0..35);
+        assert_eq!(compute_display_range(0, 10, 20, None, None), 0..30);
+    }
+
+    #[test]
+    fn display_range_is_clamped_at_row_count() {
+        assert_eq!(compute_display_range(90, 10, 20, None, Some(100)), 70..100);
+        assert_eq!(compute_display_range(0, 10, 20, None, Some(5)), 0..5);
+    }
+
+    #[test]
+    fn display_range_is_aligned_to_chunks() {
+        assert_eq!(compute_display_range(50, 10, 20, Some(25), None), 25..100);
+        // the end is extended by a whole chunk even if it's aligned already
+        assert_eq!(compute_display_range(30, 10, 10, Some(25), None), 0..75);
+        assert_eq!(compute_display_range(0, 10, 20, Some(25), Some(40)), 0..40);
+    }
+
+    #[test]
+    fn display_range_is_limited() {
+        assert_eq!(
+            compute_display_range(0, 1000, 20, None, None),
+            0..MAX_DISPLAY_ROW_COUNT
+        );
+        assert_eq!(
+            compute_display_range(1000, 1000, 20, Some(100), None),
+            900..900 + MAX_DISPLAY_ROW_COUNT
+        );
+    }
 }
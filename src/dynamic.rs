@@ -0,0 +1,361 @@
+use crate::column_order::ColumnOrderContext;
+use crate::{
+    get_sorting_for_column, get_vec_range_clamped, ChangeEvent, ColumnSort,
+    DefaultNumberTableCellRenderer, DefaultTableCellRenderer, DefaultTableHeaderCellRenderer,
+    DummyTableClassesProvider, EventHandler, TableClassesProvider, TableDataProvider,
+    TableHeadEvent, TableRow,
+};
+use leptos::*;
+use serde::{Deserialize, Serialize};
+use std::cell::RefCell;
+use std::cmp::Ordering;
+use std::collections::{HashSet, VecDeque};
+use std::fmt::{Display, Formatter};
+use std::marker::PhantomData;
+use std::ops::Range;
+
+/// The type of the values of a column that is defined at runtime. See [`ColumnDef`].
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ColumnKind {
+    /// Rendered with [`DefaultTableCellRenderer`] (the default).
+    #[default]
+    Text,
+    /// Rendered with [`DefaultNumberTableCellRenderer`].
+    Number,
+    /// Rendered as `true` or `false`.
+    Bool,
+}
+
+/// The definition of a column that is only known at runtime, e.g. because it's sent by the backend
+/// together with the rows. See [`DynamicRow`].
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ColumnDef {
+    /// The name of the column. This is what [`TableRow::col_name`] returns for the column and
+    /// what you should use to map sorting (and filtering) to your backend.
+    pub name: String,
+    /// The title that is displayed in the header cell.
+    pub title: String,
+    /// The type of the values in this column.
+    #[serde(default)]
+    pub kind: ColumnKind,
+    /// Whether the table can be sorted by this column by clicking the header cell.
+    #[serde(default)]
+    pub sortable: bool,
+}
+
+/// The value of a cell of a [`DynamicRow`].
+///
+/// With serde this is (de)serialized untagged, i.e. as a plain JSON string, number, boolean or `null`.
+#[derive(Clone, Debug, Default, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum CellValue {
+    /// An empty cell.
+    #[default]
+    Empty,
+    Bool(bool),
+    Number(f64),
+    Text(String),
+}
+
+impl Display for CellValue {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CellValue::Empty => Ok(()),
+            CellValue::Bool(value) => write!(f, "{value}"),
+            CellValue::Number(value) => write!(f, "{value}"),
+            CellValue::Text(value) => write!(f, "{value}"),
+        }
+    }
+}
+
+/// The columns of the [`DynamicRow`]s of a table. This has to be provided as context
+/// (`provide_context(columns)`) around the [`TableContent`] that renders the rows.
+///
+/// Usually the data provider calls [`DynamicColumns::set`] with the column definitions it receives
+/// from the backend when loading rows. The header and the rows are re-rendered when the columns change.
+///
+/// The column indices of the sorting that is passed to [`TableDataProvider::set_sorting`] refer
+/// to the positions in this list. Use [`DynamicColumns::col_name`] to map them to your backend.
+/// If the backend changes the columns, make sure the sorting still makes sense, e.g. by only
+/// keeping the entries whose column names still exist.
+#[derive(Copy, Clone)]
+pub struct DynamicColumns(RwSignal<Vec<ColumnDef>>);
+
+impl Default for DynamicColumns {
+    fn default() -> Self {
+        Self(create_rw_signal(vec![]))
+    }
+}
+
+impl DynamicColumns {
+    /// Sets the columns. Does nothing if they are the same as before so this can be called for every
+    /// batch of rows that is loaded.
+    pub fn set(&self, columns: Vec<ColumnDef>) {
+        if self.0.with_untracked(|current| current != &columns) {
+            self.0.set(columns);
+        }
+    }
+
+    /// Returns a `Signal` of the current columns.
+    pub fn get(&self) -> Signal<Vec<ColumnDef>> {
+        self.0.into()
+    }
+
+    /// The name of the column at `col_index` or `None` if there is no such column.
+    pub fn col_name(&self, col_index: usize) -> Option<String> {
+        self.0
+            .with_untracked(|columns| columns.get(col_index).map(|column| column.name.clone()))
+    }
+
+    /// The number of columns.
+    pub fn len(&self) -> usize {
+        self.0.with_untracked(Vec::len)
+    }
+
+    /// Returns `true` if there are no columns (yet).
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+fn use_dynamic_columns() -> DynamicColumns {
+    use_context::<DynamicColumns>()
+        .expect("`DynamicColumns` have to be provided as context to render `DynamicRow`s")
+}
+
+thread_local! {
+    static COLUMN_NAMES: RefCell<HashSet<&'static str>> = RefCell::new(HashSet::new());
+}
+
+/// [`TableRow::col_name`] has to return a `&'static str`. Column names that are only known at runtime
+/// are leaked once and then reused.
+fn intern_column_name(name: &str) -> &'static str {
+    COLUMN_NAMES.with(|names| {
+        let mut names = names.borrow_mut();
+
+        match names.get(name) {
+            Some(name) => name,
+            None => {
+                let name: &'static str = Box::leak(name.to_string().into_boxed_str());
+                names.insert(name);
+                name
+            }
+        }
+    })
+}
+
+/// A row whose columns are only known at runtime, e.g. for generic data browsers where the backend
+/// decides which columns to show. The columns are defined by the [`DynamicColumns`] that are provided as context.
+///
+/// `ClsP` is the classes provider that is used to render the row (see [`TableRow::ClassesProvider`]).
+///
+/// For a `Vec<DynamicRow>` [`TableDataProvider`] is implemented including sorting by any column.
+///
+/// ```
+/// # use leptos::*;
+/// # use leptos_struct_table::*;
+/// #
+/// #[component]
+/// pub fn App() -> impl IntoView {
+///     let columns = DynamicColumns::default();
+///     columns.set(vec![ColumnDef {
+///         name: "name".to_string(),
+///         title: "Name".to_string(),
+///         kind: ColumnKind::Text,
+///         sortable: true,
+///     }]);
+///     provide_context(columns);
+///
+///     let rows: Vec<DynamicRow> = vec![
+///         DynamicRow::new(vec![CellValue::Text("John".to_string())]),
+///         DynamicRow::new(vec![CellValue::Text("Jane".to_string())]),
+///     ];
+///
+///     view! {
+///         <table>
+///             <TableContent rows />
+///         </table>
+///     }
+/// }
+/// ```
+///
+/// Please see the [dynamic_columns example](https://github.com/Synphonyte/leptos-struct-table/blob/master/examples/dynamic_columns/src/main.rs)
+/// for how to use this with a backend.
+#[derive(Clone, Debug, PartialEq)]
+pub struct DynamicRow<ClsP = DummyTableClassesProvider> {
+    /// The values of the cells in the same order as the columns.
+    pub values: Vec<CellValue>,
+    _marker: PhantomData<ClsP>,
+}
+
+impl<ClsP> DynamicRow<ClsP> {
+    /// Creates a row from the values of its cells in the same order as the columns.
+    pub fn new(values: Vec<CellValue>) -> Self {
+        Self {
+            values,
+            _marker: PhantomData,
+        }
+    }
+
+    /// The value in the column at `col_index`. Returns [`CellValue::Empty`] if the row has no value for it.
+    pub fn value(&self, col_index: usize) -> &CellValue {
+        self.values.get(col_index).unwrap_or(&CellValue::Empty)
+    }
+}
+
+impl<ClsP> TableRow for DynamicRow<ClsP>
+where
+    ClsP: TableClassesProvider + Copy + 'static,
+{
+    type ClassesProvider = ClsP;
+
+    /// The number of columns is only known at runtime. See [`TableRow::column_count`].
+    const COLUMN_COUNT: usize = 0;
+
+    fn column_count() -> usize {
+        use_context::<DynamicColumns>().map_or(0, |columns| columns.len())
+    }
+
+    #[allow(unused_variables)]
+    fn render_row(
+        &self,
+        index: usize,
+        on_change: EventHandler<ChangeEvent<Self>>,
+    ) -> impl IntoView {
+        let class_provider = ClsP::new();
+        let columns = use_dynamic_columns().get();
+        let column_order = use_context::<ColumnOrderContext>();
+        let row = self.clone();
+
+        move || {
+            columns.with(|columns| {
+                ordered_columns(columns, column_order)
+                    .map(|(col_index, column)| {
+                        let class = class_provider.cell("");
+
+                        match (column.kind, row.value(col_index)) {
+                            (ColumnKind::Number, CellValue::Number(value)) => view! {
+                                <DefaultNumberTableCellRenderer
+                                    value=*value
+                                    class
+                                    index=col_index
+                                    on_change=|_| {}
+                                />
+                            },
+                            (_, value) => view! {
+                                <DefaultTableCellRenderer
+                                    value=value.to_string()
+                                    class
+                                    index=col_index
+                                    on_change=|_| {}
+                                />
+                            },
+                        }
+                    })
+                    .collect_view()
+            })
+        }
+    }
+
+    fn render_head_row<F>(
+        sorting: Signal<VecDeque<(usize, ColumnSort)>>,
+        on_head_click: F,
+    ) -> impl IntoView
+    where
+        F: Fn(TableHeadEvent) + Clone + 'static,
+    {
+        let class_provider = ClsP::new();
+        let columns = use_dynamic_columns().get();
+        let column_order = use_context::<ColumnOrderContext>();
+
+        move || {
+            columns.with(|columns| {
+                ordered_columns(columns, column_order)
+                    .map(|(index, column)| {
+                        let sortable = column.sortable;
+                        let title = column.title.clone();
+
+                        view! {
+                            <DefaultTableHeaderCellRenderer
+                                class=Signal::derive(move || {
+                                    if sortable {
+                                        class_provider.thead_cell(get_sorting_for_column(index, sorting), "")
+                                    } else {
+                                        class_provider.thead_cell_unsortable("")
+                                    }
+                                })
+                                inner_class=class_provider.thead_cell_inner()
+                                index
+                                sort_priority=Signal::derive(move || {
+                                    if sorting.get().len() < 2 {
+                                        return None;
+                                    }
+                                    sorting.get().iter().position(|(i, _)| *i == index)
+                                })
+                                sort_direction=Signal::derive(move || get_sorting_for_column(index, sorting))
+                                on_click=on_head_click.clone()
+                                sortable
+                            >
+                                {title}
+                            </DefaultTableHeaderCellRenderer>
+                        }
+                    })
+                    .collect_view()
+            })
+        }
+    }
+
+    fn col_name(col_index: usize) -> &'static str {
+        use_dynamic_columns()
+            .col_name(col_index)
+            .map(|name| intern_column_name(&name))
+            .unwrap_or_else(|| unreachable!("Column index {} out of bounds", col_index))
+    }
+}
+
+/// The columns with their indices in the column order of the surrounding [`TableContent`].
+fn ordered_columns(
+    columns: &[ColumnDef],
+    column_order: Option<ColumnOrderContext>,
+) -> impl Iterator<Item = (usize, &ColumnDef)> {
+    let order = match column_order {
+        Some(column_order) => column_order.get(columns.len()),
+        None => (0..columns.len()).collect(),
+    };
+
+    order
+        .into_iter()
+        .map(move |col_index| (col_index, &columns[col_index]))
+}
+
+impl<ClsP> TableDataProvider<DynamicRow<ClsP>> for Vec<DynamicRow<ClsP>>
+where
+    ClsP: Clone,
+{
+    async fn get_rows(
+        &self,
+        range: Range<usize>,
+    ) -> Result<(Vec<DynamicRow<ClsP>>, Range<usize>), String> {
+        Ok(get_vec_range_clamped(self, range))
+    }
+
+    async fn row_count(&self) -> Option<usize> {
+        Some(self.len())
+    }
+
+    fn set_sorting(&mut self, sorting: &VecDeque<(usize, ColumnSort)>) {
+        for (col_index, sort) in sorting.iter().rev() {
+            let cmp = |a: &DynamicRow<ClsP>, b: &DynamicRow<ClsP>| {
+                a.value(*col_index)
+                    .partial_cmp(b.value(*col_index))
+                    .unwrap_or(Ordering::Equal)
+            };
+
+            match sort {
+                ColumnSort::Ascending => self.sort_by(cmp),
+                ColumnSort::Descending => self.sort_by(|a, b| cmp(b, a)),
+                ColumnSort::None => {}
+            }
+        }
+    }
+}
@@ -1,4 +1,7 @@
+use crate::ColumnSort;
 use leptos::ev::MouseEvent;
+use std::collections::{HashMap, VecDeque};
+use std::ops::Range;
 use std::rc::Rc;
 
 /// The event provided to the `on_change` prop of the table component
@@ -12,17 +15,274 @@ pub struct ChangeEvent<Row: Clone> {
     pub changed_row: Row,
 }
 
-/// The event provided to the `on_selection_change` prop of the table component
+/// The event provided to the `on_cell_edit` prop of the table component. Like [`ChangeEvent`] but
+/// with the row as it was before the edit, e.g. to build an undo stack or to diff the changes.
+#[derive(Debug, Clone)]
+pub struct CellEditEvent<Row: Clone> {
+    /// The index of the table row that contains the cell that was changed. Starts at 0.
+    pub row_index: usize,
+    /// The index of the table column that contains the cell that was changed (see [`TableRow::col_name`]).
+    pub col_index: usize,
+    /// The row before the cell was changed. Only the cell at `col_index` differs from `changed_row`.
+    pub previous_row: Row,
+    /// The row after the cell was changed.
+    pub changed_row: Row,
+}
+
+/// The event provided to the `on_selection_change` prop of the table component.
+/// It is emitted whenever the selection changes, i.e. when a row is clicked, when all rows are
+/// selected or de-selected at once and when the selection is cleared because the table is reloaded.
 #[derive(Debug, Clone)]
 pub struct SelectionChangeEvent<Row: Clone> {
     /// `true` is the row was selected, `false` if it was de-selected.
+    /// For changes of the whole selection `true` if rows were selected and `false` if they were de-selected.
     pub selected: bool,
-    /// The index of the row that was de-/selected.
+    /// The index of the row that was de-/selected. `None` if the whole selection changed.
+    pub row_index: Option<usize>,
+    /// The row that was de-/selected. `None` if the whole selection changed.
+    pub row: Option<Row>,
+    /// The indices of all rows that are selected now in ascending order.
+    pub selected_indices: Vec<usize>,
+    /// The selected rows in ascending order of their indices. Rows that aren't loaded
+    /// (anymore) are missing, so this can be shorter than `selected_indices`.
+    pub selected_rows: Vec<Row>,
+    /// `true` if the change came from the "select all" checkbox in the header.
+    pub from_select_all: bool,
+}
+
+/// The event provided to the `on_selection_rejected` prop of the table component.
+/// It is emitted when a row can't be selected because `max_selection` rows are already selected.
+#[derive(Debug, Clone)]
+pub struct SelectionRejectedEvent<Row: Clone> {
+    /// The index of the row that was clicked.
+    pub row_index: usize,
+    /// The row that was clicked.
+    pub row: Row,
+    /// The maximum number of rows that can be selected.
+    pub max_selection: usize,
+}
+
+/// The event provided to the `on_row_click` prop of the table component.
+/// It is emitted when a row is clicked, regardless of the `selection` mode. Clicks on interactive
+/// elements inside of cells like `<input>`, `<button>` or `<a>` don't emit it.
+///
+/// With `select_on_row_click` (the default) a click on a row first emits this event and then
+/// selects the row. Neither happens for clicks on interactive elements, so a button inside of a cell
+/// only runs its own handler:
+///
+/// ```
+/// # use leptos::*;
+/// # use leptos_struct_table::*;
+/// # use std::collections::HashSet;
+/// #
+/// #[derive(TableRow, Clone)]
+/// #[table(impl_vec_data_provider)]
+/// pub struct Invoice {
+///     number: String,
+///     #[table(renderer = "ArchiveButtonCellRenderer")]
+///     id: u32,
+/// }
+///
+/// #[component]
+/// fn ArchiveButtonCellRenderer<F>(
+///     class: String,
+///     #[prop(into)] value: MaybeSignal<u32>,
+///     on_change: F,
+///     index: usize,
+/// ) -> impl IntoView
+/// where
+///     F: Fn(u32) + 'static,
+/// {
+///     view! {
+///         <td class=class>
+///             // doesn't select the row and doesn't emit `on_row_click`
+///             <button on:click=move |_| logging::log!("archive {}", value.get_untracked())>
+///                 "Archive"
+///             </button>
+///         </td>
+///     }
+/// }
+///
+/// #[component]
+/// fn Invoices() -> impl IntoView {
+///     # let rows: Vec<Invoice> = vec![];
+///     let selected_indices = create_rw_signal(HashSet::new());
+///
+///     // emitted before the clicked row is selected
+///     let on_row_click = move |evt: RowClickEvent<Invoice>| {
+///         logging::log!("open invoice {}", evt.row.number);
+///     };
+///
+///     view! {
+///         <table>
+///             <TableContent
+///                 rows
+///                 selection=Selection::Multiple(selected_indices)
+///                 on_row_click
+///             />
+///         </table>
+///     }
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct RowClickEvent<Row: Clone> {
+    /// The index of the row that was clicked. Starts at 0.
+    pub row_index: usize,
+    /// The row that was clicked.
+    pub row: Row,
+    /// The mouse event that triggered the event. Use it to read the pressed modifier keys.
+    pub mouse_event: MouseEvent,
+}
+
+/// The event provided to the `on_cell_click` prop of the table component.
+/// It is emitted when a body cell is clicked. Clicks on interactive elements inside of the cell like
+/// `<input>`, `<button>` or `<a>` don't emit it.
+#[derive(Debug, Clone)]
+pub struct CellClickEvent<Row: Clone> {
+    /// The index of the row of the cell. Starts at 0.
+    pub row_index: usize,
+    /// The index of the column of the cell (see [`TableRow::col_name`]). It doesn't change when the
+    /// columns are reordered or other columns are hidden.
+    pub col_index: usize,
+    /// The row of the cell.
+    pub row: Row,
+    /// The mouse event that triggered the event. Call `stop_propagation()` on it to suppress `on_row_click`.
+    pub mouse_event: MouseEvent,
+}
+
+/// The event provided to the `on_error` prop of the table component.
+/// It is emitted when the data provider returns an error from [`TableDataProvider::get_rows`],
+/// e.g. to log it or to send it to your error tracking.
+#[derive(Debug)]
+pub struct LoadErrorEvent<Err> {
+    /// The range of rows that has been requested.
+    pub range: Range<usize>,
+    /// The error returned by the data provider.
+    pub error: Rc<Err>,
+}
+
+impl<Err> Clone for LoadErrorEvent<Err> {
+    fn clone(&self) -> Self {
+        Self {
+            range: self.range.clone(),
+            error: Rc::clone(&self.error),
+        }
+    }
+}
+
+/// The event provided to the `on_cell_focus` prop of the table component.
+/// It is emitted when a cell has been opened for editing through [`TableController::edit_cell`].
+#[derive(Debug, Clone)]
+pub struct CellFocusEvent<Row: Clone> {
+    /// The index of the table row that contains the cell. Starts at 0.
     pub row_index: usize,
-    /// The row that was de-/selected.
+    /// The index of the table column that contains the cell. Starts at 0.
+    pub col_index: usize,
+    /// The row that contains the cell.
     pub row: Row,
 }
 
+/// The event provided to the `on_filter_change` prop of the table component.
+/// It is emitted when the text of a filter input in the filter row has been changed.
+///
+/// Server backed data providers can use this to translate the filters into e.g. SQL `WHERE`
+/// clauses. Alternatively implement [`TableDataProvider::set_filters`].
+#[derive(Debug, Clone)]
+pub struct FilterChangeEvent {
+    /// The index of the column whose filter has been changed. Starts at 0.
+    pub col_index: usize,
+    /// The new filter text of that column. Empty if the filter has been cleared.
+    pub value: String,
+    /// All active filters by column index. Columns without filter text are not contained.
+    pub filters: HashMap<usize, String>,
+}
+
+/// The event provided to the `on_sort_change` prop of the table component.
+/// It is emitted when the sorting has been changed by clicking a header cell.
+///
+/// Use [`sorting_to_param`] to store the sorting e.g. in the URL.
+#[derive(Debug, Clone)]
+pub struct SortChangeEvent {
+    /// The index of the column whose header has been clicked. Starts at 0.
+    pub col_index: usize,
+    /// The new sorting in order of priority. Empty if nothing is sorted anymore.
+    pub sorting: VecDeque<(usize, ColumnSort)>,
+}
+
+/// The event provided to the `on_page_change` prop of the table component.
+/// It is emitted when the current page of [`DisplayStrategy::Pagination`] has changed.
+#[derive(Debug, Clone)]
+pub struct PageChangeEvent {
+    /// The new current page. The first page is `0`.
+    pub page: usize,
+    /// The number of pages if it's known.
+    pub page_count: Option<usize>,
+    /// The number of rows per page if it's known.
+    pub page_size: Option<usize>,
+}
+
+/// The event provided to the `on_visible_range_change` prop of the table component.
+/// It is emitted (debounced) once the table has been rendered and whenever the rows inside of the
+/// viewport change, e.g. while scrolling.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VisibleRangeChangeEvent {
+    /// The indices of the rows that are (at least partially) inside of the viewport of the scroll
+    /// container. With [`DisplayStrategy::Pagination`] these are the rows of the current page.
+    pub visible_range: Range<usize>,
+    /// The indices of the rows that are rendered, i.e. the visible rows and the overscan above and
+    /// below them (see the `overscan` prop of [`TableContent`]).
+    pub rendered_range: Range<usize>,
+}
+
+/// The event provided to the `on_search_change` prop of the table component.
+/// It is emitted when the `search` prop has been changed.
+///
+/// Server backed data providers can forward the search to the server with this.
+/// Alternatively implement [`TableDataProvider::set_search`].
+#[derive(Debug, Clone)]
+pub struct SearchChangeEvent {
+    /// The new search text. Empty if the search has been cleared.
+    pub search: String,
+}
+
+/// The event provided to the `on_column_reorder` prop of the table component.
+/// It is emitted when a column has been moved, e.g. by dragging its header cell onto another one.
+#[derive(Debug, Clone)]
+pub struct ColumnReorderEvent {
+    /// The index of the column that has been moved. Starts at 0 for the first field in the struct.
+    pub col_index: usize,
+    /// The index of the column to whose position it has been moved.
+    pub target_col_index: usize,
+    /// The new display order of all columns. Contains every column index exactly once.
+    pub column_order: Vec<usize>,
+}
+
+/// The event provided to the `on_cell_input` prop of the table component.
+/// It is emitted on every input in an editable cell, before the change is committed with `on_change`,
+/// e.g. to validate the value while the user is typing. See [`use_cell_input`].
+#[derive(Debug, Clone)]
+pub struct CellInputEvent {
+    /// The index of the table row that contains the cell. Starts at 0.
+    pub row_index: usize,
+    /// The index of the table column that contains the cell. Starts at 0.
+    pub col_index: usize,
+    /// The current text of the input. It isn't necessarily a valid value of the field yet.
+    pub value: String,
+}
+
+/// The event provided to the `on_row_move` prop of the table component.
+/// It is emitted when a row has been dropped onto another row while `row_reorderable` is enabled.
+///
+/// Apply it to your data by removing the row at `from_index` and inserting it at `to_index`.
+#[derive(Debug, Clone)]
+pub struct RowMoveEvent {
+    /// The index of the row that has been moved.
+    pub from_index: usize,
+    /// The index of the row onto which it has been dropped. This is the index the moved row
+    /// should have afterwards.
+    pub to_index: usize,
+}
+
 /// Event emitted when a table head cell is clicked.
 #[derive(Debug)]
 pub struct TableHeadEvent {
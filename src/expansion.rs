@@ -0,0 +1,27 @@
+use leptos::*;
+use std::collections::HashSet;
+
+/// Attribute of the `<tr>` that shows the details of an expanded row. It tells the detail rows apart
+/// from the body rows when the rendered rows are measured.
+pub(crate) const EXPANDED_ROW_ATTRIBUTE: &str = "data-expanded-row";
+
+/// Provided as context by [`TableContent`] if the table has an `expanded_row_renderer`.
+#[derive(Copy, Clone)]
+pub(crate) struct ExpansionColumnContext {
+    pub expanded: RwSignal<HashSet<usize>>,
+}
+
+impl ExpansionColumnContext {
+    pub fn is_expanded(&self, row_index: usize) -> bool {
+        self.expanded.with(|expanded| expanded.contains(&row_index))
+    }
+
+    /// Expands the row `row_index` if it's collapsed and vice versa.
+    pub fn toggle(&self, row_index: usize) {
+        self.expanded.update(|expanded| {
+            if !expanded.remove(&row_index) {
+                expanded.insert(row_index);
+            }
+        });
+    }
+}
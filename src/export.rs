@@ -0,0 +1,311 @@
+use crate::TableRow;
+
+/// Provides the values of the cells of a row as text for exporting the table data with
+/// [`export_json`] or [`export_csv`].
+///
+/// Column indices are the same as for [`TableRow::col_name`], i.e. skipped fields are not counted.
+pub trait TableRowExport: TableRow {
+    /// The title of the column at `col_index` as displayed in the header cell.
+    /// Defaults to [`TableRow::col_name`].
+    fn col_title(col_index: usize) -> String {
+        Self::col_name(col_index).to_string()
+    }
+
+    /// The titles of all columns in the order of their column indices (see [`TableRowExport::col_title`]),
+    /// e.g. to label the checkboxes of a column chooser. Skipped fields aren't contained.
+    ///
+    /// ```
+    /// # use leptos::*;
+    /// # use leptos_struct_table::*;
+    /// #
+    /// #[derive(TableRow, Clone)]
+    /// pub struct Person {
+    ///     #[table(skip)]
+    ///     id: u32,
+    ///     name: String,
+    ///     age: u32,
+    /// }
+    ///
+    /// impl TableRowExport for Person {
+    ///     fn col_title(col_index: usize) -> String {
+    ///         ["Name", "Age in years"][col_index].to_string()
+    ///     }
+    ///
+    ///     fn col_value(&self, col_index: usize) -> Option<String> {
+    ///         match col_index {
+    ///             0 => Some(self.name.clone()),
+    ///             _ => Some(self.age.to_string()),
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// assert_eq!(Person::column_titles(), vec!["Name", "Age in years"]);
+    /// ```
+    fn column_titles() -> Vec<String> {
+        (0..Self::column_count()).map(Self::col_title).collect()
+    }
+
+    /// The value of the cell at `col_index` formatted the same way as it's rendered, i.e. taking
+    /// `getter`, `format` and `none_value` into account.
+    ///
+    /// Return `None` if the cell is empty, e.g. for an `Option` field that is `None` and has no `none_value`.
+    fn col_value(&self, col_index: usize) -> Option<String>;
+
+    /// The row as a JSON object with one entry per column in column order, e.g. for a
+    /// "copy row as JSON" action. Unlike serializing the whole struct this contains exactly what is
+    /// shown: the values are formatted like the cells (see [`TableRowExport::col_value`]), getter
+    /// columns are included and skipped fields are left out. Empty cells are `null`.
+    ///
+    /// This is only available when the **crate feature `serde_json`** is enabled
+    ///
+    /// ```
+    /// # use leptos::*;
+    /// # use leptos_struct_table::*;
+    /// # use serde_json::json;
+    /// #
+    /// #[derive(TableRow, Clone)]
+    /// pub struct Person {
+    ///     #[table(skip)]
+    ///     id: u32,
+    ///     name: String,
+    ///     #[table(title = "Years")]
+    ///     age: Option<u32>,
+    /// }
+    ///
+    /// impl TableRowExport for Person {
+    ///     fn col_title(col_index: usize) -> String {
+    ///         ["Name", "Years"][col_index].to_string()
+    ///     }
+    ///
+    ///     fn col_value(&self, col_index: usize) -> Option<String> {
+    ///         match col_index {
+    ///             0 => Some(self.name.clone()),
+    ///             _ => self.age.map(|age| age.to_string()),
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// let jane = Person { id: 7, name: "Jane".to_string(), age: None };
+    ///
+    /// assert_eq!(jane.to_json_value(ExportKey::FieldName), json!({ "name": "Jane", "age": null }));
+    /// assert_eq!(jane.to_json_value(ExportKey::Title), json!({ "Name": "Jane", "Years": null }));
+    /// ```
+    #[cfg(feature = "serde_json")]
+    #[doc(cfg(feature = "serde_json"))]
+    fn to_json_value(&self, key: ExportKey) -> serde_json::Value
+    where
+        Self: Sized,
+    {
+        let object = exported_columns::<Self>()
+            .map(|col_index| {
+                let key = match key {
+                    ExportKey::FieldName => Self::col_name(col_index).to_string(),
+                    ExportKey::Title => Self::col_title(col_index),
+                };
+                let value = self
+                    .col_value(col_index)
+                    .map_or(serde_json::Value::Null, serde_json::Value::String);
+
+                (key, value)
+            })
+            .collect();
+
+        serde_json::Value::Object(object)
+    }
+}
+
+/// What to use as the keys of the exported objects in [`export_json`] and
+/// `TableRowExport::to_json_value`.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum ExportKey {
+    /// Use the name of the struct field (see [`TableRow::col_name`]). This is the default.
+    #[default]
+    FieldName,
+    /// Use the title of the column (see [`TableRowExport::col_title`]).
+    Title,
+}
+
+/// The indices of the columns that are exported.
+pub(crate) fn exported_columns<Row: TableRow>() -> impl Iterator<Item = usize> {
+    0..Row::column_count()
+}
+
+/// Serializes `rows` into a JSON array of objects with one entry per column.
+///
+/// Empty cells (see [`TableRowExport::col_value`]) are exported as `null`.
+///
+/// ```
+/// # use leptos::*;
+/// # use leptos_struct_table::*;
+/// #
+/// #[derive(TableRow, Clone)]
+/// pub struct Person {
+///     name: String,
+///     #[table(title = "Years")]
+///     age: Option<u32>,
+/// }
+///
+/// impl TableRowExport for Person {
+///     fn col_title(col_index: usize) -> String {
+///         ["Name", "Years"][col_index].to_string()
+///     }
+///
+///     fn col_value(&self, col_index: usize) -> Option<String> {
+///         match col_index {
+///             0 => Some(self.name.clone()),
+///             _ => self.age.map(|age| age.to_string()),
+///         }
+///     }
+/// }
+///
+/// let rows = vec![
+///     Person { name: "John \"Jack\"".to_string(), age: Some(32) },
+///     Person { name: "Jane".to_string(), age: None },
+/// ];
+///
+/// assert_eq!(
+///     export_json(&rows, ExportKey::FieldName),
+///     r#"[{"name":"John \"Jack\"","age":"32"},{"name":"Jane","age":null}]"#,
+/// );
+/// assert_eq!(
+///     export_json(&rows[1..], ExportKey::Title),
+///     r#"[{"Name":"Jane","Years":null}]"#,
+/// );
+/// ```
+pub fn export_json<Row: TableRowExport>(rows: &[Row], key: ExportKey) -> String {
+    let keys = exported_columns::<Row>()
+        .map(|col_index| match key {
+            ExportKey::FieldName => Row::col_name(col_index).to_string(),
+            ExportKey::Title => Row::col_title(col_index),
+        })
+        .map(|key| json_string(&key))
+        .collect::<Vec<_>>();
+
+    let objects = rows
+        .iter()
+        .map(|row| {
+            let entries = exported_columns::<Row>()
+                .zip(&keys)
+                .map(|(col_index, key)| {
+                    let value = row
+                        .col_value(col_index)
+                        .map_or_else(|| "null".to_string(), |value| json_string(&value));
+
+                    format!("{key}:{value}")
+                })
+                .collect::<Vec<_>>();
+
+            format!("{{{}}}", entries.join(","))
+        })
+        .collect::<Vec<_>>();
+
+    format!("[{}]", objects.join(","))
+}
+
+/// Serializes `rows` into CSV according to RFC 4180. The first record contains the column titles
+/// (see [`TableRowExport::col_title`]). Records are separated by CRLF.
+///
+/// Fields that contain commas, double quotes or line breaks are enclosed in double quotes and
+/// double quotes inside of them are doubled. Empty cells (see [`TableRowExport::col_value`]) are
+/// exported as empty fields.
+///
+/// The rows are exported in the order they're given. To export them in the order the user sees them,
+/// sort them the same way as the table does before, e.g. with
+/// [`TableDataProvider::set_sorting`](crate::TableDataProvider::set_sorting) on a clone of the `Vec`.
+/// The result can then be offered as a download through a `Blob` with the type `text/csv`.
+///
+/// ```
+/// # use leptos::*;
+/// # use leptos_struct_table::*;
+/// # use std::collections::VecDeque;
+/// #
+/// #[derive(TableRow, Clone)]
+/// #[table(sortable, impl_vec_data_provider)]
+/// pub struct Person {
+///     name: String,
+///     #[table(title = "Years")]
+///     age: Option<u32>,
+/// }
+///
+/// impl TableRowExport for Person {
+///     fn col_title(col_index: usize) -> String {
+///         ["Name", "Years"][col_index].to_string()
+///     }
+///
+///     fn col_value(&self, col_index: usize) -> Option<String> {
+///         match col_index {
+///             0 => Some(self.name.clone()),
+///             _ => self.age.map(|age| age.to_string()),
+///         }
+///     }
+/// }
+///
+/// let mut rows = vec![
+///     Person { name: "Doe, John \"Jack\"".to_string(), age: Some(32) },
+///     Person { name: "Jane\nDoe".to_string(), age: None },
+/// ];
+///
+/// assert_eq!(
+///     export_csv(&rows),
+///     "Name,Years\r\n\"Doe, John \"\"Jack\"\"\",32\r\n\"Jane\nDoe\",\r\n",
+/// );
+///
+/// // export in the current sort order of the table
+/// rows.set_sorting(&VecDeque::from([(0, ColumnSort::Descending)]));
+///
+/// assert_eq!(
+///     export_csv(&rows),
+///     "Name,Years\r\n\"Jane\nDoe\",\r\n\"Doe, John \"\"Jack\"\"\",32\r\n",
+/// );
+/// ```
+pub fn export_csv<Row: TableRowExport>(rows: &[Row]) -> String {
+    let mut csv = String::new();
+
+    let titles = exported_columns::<Row>()
+        .map(|col_index| csv_field(&Row::col_title(col_index)))
+        .collect::<Vec<_>>();
+    csv.push_str(&titles.join(","));
+    csv.push_str("\r\n");
+
+    for row in rows {
+        let fields = exported_columns::<Row>()
+            .map(|col_index| {
+                row.col_value(col_index)
+                    .map(|value| csv_field(&value))
+                    .unwrap_or_default()
+            })
+            .collect::<Vec<_>>();
+        csv.push_str(&fields.join(","));
+        csv.push_str("\r\n");
+    }
+
+    csv
+}
+
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+fn json_string(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len() + 2);
+
+    escaped.push('"');
+    for c in value.chars() {
+        match c {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            '\t' => escaped.push_str("\\t"),
+            c if c.is_control() => escaped.push_str(&format!("\\u{:04x}", c as u32)),
+            c => escaped.push(c),
+        }
+    }
+    escaped.push('"');
+
+    escaped
+}
@@ -0,0 +1,279 @@
+use crate::{
+    sort_rows_with, ColumnSort, PaginatedTableDataProvider, RowComparator, TableDataProvider,
+    TableRowExport,
+};
+use std::collections::{HashMap, VecDeque};
+use std::rc::Rc;
+
+type SpanningRowClosure<Row> = Rc<dyn Fn(&Row) -> bool>;
+
+/// Returns `true` if `row` matches all `filters`, i.e. the value of every filtered column
+/// (see [`TableRowExport::col_value`]) contains the filter text. The comparison is case-insensitive.
+///
+/// Because [`TableRowExport::col_value`] is used, the filters apply to the values of the fields and not
+/// to what a custom cell renderer makes out of them.
+///
+/// ```
+/// # use leptos::*;
+/// # use leptos_struct_table::*;
+/// # use std::collections::HashMap;
+/// #
+/// #[derive(TableRow, Clone)]
+/// pub struct Person {
+///     name: String,
+///     age: u32,
+/// }
+///
+/// impl TableRowExport for Person {
+///     fn col_value(&self, col_index: usize) -> Option<String> {
+///         match col_index {
+///             0 => Some(self.name.clone()),
+///             _ => Some(self.age.to_string()),
+///         }
+///     }
+/// }
+///
+/// let john = Person { name: "John".to_string(), age: 32 };
+///
+/// assert!(row_matches_filters(&john, &HashMap::from([(0, "jo".to_string())])));
+/// assert!(row_matches_filters(&john, &HashMap::from([(0, "JO".to_string()), (1, "3".to_string())])));
+/// assert!(!row_matches_filters(&john, &HashMap::from([(0, "jo".to_string()), (1, "4".to_string())])));
+/// ```
+pub fn row_matches_filters<Row: TableRowExport>(
+    row: &Row,
+    filters: &HashMap<usize, String>,
+) -> bool {
+    filters.iter().all(|(col_index, filter)| {
+        let filter = filter.to_lowercase();
+
+        match row.col_value(*col_index) {
+            Some(value) => value.to_lowercase().contains(&filter),
+            None => filter.is_empty(),
+        }
+    })
+}
+
+/// Returns `true` if any column of `row` (see [`TableRowExport::col_value`]) contains `search`.
+/// The comparison is case-insensitive and an empty search matches every row.
+///
+/// Since the values come from [`TableRowExport::col_value`], columns whose value is computed by a
+/// `getter` are searched by whatever your implementation returns for them.
+///
+/// ```
+/// # use leptos::*;
+/// # use leptos_struct_table::*;
+/// #
+/// #[derive(TableRow, Clone)]
+/// pub struct Person {
+///     name: String,
+///     age: u32,
+/// }
+///
+/// impl TableRowExport for Person {
+///     fn col_value(&self, col_index: usize) -> Option<String> {
+///         match col_index {
+///             0 => Some(self.name.clone()),
+///             _ => Some(self.age.to_string()),
+///         }
+///     }
+/// }
+///
+/// let john = Person { name: "John".to_string(), age: 32 };
+///
+/// assert!(row_matches_search(&john, "JOH"));
+/// assert!(row_matches_search(&john, "32"));
+/// assert!(!row_matches_search(&john, "jane"));
+/// ```
+pub fn row_matches_search<Row: TableRowExport>(row: &Row, search: &str) -> bool {
+    let search = search.to_lowercase();
+
+    search.is_empty()
+        || (0..Row::column_count()).any(|col_index| {
+            row.col_value(col_index)
+                .is_some_and(|value| value.to_lowercase().contains(&search))
+        })
+}
+
+/// Data provider for local data that can be filtered through the filter row of [`TableContent`].
+///
+/// It wraps a `Vec<Row>` that implements [`TableDataProvider`], e.g. through
+/// `#[table(impl_vec_data_provider)]`, and keeps only the rows that match the filters
+/// (see [`row_matches_filters`]) and the search (see [`row_matches_search`]). Sorting is delegated to the wrapped `Vec`. The rows are handed to
+/// the table in chunks of [`PaginatedTableDataProvider::PAGE_ROW_COUNT`] rows.
+///
+/// Columns that need a custom order can be given a comparator with [`FilteredVecDataProvider::sort_with`].
+///
+/// ```
+/// # use leptos::*;
+/// # use leptos_struct_table::*;
+/// #
+/// #[derive(TableRow, Clone)]
+/// #[table(sortable, impl_vec_data_provider)]
+/// pub struct Person {
+///     name: String,
+///     age: u32,
+/// }
+///
+/// impl TableRowExport for Person {
+///     fn col_value(&self, col_index: usize) -> Option<String> {
+///         match col_index {
+///             0 => Some(self.name.clone()),
+///             _ => Some(self.age.to_string()),
+///         }
+///     }
+/// }
+///
+/// #[component]
+/// fn App() -> impl IntoView {
+///     let rows = FilteredVecDataProvider::new(vec![
+///         Person { name: "John".to_string(), age: 32 },
+///         Person { name: "Jane".to_string(), age: 28 },
+///     ]);
+///
+///     view! {
+///         <table>
+///             <TableContent rows filterable=true />
+///         </table>
+///     }
+/// }
+/// ```
+pub struct FilteredVecDataProvider<Row> {
+    rows: Vec<Row>,
+    filtered_rows: Vec<Row>,
+    filters: HashMap<usize, String>,
+    search: String,
+    sorting: VecDeque<(usize, ColumnSort)>,
+    comparators: HashMap<usize, RowComparator<Row>>,
+    is_spanning_row: Option<SpanningRowClosure<Row>>,
+}
+
+impl<Row> FilteredVecDataProvider<Row>
+where
+    Row: TableRowExport,
+{
+    /// Creates a new data provider that doesn't filter any rows yet.
+    pub fn new(rows: Vec<Row>) -> Self {
+        Self {
+            filtered_rows: rows.clone(),
+            rows,
+            filters: HashMap::new(),
+            search: String::new(),
+            sorting: VecDeque::new(),
+            comparators: HashMap::new(),
+            is_spanning_row: None,
+        }
+    }
+
+    /// Sorts the column `col_index` with `cmp` instead of the generated `PartialOrd` comparison,
+    /// e.g. to sort version strings semantically. See [`sort_rows_with`].
+    pub fn sort_with(mut self, col_index: usize, cmp: RowComparator<Row>) -> Self {
+        self.comparators.insert(col_index, cmp);
+        self
+    }
+
+    /// Marks the rows for which `is_spanning_row` returns `true` as rows that aren't data rows,
+    /// e.g. group headers rendered with [`render_spanning_cell`]. They are skipped while the table is
+    /// sorted, filtered or searched and shown at their original position otherwise.
+    ///
+    /// With spanning rows the rows are sorted after filtering, so removing the sorting restores
+    /// their original order.
+    pub fn spanning_rows(mut self, is_spanning_row: impl Fn(&Row) -> bool + 'static) -> Self {
+        self.is_spanning_row = Some(Rc::new(is_spanning_row));
+        self.apply_filters();
+        self
+    }
+
+    /// The rows that match the current filters and search in the current sort order.
+    pub fn filtered_rows(&self) -> &[Row] {
+        &self.filtered_rows
+    }
+
+    fn apply_filters(&mut self) {
+        let is_data_view = !self.filters.is_empty()
+            || !self.search.is_empty()
+            || self
+                .sorting
+                .iter()
+                .any(|(_, sort)| *sort != ColumnSort::None);
+
+        self.filtered_rows = self
+            .rows
+            .iter()
+            .filter(|row| match &self.is_spanning_row {
+                Some(is_spanning_row) if is_spanning_row(row) => !is_data_view,
+                _ => {
+                    row_matches_filters(*row, &self.filters)
+                        && row_matches_search(*row, &self.search)
+                }
+            })
+            .cloned()
+            .collect();
+    }
+}
+
+impl<Row> FilteredVecDataProvider<Row>
+where
+    Row: TableRowExport,
+    Vec<Row>: TableDataProvider<Row>,
+{
+    fn update_filtered_rows(&mut self) {
+        self.apply_filters();
+
+        // the original order of the rows is kept so that the spanning rows can be shown again
+        if self.is_spanning_row.is_some() {
+            sort_rows_with(&mut self.filtered_rows, &self.sorting, &self.comparators);
+        }
+    }
+}
+
+impl<Row> From<Vec<Row>> for FilteredVecDataProvider<Row>
+where
+    Row: TableRowExport,
+{
+    fn from(rows: Vec<Row>) -> Self {
+        Self::new(rows)
+    }
+}
+
+// This is implemented as a paginated data provider because a direct implementation of
+// `TableDataProvider` would conflict with the blanket implementation for paginated ones.
+impl<Row> PaginatedTableDataProvider<Row> for FilteredVecDataProvider<Row>
+where
+    Row: TableRowExport,
+    Vec<Row>: TableDataProvider<Row>,
+{
+    const PAGE_ROW_COUNT: usize = 50;
+
+    async fn get_page(&self, page_index: usize) -> Result<Vec<Row>, String> {
+        Ok(self
+            .filtered_rows
+            .iter()
+            .skip(page_index * Self::PAGE_ROW_COUNT)
+            .take(Self::PAGE_ROW_COUNT)
+            .cloned()
+            .collect())
+    }
+
+    async fn row_count(&self) -> Option<usize> {
+        Some(self.filtered_rows.len())
+    }
+
+    fn set_sorting(&mut self, sorting: &VecDeque<(usize, ColumnSort)>) {
+        self.sorting = sorting.clone();
+
+        if self.is_spanning_row.is_none() {
+            sort_rows_with(&mut self.rows, sorting, &self.comparators);
+        }
+        self.update_filtered_rows();
+    }
+
+    fn set_filters(&mut self, filters: &HashMap<usize, String>) {
+        self.filters = filters.clone();
+        self.update_filtered_rows();
+    }
+
+    fn set_search(&mut self, search: &str) {
+        self.search = search.to_string();
+        self.update_filtered_rows();
+    }
+}
@@ -0,0 +1,208 @@
+use crate::{ChangeEvent, ColumnSort, EventHandler, TableHeadEvent, TableRow};
+use leptos::*;
+use std::collections::VecDeque;
+
+/// Provided as context while the head row of a flattened struct is rendered so that
+/// [`DefaultTableHeaderCellRenderer`] can put the prefix in front of the titles.
+#[derive(Clone, Copy)]
+pub(crate) struct FlattenedTitlePrefix(pub &'static str);
+
+/// Renders the cells of the `nested` struct that is embedded in `row` as if its fields were fields of `row`.
+/// Use this in [`TableRow::render_row`] of the embedding struct where the nested columns should appear.
+///
+/// * `col_offset` - The column index of the first nested column in the embedding struct
+/// * `set_nested` - Puts an edited nested value back into a clone of `row`
+///
+/// Change events of the nested cells are turned into change events of `row` with the column indices
+/// shifted by `col_offset`, so the column indices stay contiguous across the flattened struct.
+///
+/// ```
+/// # use leptos::*;
+/// # use leptos_struct_table::*;
+/// # use std::collections::VecDeque;
+/// #
+/// #[derive(TableRow, Clone)]
+/// pub struct Address {
+///     street: String,
+///     city: String,
+/// }
+///
+/// #[derive(Clone)]
+/// pub struct Person {
+///     name: String,
+///     address: Address,
+/// }
+///
+/// impl TableRow for Person {
+///     type ClassesProvider = DummyTableClassesProvider;
+///
+///     // `name` + the columns of `Address`
+///     const COLUMN_COUNT: usize = 1 + Address::COLUMN_COUNT;
+///
+///     fn render_row(&self, index: usize, on_change: EventHandler<ChangeEvent<Self>>) -> impl IntoView {
+///         view! {
+///             <DefaultTableCellRenderer
+///                 class=String::new()
+///                 value=self.name.clone()
+///                 index=0
+///                 on_change=|_| {}
+///             />
+///             {render_flattened_row(
+///                 self,
+///                 self.address.clone(),
+///                 index,
+///                 1,
+///                 on_change,
+///                 |person: &mut Person, address| person.address = address,
+///             )}
+///         }
+///     }
+///
+///     fn render_head_row<F>(
+///         sorting: Signal<VecDeque<(usize, ColumnSort)>>,
+///         on_head_click: F,
+///     ) -> impl IntoView
+///     where
+///         F: Fn(TableHeadEvent) + Clone + 'static,
+///     {
+///         view! {
+///             <DefaultTableHeaderCellRenderer
+///                 class=Signal::derive(String::new)
+///                 inner_class=String::new()
+///                 index=0
+///                 sort_priority=Signal::derive(|| None)
+///                 sort_direction=Signal::derive(move || get_sorting_for_column(0, sorting))
+///                 on_click=on_head_click.clone()
+///             >
+///                 "Name"
+///             </DefaultTableHeaderCellRenderer>
+///             {render_flattened_head_row::<Address, _>(sorting, on_head_click, 1, "Address ")}
+///         }
+///     }
+///
+///     fn col_name(col_index: usize) -> &'static str {
+///         match col_index {
+///             0 => "name",
+///             col_index => Address::col_name(col_index - 1),
+///         }
+///     }
+/// }
+///
+/// assert_eq!(Person::COLUMN_COUNT, 3);
+/// assert_eq!(Person::col_name(2), "city");
+/// ```
+///
+/// [`TableContent`] flattens the rendered cells again, so reordering columns, sticky columns and the
+/// selection checkboxes work with the contiguous column indices.
+///
+/// The nested struct mustn't contain a [`FieldGetter`] that (directly or indirectly) reads from the
+/// embedding struct since that would create a cycle.
+pub fn render_flattened_row<Row, Nested>(
+    row: &Row,
+    nested: Nested,
+    index: usize,
+    col_offset: usize,
+    on_change: EventHandler<ChangeEvent<Row>>,
+    set_nested: impl Fn(&mut Row, Nested) + 'static,
+) -> impl IntoView
+where
+    Row: TableRow + Clone + 'static,
+    Nested: TableRow + Clone + 'static,
+{
+    let row = row.clone();
+
+    let on_nested_change = move |evt: ChangeEvent<Nested>| {
+        let mut changed_row = row.clone();
+        set_nested(&mut changed_row, evt.changed_row);
+
+        on_change.run(ChangeEvent {
+            row_index: evt.row_index,
+            col_index: evt.col_index + col_offset,
+            changed_row,
+        });
+    };
+
+    nested
+        .render_row(index, on_nested_change.into())
+        .into_view()
+}
+
+/// Renders the head cells of the flattened struct `Nested`. Use this in [`TableRow::render_head_row`]
+/// of the embedding struct together with [`render_flattened_row`].
+///
+/// * `col_offset` - The column index of the first nested column in the embedding struct
+/// * `title_prefix` - Put in front of the titles of the nested columns by [`DefaultTableHeaderCellRenderer`],
+///   e.g. `"Address "`. Use `""` for no prefix.
+///
+/// `sorting` and the emitted [`TableHeadEvent`]s use the column indices of the embedding struct.
+pub fn render_flattened_head_row<Nested, F>(
+    sorting: Signal<VecDeque<(usize, ColumnSort)>>,
+    on_head_click: F,
+    col_offset: usize,
+    title_prefix: &'static str,
+) -> impl IntoView
+where
+    Nested: TableRow,
+    F: Fn(TableHeadEvent) + Clone + 'static,
+{
+    let col_count = Nested::column_count();
+
+    // Columns outside of the nested struct are mapped to an index that no nested column has
+    // so the sort priorities of the nested columns stay the same.
+    let nested_sorting = Signal::derive(move || {
+        sorting.with(|sorting| {
+            sorting
+                .iter()
+                .map(|(col_index, sort)| {
+                    let nested_index = col_index
+                        .checked_sub(col_offset)
+                        .filter(|nested_index| *nested_index < col_count)
+                        .unwrap_or(usize::MAX);
+
+                    (nested_index, *sort)
+                })
+                .collect()
+        })
+    });
+
+    let on_nested_head_click = move |evt: TableHeadEvent| {
+        on_head_click(TableHeadEvent {
+            index: evt.index + col_offset,
+            mouse_event: evt.mouse_event,
+        })
+    };
+
+    run_as_child(move || {
+        provide_context(FlattenedTitlePrefix(title_prefix));
+
+        Nested::render_head_row(nested_sorting, on_nested_head_click).into_view()
+    })
+}
+
+/// Expands the cells of flattened structs (see [`render_flattened_row`]) in `cells` which is one
+/// rendered row so that there is one node per column again. Every cell renders a single root
+/// element, so nested nodes with several children are the cells of a flattened struct.
+pub(crate) fn flatten_cells(cells: View, col_count: usize) -> View {
+    match cells {
+        View::Component(mut fragment) if fragment.children.len() < col_count => {
+            std::mem::take(&mut fragment.children)
+                .into_iter()
+                .flat_map(expand_cells)
+                .collect::<Fragment>()
+                .into_view()
+        }
+        cells => cells,
+    }
+}
+
+fn expand_cells(view: View) -> Vec<View> {
+    match view {
+        View::Component(mut nested) if nested.children.len() > 1 => {
+            std::mem::take(&mut nested.children)
+                .into_iter()
+                .flat_map(expand_cells)
+                .collect()
+        }
+        view => vec![view],
+    }
+}
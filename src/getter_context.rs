@@ -0,0 +1,106 @@
+use leptos::*;
+use std::any::Any;
+use std::rc::Rc;
+
+/// Data that getters need besides the row itself, e.g. a lookup table or the current user.
+/// Pass it to the `getter_context` prop of [`TableContent`] and read it with [`use_getter_context`]
+/// inside of `getter` methods and [`FieldGetter`] methods.
+///
+/// Create it with [`GetterContext::new`] or convert a signal into it. When the signal changes, the
+/// cells of the rendered rows are rendered again so the getters see the new value. The rows aren't
+/// loaded again.
+///
+/// ```
+/// # use leptos::*;
+/// # use leptos_struct_table::*;
+/// # use std::collections::HashMap;
+/// #
+/// #[derive(Clone)]
+/// pub struct Authors(HashMap<u32, String>);
+///
+/// #[derive(TableRow, Clone)]
+/// #[table(impl_vec_data_provider)]
+/// pub struct Book {
+///     title: String,
+///     #[table(skip)]
+///     author_id: u32,
+///     author: FieldGetter<String>,
+/// }
+///
+/// impl Book {
+///     pub fn author(&self) -> String {
+///         use_getter_context::<Authors>()
+///             .and_then(|authors| authors.0.get(&self.author_id).cloned())
+///             .unwrap_or_default()
+///     }
+/// }
+///
+/// #[component]
+/// fn App() -> impl IntoView {
+///     let authors = create_rw_signal(Authors(HashMap::from([(1, "Douglas Adams".to_string())])));
+///     let rows = vec![Book {
+///         title: "The Hitchhiker's Guide to the Galaxy".to_string(),
+///         author_id: 1,
+///         author: Default::default(),
+///     }];
+///
+///     view! {
+///         <table>
+///             <TableContent rows getter_context=authors />
+///         </table>
+///     }
+/// }
+/// ```
+#[derive(Copy, Clone)]
+pub struct GetterContext(Signal<Rc<dyn Any>>);
+
+impl GetterContext {
+    /// Creates a context that doesn't change. Getters read it with `use_getter_context::<C>()`.
+    /// Signals of `C` can be converted into a context with `into()` and re-render the cells on change.
+    pub fn new<C: Clone + 'static>(context: C) -> Self {
+        let context = Rc::new(context) as Rc<dyn Any>;
+
+        Self(Signal::derive(move || Rc::clone(&context)))
+    }
+
+    pub(crate) fn track(&self) {
+        self.0.track();
+    }
+}
+
+impl<C: Clone + 'static> From<Signal<C>> for GetterContext {
+    fn from(context: Signal<C>) -> Self {
+        Self(Signal::derive(move || {
+            Rc::new(context.get()) as Rc<dyn Any>
+        }))
+    }
+}
+
+impl<C: Clone + 'static> From<ReadSignal<C>> for GetterContext {
+    fn from(context: ReadSignal<C>) -> Self {
+        Signal::from(context).into()
+    }
+}
+
+impl<C: Clone + 'static> From<RwSignal<C>> for GetterContext {
+    fn from(context: RwSignal<C>) -> Self {
+        Signal::from(context).into()
+    }
+}
+
+impl<C: Clone + 'static> From<Memo<C>> for GetterContext {
+    fn from(context: Memo<C>) -> Self {
+        Signal::from(context).into()
+    }
+}
+
+/// Returns the context of type `C` that has been passed to the `getter_context` prop of the
+/// surrounding [`TableContent`]. This is meant to be called inside of getters and cell renderers.
+///
+/// Returns `None` outside of a [`TableContent`], if it has no `getter_context` or if the context
+/// isn't of type `C`.
+pub fn use_getter_context<C: Clone + 'static>() -> Option<C> {
+    use_context::<GetterContext>()?
+        .0
+        .with_untracked(|context| context.downcast_ref::<C>().cloned())
+}
@@ -10,10 +10,14 @@
 //! - **Customization** - You can customize every aspect of the table by plugging in your own components for rendering rows, cells, headers. See [Custom Renderers](#custom-renderers) for more information.
 //! - **Headless** - No default styling is applied to the table. You can fully customize the classes that are applied to the table. See [Classes customization](#classes-customization) for more information.
 //! - **Sorting** - Optional. If turned on: Click on a column header to sort the table by that column. You can even sort by multiple columns.
+//!   Clicking another column makes it the primary one, clicking a sorted column cycles it through ascending, descending and unsorted.
+//!   The default header shows the priority of every sorted column next to the arrow.
 //! - **Virtualization** - Only the visible rows are rendered. This allows for very large tables.
 //! - **Pagination** - Instead of virtualization you can paginate the table.
 //! - **Caching** - Only visible rows are loaded and cached.
 //! - **Editing** - Optional. You can provide custom renderers for editable cells. See [Editable Cells](#editable-cells) for more information.
+//! - **Expandable rows** - Optional. Show the details of a row in a full-width row below it. See the [expandable example](https://github.com/Synphonyte/leptos-struct-table/blob/master/examples/expandable/src/main.rs).
+//! - **Live updates** - Optional. Push inserted, updated and deleted rows into the table, e.g. from a websocket, without losing the scroll position or the selection. See [`RowMutationController`].
 //!
 //! # Usage
 //!
@@ -60,6 +64,7 @@
 //! - **`head_cell_renderer`** - Specifies the name of the header cell renderer component. Used to customize the rendering of header cells. Defaults to [`DefaultTableHeaderRenderer`]. See the [custom_renderers_svg example](https://github.com/Synphonyte/leptos-struct-table/blob/master/examples/custom_renderers_svg/src/main.rs) for more information.
 //! - **`impl_vec_data_provider`** - If given, then [`TableDataProvider`] is automatically implemented for `Vec<ThisStruct>` to allow
 //!    for easy local data use. See the [simple example](https://github.com/synphonyte/leptos-struct-table/blob/master/examples/simple/src/main.rs) for more information.
+//!   Wrap a signal of such a `Vec` in [`SignalVecDataProvider`] to update the table when the data changes.
 //! - **`row_type`** - Specifies the type of the rows in the table. Defaults to the struct that this is applied to. See the [custom_type example](https://github.com/synphonyte/leptos-struct-table/blob/master/examples/custom_type/src/main.rs) for more information.
 //!
 //! ## Field attributes
@@ -71,6 +76,7 @@
 //! - **`cell_class`** - Specifies the classes that are applied to the body cells in the field's column. Can be used in conjuction with `classes_provider` to customize the classes.
 //! - **`skip`** - Specifies that the field should be skipped. This is useful for fields that are not displayed in the table.
 //! - **`skip_sort`** - Only applies if `sortable` is set on the struct. Specifies that the field should not be used for sorting. Clicking it's header will not do anything.
+//!   Without `sortable` it is silently ignored.
 //! - **`skip_header`** - Makes the title of the field not be displayed in the head row.
 //! - **`title`** - Specifies the title that is displayed in the header cell. Defaults to the field name converted to title case (`this_field` becomes `"This Field"`).
 //! - **`renderer`** - Specifies the name of the cell renderer component. Used to customize the rendering of cells.
@@ -78,16 +84,25 @@
 //!    As long as Leptos supports rendering the type it will work.
 //!    If the feature `chrono` is enabled then [`DefaultNaiveDateTableCellRenderer`], [`DefaultNaiveDateTimeTableCellRenderer`] and
 //!    [`DefaultNaiveTimeTableCellRenderer`] are used for [`chrono::NaiveDate`], [`chrono::NaiveDateTime`] and [`chrono::NaiveTime`] respectively.
+//!   For URLs and email addresses use [`DefaultUrlTableCellRenderer`] and [`DefaultEmailTableCellRenderer`] which render them as links.
+//!   Status enums (or any other `Display` type) can be rendered as badges with a class per value by [`DefaultEnumTableCellRenderer`].
+//!   Completion values can be rendered as progress bars by [`DefaultProgressTableCellRenderer`].
+//!   `Vec<String>` fields are joined with `", "` by default. Other collections like tags or labels can be rendered with [`DefaultVecTableCellRenderer`].
 //!  - **`format`** - Quick way to customize the formatting of cells without having to create a custom renderer. See [Formatting](#formatting) below for more information.
 //! - **`getter`** - Specifies a method that returns the value of the field instead of accessing the field directly when rendering.
 //! - **`none_value`** - Specifies a display value for `Option` types when they are `None`. Defaults to empty string
+//!   To also style empty cells differently, use [`OptionTableCellRenderer`] as the `renderer`.
 //!
 //! ### Formatting
 //!
 //! The `format` attribute can be used to customize the formatting of cells. It is an easier alternative to creating a custom renderer when you just want to customize some basic formatting.
 //!
 //! - **`precision`** - Specifies the number of digits to display after the decimal point. Only works for numbers.
-//! - **`string`** - Specifies a format string. Currently only used for `NaiveDate`, `NaiveDateTime` and `NaiveTime`. See [`chrono::format::strftime`] for more information.
+//!   On other types it fails to compile because their cell renderer has no `precision` prop.
+//!   To group the digits or to add a currency symbol or unit, set the `thousands_separator`, `prefix` and `suffix` props of
+//!   [`DefaultNumberTableCellRenderer`] in a wrapping renderer (see [`NumberFormat`]).
+//! - **`string`** - Specifies a format string. Currently only used for `NaiveDate`, `NaiveDateTime` and `NaiveTime`. See [`chrono::format::strftime`] for more information. With the feature `chrono-locales`
+//!   month and day names are localized in the locale of a `DateLocale` provided as context.
 
 #![cfg_attr(
     feature = "chrono",
@@ -114,7 +129,8 @@ pub struct TemperatureMeasurement {
 //!
 //! Classes can be easily customized by using the `classes_provider` attribute on the struct.
 //! You can specify any type that implementats the trait [`TableClassesProvider`]. Please see the documentation for that trait for more information.
-//! You can also look at [`TailwindClassesPreset`] for an example how this can be implemented.
+//! There are presets for [Tailwind](https://tailwindcss.com/) ([`TailwindClassesPreset`]), [Bootstrap](https://getbootstrap.com/)
+//! ([`BootstrapClassesPreset`]) and [Bulma](https://bulma.io/) ([`BulmaClassesPreset`]). You can also look at them for an example how this can be implemented.
 //!
 //! Example:
 //!
@@ -130,6 +146,10 @@ pub struct TemperatureMeasurement {
 //! }
 //! ```
 //!
+//! To add classes to rows depending on their data, e.g. to color rows by their status, pass a closure
+//! or a method with the signature `fn(&self, index: usize) -> String` to the `row_class_fn` prop of
+//! [`TableContent`]. See the [row_class example](https://github.com/Synphonyte/leptos-struct-table/blob/master/examples/row_class/src/main.rs).
+//!
 //! # Field Getters
 //!
 //! Sometimes you want to display a field that is not part of the struct but a derived value either
@@ -192,6 +212,75 @@ pub struct TemperatureMeasurement {
 //! The `getter` attribute should be used on a field that actually exists on the struct but whose
 //! value you want to modify before it's rendered.
 //!
+//! Don't combine both on the same field. A `getter` attribute on a `FieldGetter<T>` field replaces the
+//! method with the same name as the field, which is easy to overlook.
+//!
+//! ## Sorting by getters
+//!
+//! In `sortable` structs [`FieldGetter`] columns are sorted like any other column unless they have
+//! `skip_sort`. The data providers of `impl_vec_data_provider` (and with them [`FilteredVecDataProvider`]
+//! and [`SignalVecDataProvider`]) compare the values returned by the getter method. It's only called
+//! while the rows are sorted by that column, and the sort is stable, so rows with the same value keep
+//! their relative order. If the getter is expensive, compute the key once per row with
+//! [`FilteredVecDataProvider::sort_with`] instead.
+//!
+//! Server-side data providers can't sort by a column that doesn't exist in their data source.
+//! [`TableRow::sorting_to_sql`] would put the field name into the `ORDER BY` clause, so either add
+//! `skip_sort` to such fields or map the column index to an expression in your
+//! [`TableDataProvider::set_sorting`] implementation.
+//!
+//! ```
+//! # use leptos::*;
+//! # use leptos_struct_table::*;
+//! # use std::collections::VecDeque;
+//! #
+//! #[derive(TableRow, Clone)]
+//! #[table(sortable, impl_vec_data_provider)]
+//! pub struct Book {
+//!     title: String,
+//!     author: String,
+//!     title_and_author: FieldGetter<String>,
+//! }
+//!
+//! impl Book {
+//!     pub fn title_and_author(&self) -> String {
+//!         format!("{} by {}", self.title, self.author)
+//!     }
+//! }
+//!
+//! let book = |title: &str, author: &str| Book {
+//!     title: title.to_string(),
+//!     author: author.to_string(),
+//!     title_and_author: FieldGetter::default(),
+//! };
+//!
+//! let mut rows = vec![
+//!     book("Dune", "Frank Herbert"),
+//!     book("Dune", "Brian Herbert"),
+//!     book("Contact", "Carl Sagan"),
+//! ];
+//!
+//! rows.set_sorting(&VecDeque::from([(2, ColumnSort::Ascending)]));
+//!
+//! assert_eq!(
+//!     rows.iter().map(Book::title_and_author).collect::<Vec<_>>(),
+//!     ["Contact by Carl Sagan", "Dune by Brian Herbert", "Dune by Frank Herbert"],
+//! );
+//! ```
+//!
+//! ## Async getters
+//!
+//! If the value of a column has to be loaded asynchronously, e.g. to resolve a foreign key to a name,
+//! let the getter return an [`AsyncCellValue`]. The cell shows a loading view until the future resolves
+//! and an error view if it fails. The loading view only replaces the content of this cell, so every
+//! column can have its own placeholder while the rest of the row is already shown.
+//!
+//! ## Getter context
+
+//! If a getter needs data that isn't part of the row, e.g. a lookup table or the current user, pass it
+//! to the `getter_context` prop of [`TableContent`] as a [`GetterContext`] and read it in the getter with
+//! [`use_getter_context`]. If the context is a signal, the cells are rendered again when it changes.
+//!
 //! # Custom Renderers
 //!
 //! Custom renderers can be used to customize almost every aspect of the table.
@@ -214,9 +303,16 @@ pub struct TemperatureMeasurement {
 //! On the field level you can use the **`renderer`** attribute.
 //!
 //! It defaults to [`DefaultNumberTableCellRenderer`] for number types and [`DefaultTableCellRenderer`] for anything else.
+//! `bool`s are rendered as disabled checkboxes by [`DefaultBoolTableCellRenderer`].
 //! As long as Leptos supports rendering the type it will work.
 //! If the feature `chrono` is enabled then [`DefaultNaiveDateTableCellRenderer`], [`DefaultNaiveDateTimeTableCellRenderer`] and
 //! [`DefaultNaiveTimeTableCellRenderer`] are used for [`chrono::NaiveDate`], [`chrono::NaiveDateTime`] and [`chrono::NaiveTime`] respectively.
+//! Durations can't be rendered by Leptos, so set the `renderer` of `Duration` fields to [`DefaultDurationTableCellRenderer`].
+//!
+//! To use the same renderer for all fields of a custom type (e.g. `Money`) without adding the `renderer` attribute everywhere,
+//! register it once with [`TypeCellRenderers`]. See its docs for how this resolves against explicit `renderer` attributes.
+//! To choose the renderer of a column at runtime, e.g. depending on a density setting, pass [`ColumnCellRenderers`]
+//! to the `cell_renderers` prop of [`TableContent`]. They take precedence over the derived renderers.
 //!
 //! Example:
 //!
@@ -250,6 +346,68 @@ pub struct TemperatureMeasurement {
 //! }
 //! ```
 //!
+//! Only the props `class`, `value`, `on_change` and `index` (and `precision` with the `format` attribute) are passed
+//! to a cell renderer. To pass additional constant props like an icon name per column, add them to a generic renderer and
+//! set them in a thin wrapper per column. Since the wrapper only forwards the props, a mismatching prop type is a
+//! compile error in the wrapper.
+//!
+//! ```
+//! # use leptos::*;
+//! # use leptos_struct_table::*;
+//! #
+//! #[derive(TableRow, Clone)]
+//! pub struct Review {
+//!     title: String,
+//!     #[table(renderer = "StarsCellRenderer")]
+//!     rating: u8,
+//!     #[table(renderer = "HeartsCellRenderer")]
+//!     likes: u8,
+//! }
+//!
+//! // Renders `value` times the `icon`.
+//! #[component]
+//! fn IconCountCellRenderer<F>(
+//!     class: String,
+//!     #[prop(into)] value: MaybeSignal<u8>,
+//!     on_change: F,
+//!     index: usize,
+//!     #[prop(into)] icon: String,
+//! ) -> impl IntoView
+//! where
+//!     F: Fn(u8) + 'static,
+//! {
+//!     view! {
+//!         <td class=class>{move || icon.repeat(value.get() as usize)}</td>
+//!     }
+//! }
+//!
+//! #[component]
+//! fn StarsCellRenderer<F>(
+//!     class: String,
+//!     #[prop(into)] value: MaybeSignal<u8>,
+//!     on_change: F,
+//!     index: usize,
+//! ) -> impl IntoView
+//! where
+//!     F: Fn(u8) + 'static,
+//! {
+//!     view! { <IconCountCellRenderer class value on_change index icon="★" /> }
+//! }
+//!
+//! #[component]
+//! fn HeartsCellRenderer<F>(
+//!     class: String,
+//!     #[prop(into)] value: MaybeSignal<u8>,
+//!     on_change: F,
+//!     index: usize,
+//! ) -> impl IntoView
+//! where
+//!     F: Fn(u8) + 'static,
+//! {
+//!     view! { <IconCountCellRenderer class value on_change index icon="♥" /> }
+//! }
+//! ```
+//!
 //! For more detailed information please have a look at the [custom_renderers_svg example](https://github.com/synphonyte/leptos-struct-table/blob/master/examples/custom_renderers_svg/src/main.rs) for a complete customization.
 //!
 //!
@@ -307,6 +465,38 @@ pub struct TemperatureMeasurement {
 //! }
 //! ```
 //!
+//! For common field types there are built-in editable cell renderers so you don't have to write your own:
+//! [`TextInputTableCellRenderer`], [`NumberInputTableCellRenderer`], [`CheckboxInputTableCellRenderer`]
+//! and, with the feature `chrono`, [`NaiveDateInputTableCellRenderer`]. Fields with a fixed set of values
+//! that implement [`SelectOptions`] can be edited with a `<select>` through [`SelectTableCellRenderer`].
+//!
+//! ```
+//! # use leptos::*;
+//! # use leptos_struct_table::*;
+//! #
+//! #[derive(TableRow, Clone, Default, Debug)]
+//! #[table(impl_vec_data_provider)]
+//! pub struct Book {
+//!     #[table(renderer = "TextInputTableCellRenderer")]
+//!     title: String,
+//!     #[table(renderer = "NumberInputTableCellRenderer")]
+//!     pages: u32,
+//!     #[table(renderer = "CheckboxInputTableCellRenderer")]
+//!     available: bool,
+//! }
+//! ```
+//!
+//! To open a specific cell for editing from somewhere else in your app you can pass a [`TableController`]
+//! to the `table_controller` prop and call [`TableController::edit_cell`]. Inside your cell renderer
+//! [`use_cell_editing`] tells you when that cell should e.g. focus its input.
+//!
+//! If your cell renderers emit changes on every keystroke, set the `change_debounce_ms` prop to call `on_change` only
+//! after the user paused typing. The latest value is emitted as soon as the focus leaves the cell.
+//!
+//! To validate while the user is typing but save only committed values, handle `on_cell_input` in addition to `on_change`.
+//! The text and number input renderers emit it on every keystroke and `on_change` on blur or Enter. Custom renderers emit it
+//! with the callback returned by [`use_cell_input`].
+//!
 //! Please have a look at the [editable example](https://github.com/Synphonyte/leptos-struct-table/tree/master/examples/editable/src/main.rs) for fully working example.
 //!
 //! # Pagination / Virtualization / InfiniteScroll
@@ -317,10 +507,147 @@ pub struct TemperatureMeasurement {
 //! The following options are available. Check their docs for more details.
 //! - [`DisplayStrategy::Virtualization`] (default)
 //! - [`DisplayStrategy::InfiniteScroll`]
+//! - [`DisplayStrategy::LoadMore`]
 //! - [`DisplayStrategy::Pagination`]
 //!
+//! With [`PageSize::Auto`] the number of rows per page adapts to the height of the scroll container.
+//!
 //! Please have a look at the [pagination example](https://github.com/Synphonyte/leptos-struct-table/tree/master/examples/pagination/src/main.rs) for more information on how to use pagination.
 //!
+//! # Server-side Data
+//!
+//! When the rows live in a database, [`SqlQuery`] builds the `SELECT ... ORDER BY ... LIMIT ... OFFSET` and
+//! `SELECT COUNT(*)` statements for a page of rows from the requested range and sorting. The column names are taken
+//! from the struct, so the sorting sent by the client can't inject SQL.
+//!
+//! Please have a look at the [serverfn_sqlx example](https://github.com/Synphonyte/leptos-struct-table/tree/master/examples/serverfn_sqlx/src/data_provider.rs) for a data provider that uses `sqlx`.
+//!
+//! For JSON APIs that are paginated by offset and limit, enable the feature `rest` and use [`RestTableDataProvider`].
+//! It sends the range and the sorting as query parameters whose names can be configured and reports failed requests
+//! as [`RestError`]s. See the [rest example](https://github.com/Synphonyte/leptos-struct-table/tree/master/examples/rest/src/main.rs).
+//!
+//! # Filtering
+//!
+//! Set the `filterable` prop of [`TableContent`] to render a row of filter inputs below the header row.
+//! The entered filters are passed to [`TableDataProvider::set_filters`] as a map from column index to filter text,
+//! so server backed data providers can turn them into a `WHERE` clause. For local data you can wrap your `Vec`
+//! in a [`FilteredVecDataProvider`] which matches the rows with [`row_matches_filters`].
+//! Both require the row type to implement [`TableRowExport`].
+//!
+//! To search all columns at once, pass the text of a search box to the `search` prop. It's passed to [`TableDataProvider::set_search`]
+//! and `on_search_change` is called, so it can be forwarded to a server. [`FilteredVecDataProvider`] matches the rows with [`row_matches_search`]
+//! in addition to the filters.
+//!
+//! Please have a look at the [filtering example](https://github.com/Synphonyte/leptos-struct-table/tree/master/examples/filtering/src/main.rs) for more information.
+//!
+//! # Column Order
+//!
+//! The `column_order` prop of [`TableContent`] defines in which order the columns are displayed. Pass a
+//! [`ColumnReorderController`] to the `column_reorder_controller` prop to let the user reorder them by dragging the header cells.
+//! Sorting and filtering keep referring to the column index of the struct field, independent of its position.
+//!
+//! Please have a look at the [column_reorder example](https://github.com/Synphonyte/leptos-struct-table/tree/master/examples/column_reorder/src/main.rs) for how to persist the order.
+//!
+//! # Row Order
+//!
+//! Set the `row_reorderable` prop of [`TableContent`] to let the user drag rows onto other rows. The table doesn't change
+//! its data itself but calls `on_row_move` with a [`RowMoveEvent`]. Move the row in your data accordingly, e.g. in the
+//! signal of a [`SignalVecDataProvider`]. Rows can't be dragged while the table is sorted by a column.
+//! With `row_drag_handle` rows are only dragged by a handle in a leading column.
+//!
+//! ```
+//! # use leptos::*;
+//! # use leptos_struct_table::*;
+//! #
+//! #[derive(TableRow, Clone)]
+//! #[table(impl_vec_data_provider)]
+//! pub struct Task {
+//!     title: String,
+//! }
+//!
+//! #[component]
+//! fn App() -> impl IntoView {
+//!     let tasks = create_rw_signal(vec![
+//!         Task { title: "Write the docs".to_string() },
+//!         Task { title: "Release".to_string() },
+//!     ]);
+//!
+//!     let on_row_move = move |evt: RowMoveEvent| {
+//!         tasks.update(|tasks| {
+//!             let task = tasks.remove(evt.from_index);
+//!             tasks.insert(evt.to_index, task);
+//!         })
+//!     };
+//!
+//!     view! {
+//!         <table>
+//!             <TableContent
+//!                 rows=SignalVecDataProvider::new(tasks)
+//!                 row_reorderable=true
+//!                 row_drag_handle=true
+//!                 on_row_move
+//!             />
+//!         </table>
+//!     }
+//! }
+//! ```
+//!
+//! # Column Visibility
+//!
+//! The `column_visibility` prop of [`TableContent`] hides columns at runtime, e.g. behind a column chooser or
+//! depending on the role of the user. [`column_visibility`] computes the initial value from the column names.
+//! Hidden columns keep their column index, so sorting, filtering, selection and the column order are not affected.
+//!
+//! Please have a look at the [column_visibility example](https://github.com/Synphonyte/leptos-struct-table/tree/master/examples/column_visibility/src/main.rs) for a column chooser.
+//!
+//! # Column Widths
+//!
+//! Pass a [`ColumnResizeController`] to the `column_resize_controller` prop of [`TableContent`] to let the user resize
+//! the columns by dragging a handle on the right edge of the header cells. The widths are applied to a `<colgroup>`
+//! and can be read and restored through [`ColumnResizeController::widths`], e.g. to persist them.
+//! Minimum and maximum widths are set per column on the controller.
+//!
+//! To give columns fixed widths up front, e.g. so the layout doesn't shift while the rows are loading, pass CSS widths
+//! to the `column_widths` prop. [`column_widths`] computes them from column names. Use `table-layout: fixed` on the table.
+//!
+//! Please have a look at the [column_resize example](https://github.com/Synphonyte/leptos-struct-table/tree/master/examples/column_resize/src/main.rs) for more information.
+//!
+//! To remember the column order, visibility and widths and the sorting across visits, enable the feature `persistence` and
+//! call [`use_table_persistence`] with the signals that you pass to [`TableContent`]. The state is saved to `localStorage` by column name.
+//!
+//! # Column Groups
+//!
+//! Pass [`ColumnGroup`]s to the `column_groups` prop of [`TableContent`] to render an extra head row in which consecutive
+//! columns of the same group share a header cell, e.g. "Q1" above the columns of January to March. The header cells of
+//! the columns stay as they are, so sorting still happens by clicking the single columns. The group header cells are
+//! rendered with the `column_group_header_cell_renderer` prop.
+//!
+//! # Aggregates
+//!
+//! Pass [`ColumnAggregates`] to the `aggregates` prop of [`TableContent`] to render a totals row in a `<tfoot>`. Every
+//! column can have an [`Aggregate`] (sum, average, minimum, maximum or count) over the values of the loaded rows.
+//! The aggregates are signals, so they can be displayed elsewhere as well. If not all rows are loaded, e.g. with a
+//! paginated data provider, set exact totals from the server with [`ColumnAggregates::set_total`].
+//! Inside of cell renderers [`use_running_total`] computes a running total up to the current row.
+//!
+//! # Accessibility
+//!
+//! The default renderers emit ARIA roles so the table stays accessible even if its layout is changed with CSS:
+//! `role="rowgroup"` on the head and body, `role="row"` on rows, `role="columnheader"` on head cells and
+//! `role="cell"` on body cells. Sortable head cells have an `aria-sort` attribute that follows the current
+//! [`ColumnSort`], body rows have `aria-selected` and the selection checkboxes have an `aria-label`.
+//! [`TableContent`] doesn't render the `<table>` itself, so add `role="table"` to the element that contains it
+//! if it isn't a `<table>`.
+//!
+//! If you replace a default renderer with a custom one, you're responsible for these attributes yourself:
+//! - `thead_renderer` and `tbody_renderer`: `role="rowgroup"`
+//! - `thead_row_renderer`, `row_renderer`, `loading_row_renderer` and `error_row_renderer`: `role="row"` and for
+//!   `row_renderer` `aria-selected`
+//! - head cell renderers: `role="columnheader"` and `aria-sort` (see [`ColumnSort::as_aria_sort`])
+//! - cell renderers: `role="cell"`
+//!
+//! Please have a look at the [flex_layout example](https://github.com/Synphonyte/leptos-struct-table/tree/master/examples/flex_layout/src/main.rs) for custom renderers that don't use table elements.
+//!
 //! # Contribution
 //!
 //! All contributions are welcome. Please open an issue or a pull request if you have any ideas or problems.
@@ -328,31 +655,94 @@ pub struct TemperatureMeasurement {
 #![allow(non_snake_case)]
 #![feature(doc_cfg)]
 
+mod aggregates;
+mod async_cell_value;
+mod cell_click;
+mod cell_tooltip;
 mod class_providers;
+mod column_groups;
+mod column_order;
+mod column_resize;
+mod column_virtualization;
 mod components;
 mod data_provider;
+mod direction;
 mod display_strategy;
+mod dynamic;
 mod events;
+mod expansion;
+mod export;
+mod filter;
+mod flatten;
+mod getter_context;
 mod loaded_rows;
+#[cfg(feature = "persistence")]
+mod persistence;
+mod provider_cell;
 mod reload_controller;
+#[cfg(feature = "rest")]
+mod rest;
+mod retry_policy;
+mod row_class;
+mod row_height;
+mod row_key;
+mod row_mutation;
+mod row_reorder;
+mod row_selectable;
 mod scroll_container;
 mod selection;
+mod signal_data_provider;
+mod spanning_rows;
+mod sql;
+mod sticky_columns;
+mod table_controller;
 mod table_row;
+mod table_state;
 #[cfg(feature = "uuid")]
 pub mod uuid;
 
+pub use aggregates::*;
+pub use async_cell_value::AsyncCellValue;
+pub use cell_tooltip::CellTooltipFn;
 pub use class_providers::*;
+pub use column_groups::ColumnGroup;
+pub use column_order::*;
+pub use column_resize::{column_widths, ColumnResizeController, DEFAULT_MIN_COLUMN_WIDTH};
+pub use column_virtualization::{DEFAULT_COLUMN_OVERSCAN, DEFAULT_ESTIMATED_COLUMN_WIDTH};
 pub use components::*;
 pub use data_provider::*;
+pub use direction::{use_table_direction, Direction};
 pub use display_strategy::*;
+pub use dynamic::*;
 pub use events::*;
+pub use export::*;
+pub use filter::*;
+pub use flatten::{render_flattened_head_row, render_flattened_row};
+pub use getter_context::{use_getter_context, GetterContext};
 pub use leptos_struct_table_macro::TableRow;
+pub use loaded_rows::{use_row_load_error, CachedRowRange, CachedRowsState};
+#[cfg(feature = "persistence")]
+pub use persistence::{use_table_persistence, PersistedTableState};
 pub use reload_controller::*;
+#[cfg(feature = "rest")]
+pub use rest::{RestError, RestTableDataProvider};
+pub use retry_policy::RetryPolicy;
+pub use row_class::RowClassFn;
+pub use row_height::RowHeightFn;
+pub use row_key::RowKeyFn;
+pub use row_mutation::{RowMutation, RowMutationController};
+pub use row_selectable::RowSelectableFn;
 pub use scroll_container::*;
 pub use selection::*;
 use serde::{Deserialize, Serialize};
+pub use signal_data_provider::SignalVecDataProvider;
+pub use spanning_rows::{render_spanning_cell, use_table_column_count};
+pub use sql::SqlQuery;
 use std::marker::PhantomData;
+pub use sticky_columns::{STICKY_CELL_CLASS, STICKY_RIGHT_CELL_CLASS};
+pub use table_controller::*;
 pub use table_row::*;
+pub use table_state::{use_table_state, TableState};
 
 /// Type of sorting of a column
 #[derive(Copy, Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
@@ -372,6 +762,15 @@ impl ColumnSort {
         }
     }
 
+    /// Returns the value for the `aria-sort` attribute of a column header.
+    pub fn as_aria_sort(&self) -> &'static str {
+        match self {
+            ColumnSort::Ascending => "ascending",
+            ColumnSort::Descending => "descending",
+            ColumnSort::None => "none",
+        }
+    }
+
     /// Returns the SQL sort order (ASC or DESC) or `None` if `ColumnSort::None`.
     pub fn as_sql(&self) -> Option<&'static str> {
         match self {
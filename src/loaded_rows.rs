@@ -1,11 +1,46 @@
+use leptos::*;
+use std::any::Any;
+use std::fmt::Debug;
 use std::ops::{Index, Range};
+use std::rc::Rc;
 
 #[derive(Clone)]
 pub enum RowState<T: Clone> {
     Placeholder,
     Loading,
     Loaded(T),
-    Error(String),
+    Error(RowError),
+}
+
+/// The error of rows that failed to load. Keeps the error of the data provider together with its
+/// `Debug` representation that is passed to the error row renderer.
+#[derive(Clone)]
+pub struct RowError {
+    pub message: String,
+    pub error: Rc<dyn Any>,
+}
+
+impl RowError {
+    pub fn new<Err: Debug + 'static>(error: Rc<Err>) -> Self {
+        Self {
+            message: format!("{error:?}"),
+            error,
+        }
+    }
+}
+
+/// Provided as context by [`TableContent`] while a row is rendered by the error row renderer.
+#[derive(Clone)]
+pub(crate) struct RowErrorContext(pub Rc<dyn Any>);
+
+/// The error that the data provider returned for the row that is rendered by the error row renderer
+/// (see the `error_row_renderer` prop of [`TableContent`]). Use this to show more than the message,
+/// e.g. a retry button only for errors that can be retried together with [`ReloadController::reload_row`].
+///
+/// `Err` has to be the error type of the data provider (see [`TableDataProvider`]). Returns `None`
+/// if it's a different type or if this isn't called inside of the error row renderer.
+pub fn use_row_load_error<Err: 'static>() -> Option<Rc<Err>> {
+    use_context::<RowErrorContext>()?.0.downcast::<Err>().ok()
 }
 
 impl<T: Clone> std::fmt::Debug for RowState<T> {
@@ -14,11 +49,30 @@ impl<T: Clone> std::fmt::Debug for RowState<T> {
             RowState::Placeholder => write!(f, "Placeholder"),
             RowState::Loading => write!(f, "Loading"),
             RowState::Loaded(_) => write!(f, "Loaded"),
-            RowState::Error(e) => write!(f, "Error({})", e),
+            RowState::Error(e) => write!(f, "Error({})", e.message),
         }
     }
 }
 
+/// The state of a range of rows in the cache of [`TableContent`]. See [`TableController::cached_rows`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum CachedRowsState {
+    /// The rows have been requested from the data provider and the request hasn't finished yet.
+    Loading,
+    /// The rows have been loaded.
+    Loaded,
+    /// The data provider returned an error for the rows.
+    Error,
+}
+
+/// A range of consecutive rows with the same state in the cache of [`TableContent`].
+/// Rows that haven't been requested (or have been evicted) aren't part of any range.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CachedRowRange {
+    pub range: Range<usize>,
+    pub state: CachedRowsState,
+}
+
 /// This is basically a cache for rows and used by [`TableContent`] internally to track
 /// which rows are already loaded, which are still loading and which are missing.
 pub struct LoadedRows<T: Clone> {
@@ -52,7 +106,7 @@ impl<T: Clone> LoadedRows<T> {
 
     pub fn write_loaded(
         &mut self,
-        loading_result: Result<(Vec<T>, Range<usize>), String>,
+        loading_result: Result<(Vec<T>, Range<usize>), RowError>,
         missing_range: Range<usize>,
     ) {
         match loading_result {
@@ -78,6 +132,25 @@ impl<T: Clone> LoadedRows<T> {
         }
     }
 
+    /// Replaces the row at `index` with its reloaded data. Returns `false` and leaves the row
+    /// unchanged if it isn't loaded (anymore) because it has been evicted or is loading again.
+    pub fn write_reloaded(&mut self, index: usize, row: T) -> bool {
+        match self.rows.get_mut(index) {
+            Some(self_row @ (RowState::Loaded(_) | RowState::Error(_))) => {
+                *self_row = RowState::Loaded(row);
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// The index of the first loaded row for which `predicate` returns `true`.
+    pub fn position_loaded(&self, predicate: impl Fn(&T) -> bool) -> Option<usize> {
+        self.rows
+            .iter()
+            .position(|row| matches!(row, RowState::Loaded(row) if predicate(row)))
+    }
+
     #[inline]
     pub fn missing_range(&self, range: Range<usize>) -> Option<Range<usize>> {
         let do_load_predicate = |row| matches!(row, &RowState::Placeholder);
@@ -93,6 +166,61 @@ impl<T: Clone> LoadedRows<T> {
         Some(start..end)
     }
 
+    /// Resets all loaded and errored rows outside of `range` so they are loaded again once they are
+    /// requested. Rows that are currently loading are kept so the pending request isn't repeated.
+    pub fn evict_outside(&mut self, range: Range<usize>) {
+        for (index, row) in self.rows.iter_mut().enumerate() {
+            if !range.contains(&index) && matches!(row, RowState::Loaded(_) | RowState::Error(_)) {
+                *row = RowState::Placeholder;
+            }
+        }
+    }
+
+    /// Resets the rows in `range` that are still loading so they are requested again. Used to
+    /// retry failed requests.
+    pub fn reset_loading(&mut self, range: Range<usize>) {
+        let range = range.start.min(self.rows.len())..range.end.min(self.rows.len());
+
+        for row in &mut self.rows[range] {
+            if matches!(row, RowState::Loading) {
+                *row = RowState::Placeholder;
+            }
+        }
+    }
+
+    /// Resets all rows that failed to load so they are requested again once they're displayed.
+    pub fn reset_errors(&mut self) {
+        for row in &mut self.rows {
+            if matches!(row, RowState::Error(_)) {
+                *row = RowState::Placeholder;
+            }
+        }
+    }
+
+    /// The ranges of the rows that are loading, loaded or failed to load, in ascending order.
+    pub fn cached_ranges(&self) -> Vec<CachedRowRange> {
+        let mut ranges: Vec<CachedRowRange> = vec![];
+
+        for (index, row) in self.rows.iter().enumerate() {
+            let state = match row {
+                RowState::Placeholder => continue,
+                RowState::Loading => CachedRowsState::Loading,
+                RowState::Loaded(_) => CachedRowsState::Loaded,
+                RowState::Error(_) => CachedRowsState::Error,
+            };
+
+            match ranges.last_mut() {
+                Some(last) if last.range.end == index && last.state == state => last.range.end += 1,
+                _ => ranges.push(CachedRowRange {
+                    range: index..index + 1,
+                    state,
+                }),
+            }
+        }
+
+        ranges
+    }
+
     #[inline]
     pub fn clear(&mut self) {
         self.rows.fill(RowState::Placeholder);
@@ -116,3 +244,85 @@ impl<T: Clone> Index<usize> for LoadedRows<T> {
         &self.rows[index]
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn states(loaded_rows: &LoadedRows<usize>) -> Vec<String> {
+        loaded_rows
+            .rows
+            .iter()
+            .map(|row| format!("{row:?}"))
+            .collect()
+    }
+
+    fn loaded(range: Range<usize>) -> Result<(Vec<usize>, Range<usize>), RowError> {
+        Ok((range.clone().collect(), range))
+    }
+
+    #[test]
+    fn overlapping_requests_are_coalesced() {
+        let mut loaded_rows = LoadedRows::<usize>::new();
+        loaded_rows.resize(30);
+
+        assert_eq!(loaded_rows.missing_range(0..10), Some(0..10));
+        loaded_rows.write_loading(0..10);
+
+        // only the rows that aren't loading yet are requested
+        assert_eq!(loaded_rows.missing_range(0..10), None);
+        assert_eq!(loaded_rows.missing_range(5..20), Some(10..20));
+        loaded_rows.write_loading(10..20);
+
+        loaded_rows.write_loaded(loaded(0..10), 0..10);
+        assert_eq!(loaded_rows.missing_range(0..20), None);
+        assert_eq!(loaded_rows.missing_range(0..30), Some(20..30));
+    }
+
+    #[test]
+    fn evicted_rows_are_missing_again() {
+        let mut loaded_rows = LoadedRows::<usize>::new();
+        loaded_rows.resize(10);
+        loaded_rows.write_loaded(loaded(0..6), 0..6);
+        loaded_rows.write_loading(6..8);
+        loaded_rows.write_loaded(Err(RowError::new(Rc::new("failed"))), 8..10);
+
+        loaded_rows.evict_outside(2..7);
+
+        assert_eq!(
+            states(&loaded_rows),
+            [
+                "Placeholder",
+                "Placeholder",
+                "Loaded",
+                "Loaded",
+                "Loaded",
+                "Loaded",
+                "Loading",
+                "Loading",
+                "Placeholder",
+                "Placeholder",
+            ]
+        );
+
+        // the evicted rows are requested again, the rows that are still loading aren't
+        assert_eq!(loaded_rows.missing_range(0..6), Some(0..2));
+        assert_eq!(loaded_rows.missing_range(6..10), Some(8..10));
+        assert_eq!(loaded_rows.missing_range(2..8), None);
+    }
+
+    #[test]
+    fn rows_that_are_loaded_again_after_eviction_are_cached() {
+        let mut loaded_rows = LoadedRows::<usize>::new();
+        loaded_rows.resize(20);
+        loaded_rows.write_loaded(loaded(0..20), 0..20);
+
+        loaded_rows.evict_outside(10..20);
+        assert_eq!(loaded_rows.missing_range(0..20), Some(0..10));
+
+        loaded_rows.write_loading(0..10);
+        loaded_rows.write_loaded(loaded(0..10), 0..10);
+        assert_eq!(loaded_rows.missing_range(0..20), None);
+        assert!(matches!(loaded_rows[3], RowState::Loaded(3)));
+    }
+}
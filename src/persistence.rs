@@ -0,0 +1,244 @@
+#![doc(cfg(feature = "persistence"))]
+
+use crate::{column_visibility, default_sorting, ColumnResizeController, ColumnSort, TableRow};
+use leptos::leptos_dom::is_browser;
+use leptos::*;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, VecDeque};
+
+/// The column order, visibility and widths and the sorting of a table as saved to `localStorage`
+/// by [`use_table_persistence`].
+///
+/// Columns are stored by their name (see [`TableRow::col_name`]) instead of their index, so the
+/// state survives changes of the struct. Columns that don't exist anymore are dropped and new
+/// columns are appended to the column order, visible, with their natural width and unsorted.
+///
+/// ```
+/// # use leptos::*;
+/// # use leptos_struct_table::*;
+/// # use std::collections::VecDeque;
+/// #
+/// #[derive(TableRow, Clone)]
+/// pub struct Employee {
+///     name: String,
+///     department: String,
+///     salary: f64,
+/// }
+///
+/// let state = PersistedTableState::capture::<Employee>(
+///     &[2, 0, 1],
+///     &[true, false, true],
+///     &[Some(120.0)],
+///     &VecDeque::from([(2, ColumnSort::Descending)]),
+/// );
+///
+/// assert_eq!(state.column_order, vec!["salary", "name", "department"]);
+/// assert_eq!(state.hidden_columns, vec!["department"]);
+///
+/// // the struct has changed in the meantime
+/// #[derive(TableRow, Clone)]
+/// pub struct EmployeeV2 {
+///     id: u32,
+///     name: String,
+///     salary: f64,
+/// }
+///
+/// assert_eq!(state.column_order_of::<EmployeeV2>(), vec![2, 1]);
+/// assert_eq!(state.column_visibility_of::<EmployeeV2>(), vec![true, true, true]);
+/// assert_eq!(state.column_widths_of::<EmployeeV2>(), vec![None, Some(120.0), None]);
+/// assert_eq!(state.sorting_of::<EmployeeV2>(), VecDeque::from([(2, ColumnSort::Descending)]));
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct PersistedTableState {
+    /// The names of the columns in display order.
+    pub column_order: Vec<String>,
+    /// The names of the hidden columns.
+    pub hidden_columns: Vec<String>,
+    /// The widths in pixels by column name of the columns that have been resized.
+    pub column_widths: BTreeMap<String, f64>,
+    /// The names of the sorted columns in order of priority.
+    pub sorting: Vec<(String, ColumnSort)>,
+}
+
+impl PersistedTableState {
+    /// Captures the state from the values of the `column_order`, `column_visibility` and `sorting`
+    /// props of [`TableContent`] and [`ColumnResizeController::widths`].
+    pub fn capture<Row: TableRow>(
+        column_order: &[usize],
+        column_visibility: &[bool],
+        column_widths: &[Option<f64>],
+        sorting: &VecDeque<(usize, ColumnSort)>,
+    ) -> Self {
+        let col_name = |col_index: usize| {
+            (col_index < Row::column_count()).then(|| Row::col_name(col_index).to_string())
+        };
+
+        Self {
+            column_order: column_order
+                .iter()
+                .filter_map(|col_index| col_name(*col_index))
+                .collect(),
+            hidden_columns: column_visibility
+                .iter()
+                .enumerate()
+                .filter(|(_, visible)| !**visible)
+                .filter_map(|(col_index, _)| col_name(col_index))
+                .collect(),
+            column_widths: column_widths
+                .iter()
+                .enumerate()
+                .filter_map(|(col_index, width)| Some((col_name(col_index)?, (*width)?)))
+                .collect(),
+            sorting: sorting
+                .iter()
+                .filter(|(_, col_sort)| *col_sort != ColumnSort::None)
+                .filter_map(|(col_index, col_sort)| Some((col_name(*col_index)?, *col_sort)))
+                .collect(),
+        }
+    }
+
+    /// The value of the `column_order` prop. Columns that aren't contained are appended by the table.
+    pub fn column_order_of<Row: TableRow>(&self) -> Vec<usize> {
+        self.column_order
+            .iter()
+            .filter_map(|name| col_index_of::<Row>(name))
+            .collect()
+    }
+
+    /// The value of the `column_visibility` prop.
+    pub fn column_visibility_of<Row: TableRow>(&self) -> Vec<bool> {
+        column_visibility::<Row>(|_, col_name| {
+            !self.hidden_columns.iter().any(|hidden| hidden == col_name)
+        })
+    }
+
+    /// The value of [`ColumnResizeController::widths`].
+    pub fn column_widths_of<Row: TableRow>(&self) -> Vec<Option<f64>> {
+        (0..Row::column_count())
+            .map(|col_index| self.column_widths.get(Row::col_name(col_index)).copied())
+            .collect()
+    }
+
+    /// The value of the `sorting` prop.
+    pub fn sorting_of<Row: TableRow>(&self) -> VecDeque<(usize, ColumnSort)> {
+        default_sorting::<Row>(
+            self.sorting
+                .iter()
+                .map(|(col_name, col_sort)| (col_name.as_str(), *col_sort)),
+        )
+    }
+
+    /// Reads the state that has been saved under `key`. Returns `None` if there is none or if it
+    /// can't be read, e.g. because `localStorage` isn't available.
+    pub fn load(key: &str) -> Option<Self> {
+        let json = local_storage()?.get_item(key).ok()??;
+
+        serde_json::from_str(&json).ok()
+    }
+
+    /// Saves the state under `key`. Errors, e.g. because the storage is full, are ignored.
+    pub fn save(&self, key: &str) {
+        if let (Some(storage), Ok(json)) = (local_storage(), serde_json::to_string(self)) {
+            let _ = storage.set_item(key, &json);
+        }
+    }
+}
+
+fn col_index_of<Row: TableRow>(col_name: &str) -> Option<usize> {
+    (0..Row::column_count()).find(|col_index| Row::col_name(*col_index) == col_name)
+}
+
+fn local_storage() -> Option<web_sys::Storage> {
+    window().local_storage().ok().flatten()
+}
+
+/// Saves the column order, the column visibility, the column widths and the sorting of a table to
+/// `localStorage` under `key` whenever they change and restores them right away. Pass the same
+/// signals and the controller to the props of [`TableContent`] afterwards. Since the state is
+/// restored before the table is created, the first rows are already loaded with the saved sorting.
+///
+/// Columns are stored by name, so the saved state survives added and removed fields
+/// (see [`PersistedTableState`]). Use a different key for every table.
+///
+/// This is only available when the **crate feature `persistence`** is enabled
+///
+/// ```
+/// # use leptos::*;
+/// # use leptos_struct_table::*;
+/// # use std::collections::VecDeque;
+/// #
+/// #[derive(TableRow, Clone)]
+/// #[table(sortable, impl_vec_data_provider)]
+/// pub struct Employee {
+///     name: String,
+///     department: String,
+///     salary: f64,
+/// }
+///
+/// #[component]
+/// fn App() -> impl IntoView {
+///     # let rows: Vec<Employee> = vec![];
+///     let sorting = create_rw_signal(VecDeque::new());
+///     let column_order = create_rw_signal(vec![]);
+///     let column_visibility = create_rw_signal(vec![]);
+///     let column_resize_controller = ColumnResizeController::default();
+///
+///     use_table_persistence::<Employee>(
+///         "employees-table",
+///         sorting,
+///         column_order,
+///         column_visibility,
+///         Some(column_resize_controller),
+///     );
+///
+///     view! {
+///         <table>
+///             <TableContent
+///                 rows
+///                 sorting
+///                 column_order
+///                 column_visibility
+///                 column_resize_controller
+///             />
+///         </table>
+///     }
+/// }
+/// ```
+pub fn use_table_persistence<Row: TableRow>(
+    key: impl Into<String>,
+    sorting: RwSignal<VecDeque<(usize, ColumnSort)>>,
+    column_order: RwSignal<Vec<usize>>,
+    column_visibility: RwSignal<Vec<bool>>,
+    column_resize_controller: Option<ColumnResizeController>,
+) {
+    if !is_browser() {
+        return;
+    }
+
+    let key = key.into();
+
+    if let Some(state) = PersistedTableState::load(&key) {
+        sorting.set(state.sorting_of::<Row>());
+        column_order.set(state.column_order_of::<Row>());
+        column_visibility.set(state.column_visibility_of::<Row>());
+        if let Some(controller) = column_resize_controller {
+            controller.widths.set(state.column_widths_of::<Row>());
+        }
+    }
+
+    create_effect(move |prev: Option<()>| {
+        let state = PersistedTableState::capture::<Row>(
+            &column_order.get(),
+            &column_visibility.get(),
+            &column_resize_controller
+                .map(|controller| controller.widths.get())
+                .unwrap_or_default(),
+            &sorting.get(),
+        );
+
+        // nothing has changed yet when the effect runs for the first time
+        if prev.is_some() {
+            state.save(&key);
+        }
+    });
+}
@@ -0,0 +1,221 @@
+use crate::TableDataProvider;
+use std::cell::RefCell;
+use std::fmt::Debug;
+use std::future::poll_fn;
+use std::ops::Range;
+use std::task::{Poll, Waker};
+
+type ProviderChange<DataP> = Box<dyn FnOnce(&mut DataP)>;
+
+/// The data provider of a table together with the changes of its sorting, filters and search that
+/// haven't been applied yet.
+///
+/// The futures of [`TableDataProvider::get_rows`] and [`TableDataProvider::row_count`] borrow the
+/// provider until they're done, so it can't be borrowed mutably while requests are pending. Changes
+/// that are made in the meantime are deferred until the last pending request has finished. New
+/// requests wait for them to be applied, so they never use outdated parameters.
+pub(crate) struct ProviderCell<DataP> {
+    provider: RefCell<DataP>,
+    pending_changes: RefCell<Vec<ProviderChange<DataP>>>,
+    /// The requests that wait for the pending changes.
+    waiting_requests: RefCell<Vec<Waker>>,
+}
+
+impl<DataP> ProviderCell<DataP> {
+    pub fn new(provider: DataP) -> Self {
+        Self {
+            provider: RefCell::new(provider),
+            pending_changes: RefCell::new(vec![]),
+            waiting_requests: RefCell::new(vec![]),
+        }
+    }
+
+    /// Applies `change` to the data provider right away if no requests are pending and as soon as
+    /// the last pending request has finished otherwise.
+    pub fn update(&self, change: impl FnOnce(&mut DataP) + 'static) {
+        self.pending_changes.borrow_mut().push(Box::new(change));
+        self.apply_pending_changes();
+    }
+
+    /// Applies the pending changes unless requests are pending. Returns `false` if they have to wait.
+    fn apply_pending_changes(&self) -> bool {
+        let Ok(mut provider) = self.provider.try_borrow_mut() else {
+            return false;
+        };
+
+        // changes might cause other changes, e.g. through effects
+        loop {
+            let changes = std::mem::take(&mut *self.pending_changes.borrow_mut());
+            if changes.is_empty() {
+                break;
+            }
+
+            for change in changes {
+                change(&mut provider);
+            }
+        }
+        drop(provider);
+
+        for waker in std::mem::take(&mut *self.waiting_requests.borrow_mut()) {
+            waker.wake();
+        }
+
+        true
+    }
+
+    /// Resolves once there are no pending changes anymore.
+    async fn changes_applied(&self) {
+        poll_fn(|cx| {
+            if self.pending_changes.borrow().is_empty() || self.apply_pending_changes() {
+                Poll::Ready(())
+            } else {
+                self.waiting_requests.borrow_mut().push(cx.waker().clone());
+                Poll::Pending
+            }
+        })
+        .await
+    }
+
+    /// Tracks the signals that the data provider depends on.
+    pub fn track<Row, Err>(&self)
+    where
+        DataP: TableDataProvider<Row, Err>,
+        Err: Debug,
+    {
+        self.provider.borrow().track();
+    }
+
+    /// Gets the rows in `range` from the data provider after the pending changes have been applied.
+    pub async fn get_rows<Row, Err>(
+        &self,
+        range: Range<usize>,
+    ) -> Result<(Vec<Row>, Range<usize>), Err>
+    where
+        DataP: TableDataProvider<Row, Err>,
+        Err: Debug,
+    {
+        self.changes_applied().await;
+        let result = self.get_rows_borrowed(range).await;
+        self.apply_pending_changes();
+
+        result
+    }
+
+    /// Gets the row count from the data provider after the pending changes have been applied.
+    pub async fn row_count<Row, Err>(&self) -> Option<usize>
+    where
+        DataP: TableDataProvider<Row, Err>,
+        Err: Debug,
+    {
+        self.changes_applied().await;
+        let row_count = self.row_count_borrowed().await;
+        self.apply_pending_changes();
+
+        row_count
+    }
+
+    // The borrow has to be held until the future of the data provider is done. It can't panic
+    // because all mutable access goes through `update` which defers changes while it's held.
+    #[allow(clippy::await_holding_refcell_ref)]
+    async fn get_rows_borrowed<Row, Err>(
+        &self,
+        range: Range<usize>,
+    ) -> Result<(Vec<Row>, Range<usize>), Err>
+    where
+        DataP: TableDataProvider<Row, Err>,
+        Err: Debug,
+    {
+        self.provider.borrow().get_rows(range).await
+    }
+
+    // See `get_rows_borrowed`.
+    #[allow(clippy::await_holding_refcell_ref)]
+    async fn row_count_borrowed<Row, Err>(&self) -> Option<usize>
+    where
+        DataP: TableDataProvider<Row, Err>,
+        Err: Debug,
+    {
+        self.provider.borrow().row_count().await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::Cell;
+    use std::future::Future;
+    use std::pin::pin;
+    use std::rc::Rc;
+    use std::task::Context;
+
+    /// Returns the search that was set when a request started once `finished` is set.
+    struct SlowProvider {
+        search: String,
+        finished: Rc<Cell<bool>>,
+    }
+
+    impl TableDataProvider<String> for SlowProvider {
+        async fn get_rows(
+            &self,
+            range: Range<usize>,
+        ) -> Result<(Vec<String>, Range<usize>), String> {
+            let search = self.search.clone();
+
+            poll_fn(|_| match self.finished.get() {
+                true => Poll::Ready(()),
+                false => Poll::Pending,
+            })
+            .await;
+
+            Ok((vec![search], range))
+        }
+
+        fn set_search(&mut self, search: &str) {
+            self.search = search.to_string();
+        }
+    }
+
+    #[test]
+    fn changes_are_applied_right_away_without_pending_requests() {
+        let cell = ProviderCell::new(SlowProvider {
+            search: String::new(),
+            finished: Rc::new(Cell::new(true)),
+        });
+
+        cell.update(|provider| provider.set_search("new"));
+
+        assert_eq!(cell.provider.borrow().search, "new");
+    }
+
+    #[test]
+    fn changes_wait_for_pending_requests() {
+        let finished = Rc::new(Cell::new(false));
+        let cell = ProviderCell::new(SlowProvider {
+            search: String::new(),
+            finished: Rc::clone(&finished),
+        });
+        let mut cx = Context::from_waker(Waker::noop());
+
+        let mut pending_request = pin!(cell.get_rows(0..1));
+        assert!(pending_request.as_mut().poll(&mut cx).is_pending());
+
+        // borrowing the provider mutably here would panic
+        cell.update(|provider| provider.set_search("new"));
+        assert_eq!(cell.provider.borrow().search, "");
+
+        // new requests wait for the change
+        let mut next_request = pin!(cell.get_rows(0..1));
+        assert!(next_request.as_mut().poll(&mut cx).is_pending());
+
+        finished.set(true);
+        assert_eq!(
+            pending_request.as_mut().poll(&mut cx),
+            Poll::Ready(Ok((vec![String::new()], 0..1)))
+        );
+        assert_eq!(cell.provider.borrow().search, "new");
+        assert_eq!(
+            next_request.as_mut().poll(&mut cx),
+            Poll::Ready(Ok((vec!["new".to_string()], 0..1)))
+        );
+    }
+}
@@ -1,24 +1,85 @@
 use leptos::*;
+use std::ops::Range;
 
 /// You can pass this to a [`TableContent`] component's `reload_controller` prop to trigger a reload.
 ///
 /// See the [paginated_rest_datasource example](https://github.com/Synphonyte/leptos-struct-table/blob/master/examples/paginated_rest_datasource/src/main.rs)
 /// for how to use.
 #[derive(Copy, Clone)]
-pub struct ReloadController(Trigger);
+pub struct ReloadController {
+    trigger: Trigger,
+    pub(crate) row_reload_requests: RwSignal<Vec<Range<usize>>>,
+    pub(crate) retry_trigger: Trigger,
+    pub(crate) row_count_trigger: Trigger,
+}
 
 impl Default for ReloadController {
     fn default() -> Self {
-        Self(create_trigger())
+        Self {
+            trigger: create_trigger(),
+            row_reload_requests: create_rw_signal(vec![]),
+            retry_trigger: create_trigger(),
+            row_count_trigger: create_trigger(),
+        }
     }
 }
 
 impl ReloadController {
+    /// Clears all loaded rows and loads the visible ones again.
     pub fn reload(&self) {
-        self.0.notify();
+        self.trigger.notify();
+    }
+
+    /// Loads only the row at `index` again, e.g. after it has been edited and saved to the server.
+    /// The row keeps showing its current data until the new data has arrived and is then re-rendered.
+    /// It's fetched through [`TableDataProvider::get_rows`] with a one-row range or, if the data
+    /// provider has a [`TableDataProvider::CHUNK_SIZE`], with the chunk that contains the row.
+    ///
+    /// Only rows that are currently loaded are reloaded. With virtualization, rows that have been
+    /// scrolled far out of view may have been evicted (see the `cache` prop of [`TableContent`]); they
+    /// are loaded with fresh data anyway once they're scrolled into view again. If this row is
+    /// reloaded again before the data of the first call has arrived, only the data of the last call
+    /// is used. Reloads of different rows don't affect each other, while [`ReloadController::reload`]
+    /// discards all pending row reloads.
+    pub fn reload_row(&self, index: usize) {
+        self.reload_range(index..index + 1);
+    }
+
+    /// Loads the rows in `range` again, e.g. after a batch edit of these rows. Like with
+    /// [`ReloadController::reload_row`] the rows keep showing their current data until the new data
+    /// has arrived and only rows that are currently loaded are reloaded. Rows outside of the range
+    /// keep their data, and the selection is kept for all rows.
+    ///
+    /// Ranges that are requested at the same time (including the rows of [`ReloadController::reload_row`])
+    /// are coalesced, so every row is fetched once. The rows are fetched through
+    /// [`TableDataProvider::get_rows`] with one call per run of consecutive rows or, if the data
+    /// provider has a [`TableDataProvider::CHUNK_SIZE`], with one call per chunk that contains any of them.
+    pub fn reload_range(&self, range: Range<usize>) {
+        if range.is_empty() {
+            return;
+        }
+
+        self.row_reload_requests
+            .update(|row_reload_requests| row_reload_requests.push(range));
+    }
+
+    /// Loads all rows again that failed to load and are rendered with the error row renderer.
+    /// Rows that are displayed are requested right away, the others once they're scrolled into view.
+    /// Unlike [`ReloadController::reload`] this keeps all rows that have been loaded successfully.
+    ///
+    /// If the `retry_policy` prop of [`TableContent`] is set, the rows get all of its attempts again.
+    pub fn retry_failed(&self) {
+        self.retry_trigger.notify();
+    }
+
+    /// Loads only the row count again through [`TableDataProvider::row_count`], e.g. after the server
+    /// has reported new records. Loaded rows, the selection and the scroll position are kept. New rows
+    /// are loaded once they're scrolled into view, the scrollbar or pagination adjusts right away.
+    pub fn reload_row_count(&self) {
+        self.row_count_trigger.notify();
     }
 
     pub fn track(&self) {
-        self.0.track();
+        self.trigger.track();
     }
 }
@@ -0,0 +1,235 @@
+#![doc(cfg(feature = "rest"))]
+
+use crate::{ColumnSort, PaginatedTableDataProvider, TableRow};
+use gloo_net::http::Request;
+use leptos::*;
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+use std::collections::VecDeque;
+use std::marker::PhantomData;
+use std::ops::Range;
+
+/// The error of a request of [`RestTableDataProvider`]. It's passed to the `on_error` prop of
+/// [`TableContent`] and can be accessed by the error row renderer through [`use_row_load_error`].
+#[derive(Debug, thiserror::Error)]
+pub enum RestError {
+    /// The request couldn't be sent or the response couldn't be read.
+    #[error("request failed: {0}")]
+    Request(#[from] gloo_net::Error),
+    /// The server responded with a status code outside of `200 - 299`.
+    #[error("HTTP {status} {status_text}")]
+    Status { status: u16, status_text: String },
+    /// The response isn't JSON of the expected shape.
+    #[error("invalid response: {0}")]
+    InvalidResponse(String),
+}
+
+/// A data provider for JSON APIs that are paginated by offset and limit. The rows are loaded in pages
+/// of 50 rows. Every page is requested as `GET <url>?offset=<start>&limit=50&sort=<field>&order=<asc|desc>`
+/// and the server is expected to respond with `{ "data": [<rows>], "total": <row count> }`.
+///
+/// The rows are deserialized with `serde`. The sort parameters contain the names of the struct fields
+/// (see [`TableRow::col_name`]) and are repeated for every sorted column in order of priority.
+/// All parameter and response field names can be changed to match your API.
+///
+/// ```no_run
+/// # use leptos::*;
+/// # use leptos_struct_table::*;
+/// # use serde::Deserialize;
+/// #
+/// #[derive(TableRow, Clone, Deserialize)]
+/// #[table(sortable)]
+/// pub struct Product {
+///     title: String,
+///     price: f64,
+/// }
+///
+/// #[component]
+/// fn App() -> impl IntoView {
+///     let search = create_rw_signal(String::new());
+///
+///     let rows = RestTableDataProvider::<Product>::new("https://dummyjson.com/products/search")
+///         .offset_param("skip")
+///         .sort_param("sortBy")
+///         .data_field("products")
+///         .params(Signal::derive(move || vec![("q".to_string(), search.get())]));
+///
+///     let on_error = |evt: LoadErrorEvent<RestError>| logging::error!("{}", evt.error);
+///
+///     view! {
+///         <table>
+///             <TableContent rows on_error />
+///         </table>
+///     }
+/// }
+/// ```
+///
+/// See the [rest example](https://github.com/Synphonyte/leptos-struct-table/blob/master/examples/rest/src/main.rs)
+/// for a complete table.
+pub struct RestTableDataProvider<Row> {
+    url: String,
+    offset_param: String,
+    limit_param: String,
+    sort_param: String,
+    order_param: String,
+    data_field: String,
+    total_field: String,
+    params: MaybeSignal<Vec<(String, String)>>,
+    sorting: VecDeque<(usize, ColumnSort)>,
+    _row: PhantomData<Row>,
+}
+
+impl<Row> RestTableDataProvider<Row>
+where
+    Row: TableRow + DeserializeOwned,
+{
+    /// Creates a data provider that requests the rows from `url`. The url can contain query
+    /// parameters that are sent with every request.
+    pub fn new(url: impl Into<String>) -> Self {
+        Self {
+            url: url.into(),
+            offset_param: "offset".to_string(),
+            limit_param: "limit".to_string(),
+            sort_param: "sort".to_string(),
+            order_param: "order".to_string(),
+            data_field: "data".to_string(),
+            total_field: "total".to_string(),
+            params: MaybeSignal::default(),
+            sorting: VecDeque::new(),
+            _row: PhantomData,
+        }
+    }
+
+    /// The name of the query parameter with the index of the first requested row. Defaults to `offset`.
+    pub fn offset_param(mut self, name: impl Into<String>) -> Self {
+        self.offset_param = name.into();
+        self
+    }
+
+    /// The name of the query parameter with the number of requested rows. Defaults to `limit`.
+    pub fn limit_param(mut self, name: impl Into<String>) -> Self {
+        self.limit_param = name.into();
+        self
+    }
+
+    /// The name of the query parameter with the field name of a sorted column. Defaults to `sort`.
+    pub fn sort_param(mut self, name: impl Into<String>) -> Self {
+        self.sort_param = name.into();
+        self
+    }
+
+    /// The name of the query parameter with the direction (`asc` or `desc`) of a sorted column.
+    /// Defaults to `order`.
+    pub fn order_param(mut self, name: impl Into<String>) -> Self {
+        self.order_param = name.into();
+        self
+    }
+
+    /// The field of the response object that contains the rows. Defaults to `data`.
+    pub fn data_field(mut self, name: impl Into<String>) -> Self {
+        self.data_field = name.into();
+        self
+    }
+
+    /// The field of the response object that contains the total number of rows. Defaults to `total`.
+    pub fn total_field(mut self, name: impl Into<String>) -> Self {
+        self.total_field = name.into();
+        self
+    }
+
+    /// Additional query parameters that are sent with every request, e.g. a search. If they come
+    /// from a signal, the table is reloaded when they change.
+    pub fn params(mut self, params: impl Into<MaybeSignal<Vec<(String, String)>>>) -> Self {
+        self.params = params.into();
+        self
+    }
+
+    async fn fetch(&self, range: &Range<usize>) -> Result<Value, RestError> {
+        let offset = range.start.to_string();
+        let limit = range.len().to_string();
+
+        let mut query = vec![
+            (self.offset_param.as_str(), offset),
+            (self.limit_param.as_str(), limit),
+        ];
+
+        for (col_index, col_sort) in &self.sorting {
+            let order = match col_sort {
+                ColumnSort::Ascending => "asc",
+                ColumnSort::Descending => "desc",
+                ColumnSort::None => continue,
+            };
+
+            if *col_index < Row::column_count() {
+                query.push((
+                    self.sort_param.as_str(),
+                    Row::col_name(*col_index).to_string(),
+                ));
+                query.push((self.order_param.as_str(), order.to_string()));
+            }
+        }
+
+        let params = self.params.get_untracked();
+        query.extend(
+            params
+                .iter()
+                .map(|(name, value)| (name.as_str(), value.clone())),
+        );
+
+        let response = Request::get(&self.url).query(query).send().await?;
+
+        if !response.ok() {
+            return Err(RestError::Status {
+                status: response.status(),
+                status_text: response.status_text(),
+            });
+        }
+
+        Ok(response.json().await?)
+    }
+}
+
+// This is implemented as a paginated data provider because a direct implementation of
+// `TableDataProvider` would conflict with the blanket implementation for paginated ones.
+impl<Row> PaginatedTableDataProvider<Row, RestError> for RestTableDataProvider<Row>
+where
+    Row: TableRow + DeserializeOwned,
+{
+    const PAGE_ROW_COUNT: usize = 50;
+
+    async fn get_page(&self, page_index: usize) -> Result<Vec<Row>, RestError> {
+        let start = page_index * Self::PAGE_ROW_COUNT;
+        let mut response = self.fetch(&(start..start + Self::PAGE_ROW_COUNT)).await?;
+
+        let data = response
+            .get_mut(&self.data_field)
+            .map(Value::take)
+            .ok_or_else(|| {
+                RestError::InvalidResponse(format!("missing field `{}`", self.data_field))
+            })?;
+
+        serde_json::from_value(data).map_err(|err| RestError::InvalidResponse(err.to_string()))
+    }
+
+    async fn row_count(&self) -> Option<usize> {
+        // some APIs return all rows for a limit of 0, so one row is requested instead
+        let response = self
+            .fetch(&(0..1))
+            .await
+            .map_err(|err| logging::error!("Failed to load row count: {err}"))
+            .ok()?;
+
+        response
+            .get(&self.total_field)
+            .and_then(Value::as_u64)
+            .map(|total| total as usize)
+    }
+
+    fn set_sorting(&mut self, sorting: &VecDeque<(usize, ColumnSort)>) {
+        self.sorting = sorting.clone();
+    }
+
+    fn track(&self) {
+        self.params.track();
+    }
+}
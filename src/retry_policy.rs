@@ -0,0 +1,65 @@
+use std::time::Duration;
+
+/// Defines how often and when rows that failed to load are requested again from the data provider.
+/// Pass it to the `retry_policy` prop of [`TableContent`].
+///
+/// While a failed request is retried, its rows keep being rendered as loading rows. Only after
+/// `max_attempts` failed requests are they rendered with the error row renderer. A retry only
+/// happens if the rows are still displayed once the delay is over, so scrolling away cancels it.
+/// The rows are loaded again with fresh attempts once they're scrolled into view again.
+///
+/// The delay before the `n`-th retry (starting at `0`) is `initial_delay * multiplier^n`
+/// but never more than `max_delay`.
+///
+/// ```
+/// # use leptos_struct_table::RetryPolicy;
+/// # use std::time::Duration;
+/// let policy = RetryPolicy {
+///     max_attempts: 5,
+///     initial_delay: Duration::from_millis(200),
+///     multiplier: 2.0,
+///     max_delay: Duration::from_secs(1),
+/// };
+///
+/// assert_eq!(policy.delay(0), Duration::from_millis(200));
+/// assert_eq!(policy.delay(1), Duration::from_millis(400));
+/// assert_eq!(policy.delay(2), Duration::from_millis(800));
+/// assert_eq!(policy.delay(3), Duration::from_secs(1));
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct RetryPolicy {
+    /// How many requests are made at most for the same rows, including the first one.
+    pub max_attempts: usize,
+    /// The delay before the first retry.
+    pub initial_delay: Duration,
+    /// The factor by which the delay grows with every retry.
+    pub multiplier: f64,
+    /// The maximum delay between two requests.
+    pub max_delay: Duration,
+}
+
+impl Default for RetryPolicy {
+    /// 3 attempts with 500ms and then 1s between them.
+    fn default() -> Self {
+        Self {
+            max_attempts: 3,
+            initial_delay: Duration::from_millis(500),
+            multiplier: 2.0,
+            max_delay: Duration::from_secs(10),
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// The delay before the retry with the index `retry` (starting at `0` for the first retry).
+    pub fn delay(&self, retry: usize) -> Duration {
+        let factor = self.multiplier.powi(retry.min(i32::MAX as usize) as i32);
+        let delay = self.initial_delay.as_nanos() as f64 * factor;
+
+        if delay.is_finite() && delay < self.max_delay.as_nanos() as f64 {
+            Duration::from_nanos(delay.max(0.0) as u64)
+        } else {
+            self.max_delay
+        }
+    }
+}
@@ -0,0 +1,63 @@
+use std::rc::Rc;
+
+type RowClassClosure<Row> = Rc<dyn Fn(&Row, usize) -> String>;
+
+/// New type wrapper of a closure that returns additional classes for a body row from its data
+/// and its index. See the `row_class_fn` prop of [`TableContent`].
+///
+/// Because this only needs a `Fn(&Row, usize) -> String`, you can pass a method of your row struct
+/// directly:
+///
+/// ```
+/// # use leptos::*;
+/// # use leptos_struct_table::*;
+/// #
+/// #[derive(TableRow, Clone)]
+/// #[table(impl_vec_data_provider)]
+/// pub struct Invoice {
+///     number: String,
+///     overdue: bool,
+/// }
+///
+/// impl Invoice {
+///     fn row_class(&self, _index: usize) -> String {
+///         if self.overdue { "overdue" } else { "" }.to_string()
+///     }
+/// }
+///
+/// #[component]
+/// fn App() -> impl IntoView {
+///     let rows = vec![
+///         Invoice { number: "2024-001".to_string(), overdue: false },
+///         Invoice { number: "2024-002".to_string(), overdue: true },
+///     ];
+///
+///     view! {
+///         <table>
+///             <TableContent rows row_class_fn=Invoice::row_class />
+///         </table>
+///     }
+/// }
+/// ```
+pub struct RowClassFn<Row>(RowClassClosure<Row>);
+
+impl<Row> Clone for RowClassFn<Row> {
+    fn clone(&self) -> Self {
+        Self(Rc::clone(&self.0))
+    }
+}
+
+impl<F, Row> From<F> for RowClassFn<Row>
+where
+    F: Fn(&Row, usize) -> String + 'static,
+{
+    fn from(f: F) -> Self {
+        Self(Rc::new(f))
+    }
+}
+
+impl<Row> RowClassFn<Row> {
+    pub fn run(&self, row: &Row, index: usize) -> String {
+        (self.0)(row, index)
+    }
+}
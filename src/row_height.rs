@@ -0,0 +1,101 @@
+use std::collections::BTreeMap;
+use std::rc::Rc;
+
+type RowHeightClosure<Row> = Rc<dyn Fn(&Row, usize) -> Option<f64>>;
+
+/// New type wrapper of a closure that returns the height hint of a row in pixels.
+/// See the `row_height_fn` prop of [`TableContent`].
+pub struct RowHeightFn<Row>(RowHeightClosure<Row>);
+
+impl<Row> Clone for RowHeightFn<Row> {
+    fn clone(&self) -> Self {
+        Self(Rc::clone(&self.0))
+    }
+}
+
+impl<F, Row> From<F> for RowHeightFn<Row>
+where
+    F: Fn(&Row, usize) -> Option<f64> + 'static,
+{
+    fn from(f: F) -> Self {
+        Self(Rc::new(f))
+    }
+}
+
+impl<Row> RowHeightFn<Row> {
+    pub fn run(&self, row: &Row, index: usize) -> Option<f64> {
+        (self.0)(row, index)
+    }
+}
+
+/// The offset in pixels of the top of the row at `index` from the top of the first row. Rows that
+/// have an entry in `heights` have this height, all others have the `average` height.
+pub(crate) fn row_offset(index: usize, average: f64, heights: &BTreeMap<usize, f64>) -> f64 {
+    index as f64 * average
+        + heights
+            .range(..index)
+            .map(|(_, height)| height - average)
+            .sum::<f64>()
+}
+
+/// The index of the row that contains the vertical `offset` in pixels from the top of the first row.
+/// This is the inverse of [`row_offset`].
+pub(crate) fn row_index_at(offset: f64, average: f64, heights: &BTreeMap<usize, f64>) -> usize {
+    let mut row_start = 0.0;
+    let mut index = 0;
+
+    for (&row_index, &height) in heights {
+        let rows_before_height = (row_index - index) as f64 * average;
+        if offset < row_start + rows_before_height {
+            break;
+        }
+        row_start += rows_before_height;
+
+        if offset < row_start + height {
+            return row_index;
+        }
+        row_start += height;
+        index = row_index + 1;
+    }
+
+    index + ((offset - row_start).max(0.0) / average).floor() as usize
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn row_offset_without_hints_uses_the_average() {
+        let heights = BTreeMap::new();
+
+        assert_eq!(row_offset(0, 20.0, &heights), 0.0);
+        assert_eq!(row_offset(5, 20.0, &heights), 100.0);
+    }
+
+    #[test]
+    fn row_offset_mixes_hinted_heights_and_the_average() {
+        // rows 1 and 3 have hints, all others are 20px high
+        let heights = BTreeMap::from([(1, 50.0), (3, 10.0)]);
+
+        assert_eq!(row_offset(0, 20.0, &heights), 0.0);
+        assert_eq!(row_offset(1, 20.0, &heights), 20.0);
+        assert_eq!(row_offset(2, 20.0, &heights), 70.0);
+        assert_eq!(row_offset(3, 20.0, &heights), 90.0);
+        assert_eq!(row_offset(4, 20.0, &heights), 100.0);
+        assert_eq!(row_offset(6, 20.0, &heights), 140.0);
+    }
+
+    #[test]
+    fn row_index_at_is_the_inverse_of_row_offset() {
+        let heights = BTreeMap::from([(1, 50.0), (3, 10.0)]);
+
+        for index in 0..8 {
+            let offset = row_offset(index, 20.0, &heights);
+
+            assert_eq!(row_index_at(offset, 20.0, &heights), index);
+            assert_eq!(row_index_at(offset + 5.0, 20.0, &heights), index);
+        }
+        assert_eq!(row_index_at(-10.0, 20.0, &heights), 0);
+    }
+}
@@ -0,0 +1,59 @@
+use std::rc::Rc;
+
+type RowKeyClosure<Row> = Rc<dyn Fn(&Row) -> String>;
+
+/// New type wrapper of a closure that returns a stable unique key of a row, e.g. its database id.
+/// See the `row_key_fn` prop of [`TableContent`].
+///
+/// ```
+/// # use leptos::*;
+/// # use leptos_struct_table::*;
+/// # use std::collections::HashSet;
+/// #
+/// #[derive(TableRow, Clone)]
+/// #[table(sortable, impl_vec_data_provider)]
+/// pub struct User {
+///     #[table(skip)]
+///     id: u32,
+///     name: String,
+/// }
+///
+/// #[component]
+/// fn App() -> impl IntoView {
+///     let rows = vec![
+///         User { id: 1, name: "Ada".to_string() },
+///         User { id: 2, name: "Grace".to_string() },
+///     ];
+///
+///     let selected_indices = create_rw_signal(HashSet::new());
+///     let row_key_fn = |user: &User| user.id.to_string();
+///
+///     view! {
+///         <table>
+///             <TableContent rows selection=Selection::Multiple(selected_indices) row_key_fn />
+///         </table>
+///     }
+/// }
+/// ```
+pub struct RowKeyFn<Row>(RowKeyClosure<Row>);
+
+impl<Row> Clone for RowKeyFn<Row> {
+    fn clone(&self) -> Self {
+        Self(Rc::clone(&self.0))
+    }
+}
+
+impl<F, Row> From<F> for RowKeyFn<Row>
+where
+    F: Fn(&Row) -> String + 'static,
+{
+    fn from(f: F) -> Self {
+        Self(Rc::new(f))
+    }
+}
+
+impl<Row> RowKeyFn<Row> {
+    pub fn run(&self, row: &Row) -> String {
+        (self.0)(row)
+    }
+}
@@ -0,0 +1,120 @@
+use leptos::*;
+
+/// A change of a row that happened outside of the table, e.g. on the server. Rows are identified by
+/// the key returned by the `row_key_fn` prop of [`TableContent`]. See [`RowMutationController`].
+#[derive(Clone, Debug)]
+pub enum RowMutation<Row> {
+    /// A new row has been added.
+    Insert(Row),
+    /// The data of the row with the same key has changed.
+    Update(Row),
+    /// The row with this key has been removed.
+    Delete(String),
+}
+
+/// You can pass this to a [`TableContent`] component's `row_mutations` prop to push changes of rows
+/// into the table while it's shown, e.g. from messages of a websocket.
+///
+/// The data provider has to reflect the changes as well, i.e. the rows it loads afterwards already
+/// contain them. The table applies them to its loaded rows like this:
+///
+/// - **Updates** replace the loaded row with the same key in place and re-render only that row. No
+///   request is made to the data provider. The row stays at its position even if the table is sorted
+///   by a value that has changed, so rows don't move away from under the user's cursor. Updates of
+///   rows that aren't loaded are ignored since they're loaded with fresh data anyway.
+/// - **Inserts** and **deletes** shift the following rows and change the row count. Only the data
+///   provider knows where a new row belongs in the current sorting, so the row count and the loaded
+///   rows are loaded again. Unlike [`ReloadController::reload`] the scroll position is kept, the
+///   row count is updated without resetting the scrollbar and the selected rows are selected again
+///   at their new index once they're loaded. Mutations that are pushed together cause only one reload.
+///
+/// This requires the `row_key_fn` prop. Without it every mutation reloads the loaded rows.
+///
+/// ```
+/// # use leptos::*;
+/// # use leptos_struct_table::*;
+/// # use std::collections::HashSet;
+/// #
+/// #[derive(TableRow, Clone)]
+/// #[table(sortable, impl_vec_data_provider)]
+/// pub struct Order {
+///     #[table(skip)]
+///     id: u32,
+///     customer: String,
+///     status: String,
+/// }
+///
+/// // e.g. the deserialized messages of a websocket
+/// enum ServerMessage {
+///     Created(Order),
+///     Changed(Order),
+///     Removed(u32),
+/// }
+///
+/// #[component]
+/// fn App() -> impl IntoView {
+///     # let rows = vec![];
+///     let row_mutations = RowMutationController::default();
+///     let selected_indices = create_rw_signal(HashSet::new());
+///
+///     let on_message = move |message: ServerMessage| match message {
+///         ServerMessage::Created(order) => row_mutations.insert(order),
+///         ServerMessage::Changed(order) => row_mutations.update(order),
+///         ServerMessage::Removed(id) => row_mutations.delete(id.to_string()),
+///     };
+///     # let _ = on_message;
+///
+///     view! {
+///         <table>
+///             <TableContent
+///                 rows
+///                 row_key_fn=|order: &Order| order.id.to_string()
+///                 row_mutations
+///                 selection=Selection::Multiple(selected_indices)
+///             />
+///         </table>
+///     }
+/// }
+/// ```
+pub struct RowMutationController<Row: 'static> {
+    pub(crate) mutations: RwSignal<Vec<RowMutation<Row>>>,
+}
+
+impl<Row: 'static> Clone for RowMutationController<Row> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<Row: 'static> Copy for RowMutationController<Row> {}
+
+impl<Row: 'static> Default for RowMutationController<Row> {
+    fn default() -> Self {
+        Self {
+            mutations: create_rw_signal(vec![]),
+        }
+    }
+}
+
+impl<Row: 'static> RowMutationController<Row> {
+    /// Pushes a new row into the table.
+    pub fn insert(&self, row: Row) {
+        self.apply([RowMutation::Insert(row)]);
+    }
+
+    /// Replaces the loaded row with the same key.
+    pub fn update(&self, row: Row) {
+        self.apply([RowMutation::Update(row)]);
+    }
+
+    /// Removes the row with the key `key` from the table.
+    pub fn delete(&self, key: impl Into<String>) {
+        self.apply([RowMutation::Delete(key.into())]);
+    }
+
+    /// Pushes several mutations at once, e.g. all changes of one message.
+    pub fn apply(&self, mutations: impl IntoIterator<Item = RowMutation<Row>>) {
+        self.mutations
+            .update(|pending_mutations| pending_mutations.extend(mutations));
+    }
+}
@@ -0,0 +1,57 @@
+use leptos::*;
+
+/// How close to the top or bottom edge of the scroll container a dragged row has to be to scroll.
+pub(crate) const AUTO_SCROLL_EDGE: f64 = 48.0;
+/// How far the scroll container is scrolled per `dragover` event near its edges.
+pub(crate) const AUTO_SCROLL_STEP: f64 = 20.0;
+
+/// Provided as context by [`TableContent`] if its rows can be reordered by dragging.
+#[derive(Copy, Clone)]
+pub(crate) struct RowReorderContext {
+    /// `false` while the table is sorted by a column
+    pub enabled: Signal<bool>,
+    pub drag_handle: bool,
+    pub dragged_row: RwSignal<Option<usize>>,
+    pub drop_target: RwSignal<Option<usize>>,
+    /// The row whose drag handle is pressed. Only this row is draggable with `drag_handle`.
+    pub armed_row: RwSignal<Option<usize>>,
+    pub on_move: Callback<(usize, usize)>,
+}
+
+impl RowReorderContext {
+    pub fn is_draggable(&self, row_index: usize) -> bool {
+        self.enabled.get() && (!self.drag_handle || self.armed_row.get() == Some(row_index))
+    }
+
+    pub fn is_dragging(&self) -> bool {
+        self.dragged_row.get_untracked().is_some()
+    }
+
+    pub fn drag_start(&self, row_index: usize) {
+        self.dragged_row.set(Some(row_index));
+    }
+
+    pub fn drag_over(&self, row_index: usize) {
+        if self.drop_target.get_untracked() != Some(row_index) {
+            self.drop_target.set(Some(row_index));
+        }
+    }
+
+    /// Moves the dragged row to the position of the row `row_index`.
+    pub fn drop_on(&self, row_index: usize) {
+        if let Some(dragged_row) = self.dragged_row.get_untracked() {
+            if dragged_row != row_index && self.enabled.get_untracked() {
+                (self.on_move)((dragged_row, row_index));
+            }
+        }
+        self.drag_end();
+    }
+
+    pub fn drag_end(&self) {
+        batch(|| {
+            self.dragged_row.set(None);
+            self.drop_target.set(None);
+            self.armed_row.set(None);
+        });
+    }
+}
@@ -0,0 +1,70 @@
+use std::rc::Rc;
+
+type RowSelectableClosure<Row> = Rc<dyn Fn(&Row, usize) -> bool>;
+
+/// New type wrapper of a closure that returns whether a body row can be selected from its data
+/// and its index. See the `row_selectable_fn` prop of [`TableContent`].
+///
+/// Like with [`RowClassFn`] you can pass a method of your row struct with the signature
+/// `fn(&self, index: usize) -> bool` directly:
+///
+/// ```
+/// # use leptos::*;
+/// # use leptos_struct_table::*;
+/// # use std::collections::HashSet;
+/// #
+/// #[derive(TableRow, Clone)]
+/// #[table(impl_vec_data_provider)]
+/// pub struct Record {
+///     title: String,
+///     locked: bool,
+/// }
+///
+/// impl Record {
+///     fn is_selectable(&self, _index: usize) -> bool {
+///         !self.locked
+///     }
+/// }
+///
+/// #[component]
+/// fn App() -> impl IntoView {
+///     let rows = vec![
+///         Record { title: "Draft".to_string(), locked: false },
+///         Record { title: "Published".to_string(), locked: true },
+///     ];
+///     let selected_indices = create_rw_signal(HashSet::new());
+///
+///     view! {
+///         <table>
+///             <TableContent
+///                 rows
+///                 selection=Selection::Multiple(selected_indices)
+///                 row_selectable_fn=Record::is_selectable
+///                 max_selection=10_usize
+///             />
+///         </table>
+///     }
+/// }
+/// ```
+pub struct RowSelectableFn<Row>(RowSelectableClosure<Row>);
+
+impl<Row> Clone for RowSelectableFn<Row> {
+    fn clone(&self) -> Self {
+        Self(Rc::clone(&self.0))
+    }
+}
+
+impl<F, Row> From<F> for RowSelectableFn<Row>
+where
+    F: Fn(&Row, usize) -> bool + 'static,
+{
+    fn from(f: F) -> Self {
+        Self(Rc::new(f))
+    }
+}
+
+impl<Row> RowSelectableFn<Row> {
+    pub fn run(&self, row: &Row, index: usize) -> bool {
+        (self.0)(row, index)
+    }
+}
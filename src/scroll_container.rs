@@ -1,5 +1,5 @@
 use leptos::html::ElementDescriptor;
-use leptos::{NodeRef, Signal};
+use leptos::{NodeRef, Signal, SignalGetUntracked};
 use leptos_use::core::ElementMaybeSignal;
 use leptos_use::use_document;
 use wasm_bindgen::JsCast;
@@ -7,6 +7,12 @@ use wasm_bindgen::JsCast;
 #[derive(Copy, Clone, Debug)]
 pub struct ScrollContainer(Signal<Option<web_sys::Element>>);
 
+impl ScrollContainer {
+    pub(crate) fn element(&self) -> Option<web_sys::Element> {
+        self.0.get_untracked()
+    }
+}
+
 impl Default for ScrollContainer {
     fn default() -> Self {
         Self(Signal::derive(move || {
@@ -1,5 +1,6 @@
 use leptos::*;
 use std::collections::HashSet;
+use std::sync::atomic::{AtomicUsize, Ordering};
 
 /// Type of selection together with the `RwSignal` to hold the selection
 #[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
@@ -18,6 +19,105 @@ pub enum Selection {
 }
 
 impl Selection {
+    /// The indices of the selected rows in ascending order. Doesn't track the selection signal.
+    pub(crate) fn selected_indices_untracked(&self) -> Vec<usize> {
+        let mut selected_indices = match self {
+            Selection::None => vec![],
+            Selection::Single(selected_index) => {
+                selected_index.get_untracked().into_iter().collect()
+            }
+            Selection::Multiple(selected_indices) => {
+                selected_indices.get_untracked().into_iter().collect()
+            }
+        };
+        selected_indices.sort_unstable();
+
+        selected_indices
+    }
+
+    /// Returns `true` if the row at `index` is selected. Tracks the selection signal.
+    pub fn is_selected(&self, index: usize) -> bool {
+        match self {
+            Selection::None => false,
+            Selection::Single(selected_index) => selected_index.get() == Some(index),
+            Selection::Multiple(selected_indices) => {
+                selected_indices.with(|selected_indices| selected_indices.contains(&index))
+            }
+        }
+    }
+
+    /// The state of a "select all" checkbox for the rows at `indices`, e.g. the loaded ones.
+    /// Tracks the selection signal.
+    pub fn select_all_state(&self, indices: &[usize]) -> SelectAllState {
+        let selected_count = indices.iter().filter(|i| self.is_selected(**i)).count();
+
+        if selected_count == 0 {
+            SelectAllState::Unchecked
+        } else if selected_count == indices.len() {
+            SelectAllState::Checked
+        } else {
+            SelectAllState::Indeterminate
+        }
+    }
+
+    /// Selects all rows at `indices` or de-selects them if they're all selected already.
+    /// Rows that aren't contained in `indices` keep their state. Only works with [`Selection::Multiple`].
+    ///
+    /// Returns `true` if the rows have been selected and `false` if they have been de-selected.
+    ///
+    /// ```
+    /// # use leptos::*;
+    /// # use leptos_struct_table::*;
+    /// # use std::collections::HashSet;
+    /// # let runtime = create_runtime();
+    /// let selected_indices = create_rw_signal(HashSet::from([1]));
+    /// let selection = Selection::Multiple(selected_indices);
+    /// let loaded = [0, 1, 2];
+    ///
+    /// assert_eq!(selection.select_all_state(&loaded), SelectAllState::Indeterminate);
+    ///
+    /// assert!(selection.toggle_all(&loaded));
+    /// assert_eq!(selection.select_all_state(&loaded), SelectAllState::Checked);
+    ///
+    /// assert!(!selection.toggle_all(&loaded));
+    /// assert_eq!(selection.select_all_state(&loaded), SelectAllState::Unchecked);
+    /// # runtime.dispose();
+    /// ```
+    pub fn toggle_all(&self, indices: &[usize]) -> bool {
+        let Selection::Multiple(selected_indices) = self else {
+            return false;
+        };
+
+        let select = untrack(|| self.select_all_state(indices)) != SelectAllState::Checked;
+
+        selected_indices.update(|selected_indices| {
+            for i in indices {
+                if select {
+                    selected_indices.insert(*i);
+                } else {
+                    selected_indices.remove(i);
+                }
+            }
+        });
+
+        select
+    }
+
+    /// Adds the row at `index` to the selection. With [`Selection::Single`] it replaces the selected row.
+    pub(crate) fn select(&self, index: usize) {
+        match self {
+            Selection::None => {}
+            Selection::Single(selected_index) => {
+                selected_index.set(Some(index));
+            }
+            Selection::Multiple(selected_indices) => {
+                selected_indices.update(|selected_indices| {
+                    selected_indices.insert(index);
+                });
+            }
+        }
+    }
+
     /// Clear the selection
     pub fn clear(&self) {
         match self {
@@ -33,3 +133,286 @@ impl Selection {
         }
     }
 }
+
+/// The state of a "select all" checkbox. See [`Selection::select_all_state`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum SelectAllState {
+    /// None of the rows are selected.
+    Unchecked,
+    /// Some but not all of the rows are selected.
+    Indeterminate,
+    /// All of the rows are selected.
+    Checked,
+}
+
+/// Provided as context by [`TableContent`] if the selection checkboxes or radio buttons are shown.
+#[derive(Copy, Clone)]
+pub(crate) struct SelectionColumnContext {
+    pub selection: Selection,
+    pub select_all_state: Signal<SelectAllState>,
+    pub toggle_all: Callback<()>,
+    pub toggle_row: Callback<usize>,
+    /// The number of the group of the radio buttons with `Selection::Single`.
+    pub radio_group: Option<usize>,
+}
+
+impl SelectionColumnContext {
+    /// The `name` of the radio buttons with `Selection::Single`. It's unique per table so that the
+    /// radio buttons of different tables don't affect each other.
+    pub fn radio_name(&self) -> Option<String> {
+        self.radio_group
+            .map(|radio_group| format!("table-selection-{radio_group}"))
+    }
+}
+
+/// Returns a new number for a group of radio buttons.
+pub(crate) fn next_radio_group() -> usize {
+    static RADIO_GROUP_COUNT: AtomicUsize = AtomicUsize::new(0);
+
+    RADIO_GROUP_COUNT.fetch_add(1, Ordering::Relaxed)
+}
+
+/// What happens when rows are selected while `max_selection` rows are already selected.
+/// See the `max_selection` prop of [`TableContent`].
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum SelectionLimitPolicy {
+    /// The rows are not selected and `on_selection_rejected` is called instead (the default).
+    #[default]
+    Reject,
+
+    /// The rows that have been selected first are de-selected to make room for the new ones.
+    EvictOldest,
+}
+
+/// Limits `selected_indices` to `max_selection` entries after the indices in `added` have been
+/// inserted (in this order). `order` keeps track of the order in which the indices were selected.
+/// Returns `true` if any of the indices in `added` was rejected.
+pub(crate) fn limit_selection(
+    selected_indices: &mut HashSet<usize>,
+    order: &mut Vec<usize>,
+    added: &[usize],
+    max_selection: usize,
+    policy: SelectionLimitPolicy,
+) -> bool {
+    order.retain(|i| selected_indices.contains(i) && !added.contains(i));
+
+    // indices that have been selected by setting the signal directly
+    let mut untracked = selected_indices
+        .iter()
+        .filter(|i| !order.contains(i) && !added.contains(i))
+        .copied()
+        .collect::<Vec<_>>();
+    untracked.sort_unstable();
+    order.extend(untracked);
+
+    let mut rejected = false;
+
+    for &i in added {
+        if order.len() >= max_selection {
+            if policy == SelectionLimitPolicy::Reject || order.is_empty() {
+                selected_indices.remove(&i);
+                rejected = true;
+                continue;
+            }
+
+            let oldest = order.remove(0);
+            selected_indices.remove(&oldest);
+        }
+
+        order.push(i);
+    }
+
+    rejected
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Selects `index` like a click with cmd/ctrl pressed.
+    fn select(
+        selected_indices: &mut HashSet<usize>,
+        order: &mut Vec<usize>,
+        index: usize,
+        policy: SelectionLimitPolicy,
+    ) -> bool {
+        selected_indices.insert(index);
+        limit_selection(selected_indices, order, &[index], 3, policy)
+    }
+
+    /// Selects all of `indices` like the checkbox in the header.
+    fn select_all(
+        selected_indices: &mut HashSet<usize>,
+        order: &mut Vec<usize>,
+        indices: &[usize],
+        policy: SelectionLimitPolicy,
+    ) -> bool {
+        let added = indices
+            .iter()
+            .copied()
+            .filter(|i| !selected_indices.contains(i))
+            .collect::<Vec<_>>();
+        selected_indices.extend(indices);
+        limit_selection(selected_indices, order, &added, 3, policy)
+    }
+
+    #[test]
+    fn selection_below_the_limit_is_kept() {
+        let mut selected_indices = HashSet::new();
+        let mut order = vec![];
+
+        for index in [4, 1, 7] {
+            let rejected = select(
+                &mut selected_indices,
+                &mut order,
+                index,
+                SelectionLimitPolicy::Reject,
+            );
+            assert!(!rejected);
+        }
+
+        assert_eq!(selected_indices, HashSet::from([1, 4, 7]));
+        assert_eq!(order, vec![4, 1, 7]);
+    }
+
+    #[test]
+    fn selection_over_the_limit_is_rejected() {
+        let mut selected_indices = HashSet::new();
+        let mut order = vec![];
+
+        for index in [4, 1, 7] {
+            select(
+                &mut selected_indices,
+                &mut order,
+                index,
+                SelectionLimitPolicy::Reject,
+            );
+        }
+        let rejected = select(
+            &mut selected_indices,
+            &mut order,
+            2,
+            SelectionLimitPolicy::Reject,
+        );
+
+        assert!(rejected);
+        assert_eq!(selected_indices, HashSet::from([1, 4, 7]));
+        assert_eq!(order, vec![4, 1, 7]);
+    }
+
+    #[test]
+    fn selection_over_the_limit_evicts_the_oldest() {
+        let mut selected_indices = HashSet::new();
+        let mut order = vec![];
+
+        for index in [4, 1, 7, 2] {
+            let rejected = select(
+                &mut selected_indices,
+                &mut order,
+                index,
+                SelectionLimitPolicy::EvictOldest,
+            );
+            assert!(!rejected);
+        }
+
+        assert_eq!(selected_indices, HashSet::from([1, 2, 7]));
+        assert_eq!(order, vec![1, 7, 2]);
+    }
+
+    #[test]
+    fn indices_set_from_the_outside_count_towards_the_limit() {
+        let mut selected_indices = HashSet::from([8, 5, 6]);
+        let mut order = vec![];
+
+        let rejected = select(
+            &mut selected_indices,
+            &mut order,
+            2,
+            SelectionLimitPolicy::EvictOldest,
+        );
+
+        assert!(!rejected);
+        assert_eq!(selected_indices, HashSet::from([2, 6, 8]));
+        assert_eq!(order, vec![6, 8, 2]);
+    }
+
+    #[test]
+    fn select_all_is_clamped_to_the_limit() {
+        let mut selected_indices = HashSet::new();
+        let mut order = vec![];
+
+        let rejected = select_all(
+            &mut selected_indices,
+            &mut order,
+            &[0, 1, 2, 3, 4, 5],
+            SelectionLimitPolicy::Reject,
+        );
+
+        assert!(rejected);
+        assert_eq!(selected_indices, HashSet::from([0, 1, 2]));
+        assert_eq!(order, vec![0, 1, 2]);
+    }
+
+    #[test]
+    fn select_all_keeps_the_selected_rows_when_rejecting() {
+        let mut selected_indices = HashSet::new();
+        let mut order = vec![];
+
+        select(
+            &mut selected_indices,
+            &mut order,
+            4,
+            SelectionLimitPolicy::Reject,
+        );
+        let rejected = select_all(
+            &mut selected_indices,
+            &mut order,
+            &[0, 1, 2, 3, 4, 5],
+            SelectionLimitPolicy::Reject,
+        );
+
+        assert!(rejected);
+        assert_eq!(selected_indices, HashSet::from([0, 1, 4]));
+        assert_eq!(order, vec![4, 0, 1]);
+    }
+
+    #[test]
+    fn select_all_evicts_the_oldest() {
+        let mut selected_indices = HashSet::new();
+        let mut order = vec![];
+
+        select(
+            &mut selected_indices,
+            &mut order,
+            4,
+            SelectionLimitPolicy::EvictOldest,
+        );
+        let rejected = select_all(
+            &mut selected_indices,
+            &mut order,
+            &[0, 1, 2, 3, 4, 5],
+            SelectionLimitPolicy::EvictOldest,
+        );
+
+        assert!(!rejected);
+        assert_eq!(selected_indices, HashSet::from([2, 3, 5]));
+        assert_eq!(order, vec![2, 3, 5]);
+    }
+
+    #[test]
+    fn select_all_below_the_limit_is_kept() {
+        let mut selected_indices = HashSet::new();
+        let mut order = vec![];
+
+        let rejected = select_all(
+            &mut selected_indices,
+            &mut order,
+            &[0, 1],
+            SelectionLimitPolicy::Reject,
+        );
+
+        assert!(!rejected);
+        assert_eq!(selected_indices, HashSet::from([0, 1]));
+        assert_eq!(order, vec![0, 1]);
+    }
+}
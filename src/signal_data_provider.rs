@@ -0,0 +1,116 @@
+use crate::{
+    sort_rows_with, ColumnSort, PaginatedTableDataProvider, RowComparator, TableDataProvider,
+};
+use leptos::*;
+use std::cell::RefCell;
+use std::collections::{HashMap, VecDeque};
+
+/// Data provider for local data that changes while the table is shown, e.g. rows that are pushed
+/// by a websocket. It wraps a signal of a `Vec<Row>` that implements [`TableDataProvider`], e.g.
+/// through `#[table(impl_vec_data_provider)]`, and reloads the table every time the signal changes.
+/// The row count is updated as well.
+///
+/// Sorting is delegated to the wrapped `Vec` like with [`FilteredVecDataProvider`]. The rows are sorted
+/// once per change and then handed to the table in chunks of [`PaginatedTableDataProvider::PAGE_ROW_COUNT`] rows.
+/// Since the indices of the rows can change with every update, the selection is cleared on reload.
+///
+/// ```
+/// # use leptos::*;
+/// # use leptos_struct_table::*;
+/// #
+/// #[derive(TableRow, Clone)]
+/// #[table(sortable, impl_vec_data_provider)]
+/// pub struct Measurement {
+///     sensor: String,
+///     value: f64,
+/// }
+///
+/// #[component]
+/// fn App() -> impl IntoView {
+///     let measurements = create_rw_signal(vec![]);
+///
+///     let add = move |_| {
+///         measurements.update(|measurements| {
+///             measurements.push(Measurement { sensor: "A".to_string(), value: 1.0 })
+///         })
+///     };
+///
+///     view! {
+///         <button on:click=add>"Add"</button>
+///         <table>
+///             <TableContent rows=SignalVecDataProvider::new(measurements) />
+///         </table>
+///     }
+/// }
+/// ```
+pub struct SignalVecDataProvider<Row: 'static> {
+    rows: Signal<Vec<Row>>,
+    sorting: VecDeque<(usize, ColumnSort)>,
+    comparators: HashMap<usize, RowComparator<Row>>,
+    sorted_rows: RefCell<Option<Vec<Row>>>,
+}
+
+impl<Row> SignalVecDataProvider<Row>
+where
+    Row: Clone + 'static,
+    Vec<Row>: TableDataProvider<Row>,
+{
+    /// Creates a new data provider that shows the rows of the signal.
+    pub fn new(rows: impl Into<Signal<Vec<Row>>>) -> Self {
+        Self {
+            rows: rows.into(),
+            sorting: VecDeque::new(),
+            comparators: HashMap::new(),
+            sorted_rows: RefCell::new(None),
+        }
+    }
+
+    /// Sorts the column `col_index` with `cmp` instead of the generated `PartialOrd` comparison,
+    /// e.g. to sort version strings semantically. See [`sort_rows_with`].
+    pub fn sort_with(mut self, col_index: usize, cmp: RowComparator<Row>) -> Self {
+        self.comparators.insert(col_index, cmp);
+        self
+    }
+}
+
+// This is implemented as a paginated data provider because a direct implementation of
+// `TableDataProvider` would conflict with the blanket implementation for paginated ones.
+impl<Row> PaginatedTableDataProvider<Row> for SignalVecDataProvider<Row>
+where
+    Row: Clone + 'static,
+    Vec<Row>: TableDataProvider<Row>,
+{
+    const PAGE_ROW_COUNT: usize = 50;
+
+    async fn get_page(&self, page_index: usize) -> Result<Vec<Row>, String> {
+        let mut sorted_rows = self.sorted_rows.borrow_mut();
+
+        let sorted_rows = sorted_rows.get_or_insert_with(|| {
+            let mut rows = self.rows.get_untracked();
+            sort_rows_with(&mut rows, &self.sorting, &self.comparators);
+            rows
+        });
+
+        Ok(sorted_rows
+            .iter()
+            .skip(page_index * Self::PAGE_ROW_COUNT)
+            .take(Self::PAGE_ROW_COUNT)
+            .cloned()
+            .collect())
+    }
+
+    async fn row_count(&self) -> Option<usize> {
+        Some(self.rows.with_untracked(Vec::len))
+    }
+
+    fn set_sorting(&mut self, sorting: &VecDeque<(usize, ColumnSort)>) {
+        self.sorting = sorting.clone();
+        self.sorted_rows.replace(None);
+    }
+
+    fn track(&self) {
+        // called whenever the rows change right before the table is reloaded
+        self.rows.track();
+        self.sorted_rows.replace(None);
+    }
+}
@@ -0,0 +1,108 @@
+use crate::TableRow;
+use leptos::*;
+
+/// Provided as context by [`TableContent`] with the number of rendered columns.
+#[derive(Copy, Clone)]
+pub(crate) struct ColumnCountContext(pub Signal<usize>);
+
+/// The number of columns that the surrounding [`TableContent`] renders. This contains the visible
+/// columns of `Row` as well as the selection and disclosure columns if they are shown.
+/// Outside of a [`TableContent`] this is [`TableRow::column_count`].
+pub fn use_table_column_count<Row: TableRow>() -> Signal<usize> {
+    match use_context::<ColumnCountContext>() {
+        Some(column_count) => column_count.0,
+        None => {
+            let column_count = Row::column_count();
+            Signal::derive(move || column_count)
+        }
+    }
+}
+
+/// Renders a single body cell that spans all columns of the surrounding [`TableContent`] (see
+/// [`use_table_column_count`]). Use this in a custom row renderer for rows that merge all their
+/// cells, e.g. the header of a group of rows.
+///
+/// Such rows aren't data rows. With [`FilteredVecDataProvider::spanning_rows`] they are skipped
+/// while the table is sorted, filtered or searched. If they are higher than the other rows, set the
+/// `measure_row_heights` or `row_height_fn` prop of [`TableContent`] so that the virtualization
+/// positions the rows correctly.
+///
+/// ```
+/// # use leptos::*;
+/// # use leptos_struct_table::*;
+/// #
+/// #[derive(TableRow, Clone)]
+/// #[table(sortable, impl_vec_data_provider)]
+/// pub struct Employee {
+///     name: String,
+///     role: String,
+///     #[table(skip)]
+///     department: Option<String>,
+/// }
+///
+/// impl Employee {
+///     fn department(department: &str) -> Self {
+///         Self { name: String::new(), role: String::new(), department: Some(department.to_string()) }
+///     }
+///
+///     fn new(name: &str, role: &str) -> Self {
+///         Self { name: name.to_string(), role: role.to_string(), department: None }
+///     }
+/// }
+///
+/// impl TableRowExport for Employee {
+///     fn col_value(&self, col_index: usize) -> Option<String> {
+///         match col_index {
+///             0 => Some(self.name.clone()),
+///             _ => Some(self.role.clone()),
+///         }
+///     }
+/// }
+///
+/// #[allow(non_snake_case)]
+/// fn GroupedRowRenderer(
+///     class: Signal<String>,
+///     row: Employee,
+///     index: usize,
+///     selected: Signal<bool>,
+///     on_select: EventHandler<web_sys::MouseEvent>,
+///     on_change: EventHandler<ChangeEvent<Employee>>,
+/// ) -> impl IntoView {
+///     match row.department.clone() {
+///         Some(department) => view! {
+///             <tr class="group-header" role="row">
+///                 {render_spanning_cell::<Employee>(department, "".to_string())}
+///             </tr>
+///         }
+///         .into_view(),
+///         None => DefaultTableRowRenderer(class, row, index, selected, on_select, on_change).into_view(),
+///     }
+/// }
+///
+/// #[component]
+/// fn App() -> impl IntoView {
+///     let rows = FilteredVecDataProvider::new(vec![
+///         Employee::department("Engineering"),
+///         Employee::new("Grace", "Admiral"),
+///         Employee::new("Margaret", "Director"),
+///         Employee::department("Research"),
+///         Employee::new("Ada", "Analyst"),
+///     ])
+///     .spanning_rows(|employee: &Employee| employee.department.is_some());
+///
+///     view! {
+///         <table>
+///             <TableContent rows row_renderer=GroupedRowRenderer />
+///         </table>
+///     }
+/// }
+/// ```
+pub fn render_spanning_cell<Row: TableRow>(content: impl IntoView, class: String) -> impl IntoView {
+    let column_count = use_table_column_count::<Row>();
+
+    view! {
+        <td class=class colspan=column_count role="cell">
+            {content}
+        </td>
+    }
+}
@@ -0,0 +1,157 @@
+use crate::{ColumnSort, TableRow};
+use std::collections::VecDeque;
+use std::marker::PhantomData;
+use std::ops::Range;
+
+/// Builds the SQL statements a server needs to serve the rows of a table page by page. Use it in the
+/// server functions that back your [`TableDataProvider`] with any database library, e.g. `sqlx`.
+///
+/// All column names come from [`TableRow::col_name`], i.e. from the struct fields at compile time,
+/// and the table name has to be `'static`. Sorting by a column index that doesn't exist is ignored,
+/// so the sorting and range sent by the client can be used as they are without risking SQL injection.
+/// Skipped fields aren't selected. Filters are up to you since they need bound parameters.
+///
+/// Columns that aren't stored in the database, like [`FieldGetter`](crate::FieldGetter)s or fields
+/// with a `getter`, have to be excluded with [`SqlQuery::skip_columns`]. Otherwise their names end
+/// up in the `SELECT` and the query fails.
+///
+/// ```
+/// # use leptos::*;
+/// # use leptos_struct_table::*;
+/// # use std::collections::VecDeque;
+/// #
+/// #[derive(TableRow, Clone)]
+/// struct Customer {
+///     first_name: String,
+///     last_name: String,
+///     city: String,
+/// }
+///
+/// let query = SqlQuery::<Customer>::new("customers");
+///
+/// let sorting = VecDeque::from([(1, ColumnSort::Ascending), (7, ColumnSort::Descending)]);
+///
+/// assert_eq!(
+///     query.select(&sorting, 20..30),
+///     "SELECT first_name, last_name, city FROM customers ORDER BY last_name ASC LIMIT 10 OFFSET 20"
+/// );
+/// assert_eq!(query.count(), "SELECT COUNT(*) FROM customers");
+///
+/// // e.g. with sqlx:
+/// // sqlx::query_as::<_, Customer>(&query.select(&sorting, range)).fetch_all(&pool).await
+/// ```
+///
+/// See the [serverfn_sqlx example](https://github.com/Synphonyte/leptos-struct-table/blob/master/examples/serverfn_sqlx/src/data_provider.rs)
+/// for a complete data provider.
+pub struct SqlQuery<Row> {
+    table: &'static str,
+    skipped_columns: &'static [usize],
+    _row: PhantomData<Row>,
+}
+
+impl<Row> Clone for SqlQuery<Row> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<Row> Copy for SqlQuery<Row> {}
+
+impl<Row: TableRow> SqlQuery<Row> {
+    /// Creates the queries for the database table `table`.
+    pub fn new(table: &'static str) -> Self {
+        Self {
+            table,
+            skipped_columns: &[],
+            _row: PhantomData,
+        }
+    }
+
+    /// Excludes the columns with the given indices from the `SELECT` and from sorting. Use it for
+    /// columns that don't exist in the database table.
+    ///
+    /// ```
+    /// # use leptos::*;
+    /// # use leptos_struct_table::*;
+    /// # use std::collections::VecDeque;
+    /// #
+    /// #[derive(TableRow, Clone)]
+    /// struct Customer {
+    ///     first_name: String,
+    ///     last_name: String,
+    ///     full_name: FieldGetter<String>,
+    /// }
+    ///
+    /// impl Customer {
+    ///     fn full_name(&self) -> String {
+    ///         format!("{} {}", self.first_name, self.last_name)
+    ///     }
+    /// }
+    ///
+    /// let query = SqlQuery::<Customer>::new("customers").skip_columns(&[2]);
+    ///
+    /// let sorting = VecDeque::from([(2, ColumnSort::Ascending), (0, ColumnSort::Ascending)]);
+    ///
+    /// assert_eq!(
+    ///     query.select(&sorting, 0..10),
+    ///     "SELECT first_name, last_name FROM customers ORDER BY first_name ASC LIMIT 10 OFFSET 0"
+    /// );
+    /// ```
+    pub fn skip_columns(mut self, col_indices: &'static [usize]) -> Self {
+        self.skipped_columns = col_indices;
+        self
+    }
+
+    /// The indices of all columns that aren't skipped.
+    fn column_indices(&self) -> impl Iterator<Item = usize> + '_ {
+        (0..Row::column_count()).filter(|col_index| !self.skipped_columns.contains(col_index))
+    }
+
+    /// The comma separated names of all columns that aren't skipped, e.g. to build your own `SELECT`.
+    pub fn columns(&self) -> String {
+        self.column_indices()
+            .map(Row::col_name)
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+
+    /// The `ORDER BY ...` clause for the given sorting or `None` if nothing is sorted.
+    /// Unlike [`TableRow::sorting_to_sql`] this ignores column indices that don't exist or are
+    /// skipped.
+    pub fn order_by(&self, sorting: &VecDeque<(usize, ColumnSort)>) -> Option<String> {
+        let sorting = sorting
+            .iter()
+            .filter(|(col_index, _)| {
+                *col_index < Row::column_count() && !self.skipped_columns.contains(col_index)
+            })
+            .copied()
+            .collect();
+
+        Row::sorting_to_sql(&sorting)
+    }
+
+    /// The `LIMIT ... OFFSET ...` clause that selects the rows in `range`.
+    pub fn limit_offset(&self, range: Range<usize>) -> String {
+        format!("LIMIT {} OFFSET {}", range.len(), range.start)
+    }
+
+    /// Selects the rows in `range` sorted by `sorting`.
+    pub fn select(&self, sorting: &VecDeque<(usize, ColumnSort)>, range: Range<usize>) -> String {
+        let mut sql = format!("SELECT {} FROM {}", self.columns(), self.table);
+
+        if let Some(order_by) = self.order_by(sorting) {
+            sql.push(' ');
+            sql.push_str(&order_by);
+        }
+
+        sql.push(' ');
+        sql.push_str(&self.limit_offset(range));
+
+        sql
+    }
+
+    /// Counts all rows of the table. Use it to implement [`TableDataProvider::row_count`].
+    pub fn count(&self) -> String {
+        format!("SELECT COUNT(*) FROM {}", self.table)
+    }
+}
@@ -0,0 +1,189 @@
+use crate::Direction;
+use leptos::html::AnyElement;
+use leptos::*;
+use leptos_use::{use_element_size_with_options, UseElementSizeOptions, UseElementSizeReturn};
+use std::collections::HashMap;
+
+/// Stable class that is added to every cell of a sticky column in addition to
+/// [`TableClassesProvider::sticky_cell`].
+pub const STICKY_CELL_CLASS: &str = "sticky-col";
+
+/// Stable class that is added to every cell of a column that is pinned to the right in addition to
+/// [`STICKY_CELL_CLASS`] and [`TableClassesProvider::sticky_right_cell`].
+pub const STICKY_RIGHT_CELL_CLASS: &str = "sticky-col-right";
+
+/// Provided as context by [`TableContent`] so that the cells of sticky columns can be pinned.
+#[derive(Copy, Clone)]
+pub(crate) struct StickyColumnsContext {
+    /// The indices of the columns that are pinned to the left.
+    pub columns: Signal<Vec<usize>>,
+    /// The indices of the columns that are pinned to the right.
+    pub right_columns: Signal<Vec<usize>>,
+    /// The measured widths of the head cells of the sticky columns by column index.
+    pub widths: RwSignal<HashMap<usize, f64>>,
+    /// The `left` offsets of the left sticky columns by column index.
+    pub offsets: Memo<HashMap<usize, f64>>,
+    /// The `right` offsets of the right sticky columns by column index.
+    pub right_offsets: Memo<HashMap<usize, f64>>,
+    /// Returns the classes of the classes provider for a left sticky cell.
+    pub class: Callback<(usize, bool), String>,
+    /// Returns the classes of the classes provider for a right sticky cell.
+    pub right_class: Callback<(usize, bool), String>,
+    /// The `direction` prop of the table. Swaps the sides the columns are pinned to.
+    pub direction: Signal<Direction>,
+}
+
+impl StickyColumnsContext {
+    /// Whether the column is pinned to either side. Tracks the sticky columns.
+    pub fn is_sticky(self, col_index: usize) -> bool {
+        self.columns.with(|columns| columns.contains(&col_index))
+            || self
+                .right_columns
+                .with(|columns| columns.contains(&col_index))
+    }
+
+    /// Whether the column is pinned to the right. Columns that are pinned to both sides stay left.
+    fn is_right(self, col_index: usize) -> bool {
+        !self
+            .columns
+            .with_untracked(|columns| columns.contains(&col_index))
+            && self
+                .right_columns
+                .with_untracked(|columns| columns.contains(&col_index))
+    }
+
+    /// Computes the `left` offsets of the sticky columns: every sticky column is pinned right
+    /// after the sticky columns that are displayed before it. Pass the column order reversed to
+    /// compute the `right` offsets of the columns that are pinned to the right.
+    pub fn compute_offsets(
+        sticky_columns: &[usize],
+        column_order: &[usize],
+        widths: &HashMap<usize, f64>,
+    ) -> HashMap<usize, f64> {
+        let mut left = 0.0;
+
+        column_order
+            .iter()
+            .filter(|col_index| sticky_columns.contains(col_index))
+            .map(|col_index| {
+                let offset = left;
+                left += widths.get(col_index).copied().unwrap_or_default();
+                (*col_index, offset)
+            })
+            .collect()
+    }
+
+    /// Pins the cells of the sticky columns in `cells` which is one rendered row with one node
+    /// per column in the natural column order. The head cells are measured to compute the offsets.
+    /// Tracks the sticky columns.
+    pub fn stick_cells(self, cells: View, is_head: bool) -> View {
+        let mut sticky_columns = self.columns.get();
+        sticky_columns.extend(self.right_columns.get());
+
+        match cells {
+            View::Component(mut fragment) if !sticky_columns.is_empty() => {
+                for col_index in sticky_columns {
+                    if let Some(cell) = fragment.children.get_mut(col_index) {
+                        let stuck = self.stick_cell(std::mem::take(cell), col_index, is_head);
+                        *cell = stuck;
+                    }
+                }
+
+                View::Component(fragment)
+            }
+            cells => cells,
+        }
+    }
+
+    /// Pins the root element of a single cell.
+    pub fn stick_cell(self, cell: View, col_index: usize, is_head: bool) -> View {
+        let is_right = self.is_right(col_index);
+
+        map_root_element(cell, move |element| {
+            let (offsets, classes) = if is_right {
+                (
+                    self.right_offsets,
+                    format!(
+                        "{STICKY_CELL_CLASS} {STICKY_RIGHT_CELL_CLASS} {}",
+                        (self.right_class)((col_index, is_head))
+                    ),
+                )
+            } else {
+                (
+                    self.offsets,
+                    format!("{STICKY_CELL_CLASS} {}", (self.class)((col_index, is_head))),
+                )
+            };
+
+            // in right-to-left tables the left sticky columns are pinned to the right and vice versa
+            let direction = self.direction;
+            let side = move || {
+                if is_right {
+                    direction.get().end_side()
+                } else {
+                    direction.get().start_side()
+                }
+            };
+            let offset = move |pinned_side: &'static str| {
+                (side() == pinned_side).then(|| {
+                    let offset = offsets
+                        .with(|offsets| offsets.get(&col_index).copied().unwrap_or_default());
+                    format!("{offset}px")
+                })
+            };
+
+            let element = element
+                .classes(classes)
+                .style("position", "sticky")
+                .style("left", move || offset("left"))
+                .style("right", move || offset("right"));
+
+            if is_head {
+                self.measure(element, col_index)
+            } else {
+                element
+            }
+        })
+    }
+
+    fn measure(
+        self,
+        element: HtmlElement<AnyElement>,
+        col_index: usize,
+    ) -> HtmlElement<AnyElement> {
+        let node_ref = create_node_ref::<AnyElement>();
+        let element = element.node_ref(node_ref);
+
+        let UseElementSizeReturn { width, .. } = use_element_size_with_options(
+            node_ref,
+            UseElementSizeOptions::default().box_(web_sys::ResizeObserverBoxOptions::BorderBox),
+        );
+
+        let widths = self.widths;
+        create_effect(move |_| {
+            let width = width.get();
+            widths.update(|widths| {
+                widths.insert(col_index, width);
+            });
+        });
+
+        element
+    }
+}
+
+/// Calls `f` with the root element of `view`. Components are descended into as long as they
+/// have exactly one child. Returns the view unchanged if there is no such element.
+pub(crate) fn map_root_element(
+    view: View,
+    f: impl FnOnce(HtmlElement<AnyElement>) -> HtmlElement<AnyElement>,
+) -> View {
+    match view {
+        View::Element(element) => f(element.into_html_element()).into_view(),
+        View::Component(mut component) if component.children.len() == 1 => {
+            let child = component.children.remove(0);
+            component.children.push(map_root_element(child, f));
+            View::Component(component)
+        }
+        view => view,
+    }
+}
@@ -0,0 +1,307 @@
+use crate::{CachedRowRange, CellInputEvent, EventHandler};
+use leptos::*;
+use std::ops::Range;
+use std::rc::Rc;
+use std::time::Duration;
+
+/// The coordinate of a cell in the body of the table.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct CellCoordinate {
+    /// The index of the row. Starts at 0 for the first body row.
+    pub row_index: usize,
+    /// The index of the column. Starts at 0 for the first column.
+    pub col_index: usize,
+}
+
+/// Where [`TableController::scroll_to_row`] places the row in the scroll container.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum ScrollAlign {
+    /// Only scrolls if the row isn't fully visible. The row is then placed at the top.
+    #[default]
+    Nearest,
+    /// Places the row at the top of the scroll container.
+    Start,
+    /// Places the row in the vertical center of the scroll container.
+    Center,
+}
+
+/// How long the row that has been scrolled to with [`TableController::scroll_to_row`] is highlighted.
+pub const ROW_HIGHLIGHT_DURATION: Duration = Duration::from_millis(2000);
+
+pub(crate) type RowIndexAtOffsetFn = Rc<dyn Fn(f64) -> Option<usize>>;
+
+/// You can pass this to a [`TableContent`] component's `table_controller` prop to control the
+/// table from the outside.
+///
+/// See the [editable example](https://github.com/Synphonyte/leptos-struct-table/blob/master/examples/editable/src/main.rs)
+/// for how to use.
+#[derive(Copy, Clone)]
+pub struct TableController {
+    pub(crate) edit_request: RwSignal<Option<(usize, String)>>,
+    pub(crate) editing_cell: RwSignal<Option<CellCoordinate>>,
+    pub(crate) row_index_at_offset: StoredValue<Option<RowIndexAtOffsetFn>>,
+    pub(crate) loading: RwSignal<bool>,
+    pub(crate) initial_loading: RwSignal<bool>,
+    pub(crate) scroll_request: RwSignal<Option<(usize, ScrollAlign)>>,
+    pub(crate) highlighted_row: RwSignal<Option<usize>>,
+    pub(crate) cached_rows: RwSignal<Option<Memo<Vec<CachedRowRange>>>>,
+    pub(crate) prefetch_requests: RwSignal<Vec<Range<usize>>>,
+}
+
+impl Default for TableController {
+    fn default() -> Self {
+        Self {
+            edit_request: create_rw_signal(None),
+            editing_cell: create_rw_signal(None),
+            row_index_at_offset: store_value(None),
+            loading: create_rw_signal(false),
+            initial_loading: create_rw_signal(true),
+            scroll_request: create_rw_signal(None),
+            highlighted_row: create_rw_signal(None),
+            cached_rows: create_rw_signal(None),
+            prefetch_requests: create_rw_signal(vec![]),
+        }
+    }
+}
+
+impl TableController {
+    /// Opens the cell at `row_index` in the column called `col_name` (see [`TableRow::col_name`]) for editing.
+    ///
+    /// If the row isn't visible it is scrolled into view. With [`DisplayStrategy::Pagination`] the page
+    /// that contains the row is shown instead. If the row isn't loaded yet, it is loaded first and the
+    /// cell is opened as soon as it's there. In both cases `on_cell_focus` of [`TableContent`] is called
+    /// once the cell is actually open.
+    ///
+    /// Cell renderers can use [`use_cell_editing`] to find out if they're the cell being edited.
+    ///
+    /// If `row_index` is beyond the known row count or there is no column called `col_name`
+    /// this does nothing.
+    pub fn edit_cell(&self, row_index: usize, col_name: impl Into<String>) {
+        self.edit_request.set(Some((row_index, col_name.into())));
+    }
+
+    /// Scrolls the row at `row_index` into view, e.g. to show a search match or the row of a deep link.
+    /// With [`DisplayStrategy::Pagination`] the page that contains the row is shown instead.
+    ///
+    /// The scroll position is computed from the heights of the rows that have been measured or hinted
+    /// (see the `measure_row_heights` and `row_height_fn` props of [`TableContent`]) and the average
+    /// height of all others. Since the row may not be loaded yet, the position is corrected once it
+    /// has been loaded and rendered, so it ends up where `align` places it even if its height differs.
+    ///
+    /// The row is highlighted for [`ROW_HIGHLIGHT_DURATION`] with the classes of
+    /// [`TableClassesProvider::highlighted_row`] so users can see where the table has scrolled to.
+    ///
+    /// If `row_index` is beyond the known row count this does nothing.
+    ///
+    /// ```
+    /// # use leptos::*;
+    /// # use leptos_struct_table::*;
+    /// #
+    /// #[derive(TableRow, Clone)]
+    /// #[table(impl_vec_data_provider)]
+    /// pub struct Book {
+    ///     title: String,
+    /// }
+    ///
+    /// #[component]
+    /// fn App() -> impl IntoView {
+    ///     # let rows: Vec<Book> = vec![];
+    ///     let table_controller = TableController::default();
+    ///
+    ///     view! {
+    ///         <button on:click=move |_| table_controller.scroll_to_row(500, ScrollAlign::Center)>
+    ///             "Go to row 500"
+    ///         </button>
+    ///         <table>
+    ///             <TableContent rows table_controller />
+    ///         </table>
+    ///     }
+    /// }
+    /// ```
+    pub fn scroll_to_row(&self, row_index: usize, align: ScrollAlign) {
+        self.scroll_request.set(Some((row_index, align)));
+    }
+
+    /// Returns a `Signal` of the row that is highlighted after [`TableController::scroll_to_row`].
+    pub fn highlighted_row(&self) -> Signal<Option<usize>> {
+        self.highlighted_row.into()
+    }
+
+    /// Closes the cell that is currently being edited (if any).
+    pub fn stop_editing(&self) {
+        self.editing_cell.set(None);
+    }
+
+    /// Returns the index of the body row at the vertical position `y`.
+    ///
+    /// `y` is in viewport coordinates, i.e. the same coordinate space as `clientY` of mouse and pointer
+    /// events or [`getBoundingClientRect()`](https://developer.mozilla.org/en-US/docs/Web/API/Element/getBoundingClientRect).
+    /// Rows that are rendered are measured directly so rows with different heights are handled correctly.
+    /// For positions inside of the placeholders of rows that aren't rendered (see [`DisplayStrategy::Virtualization`])
+    /// the index is estimated from the average row height.
+    ///
+    /// Returns `None` if `y` is above or below the body of the table or if the table isn't mounted.
+    pub fn row_index_at_offset(&self, y: f64) -> Option<usize> {
+        self.row_index_at_offset
+            .with_value(|row_index_at_offset| row_index_at_offset.as_ref().and_then(|f| f(y)))
+    }
+
+    /// Returns a `Signal` of the cell that is currently being edited.
+    pub fn editing_cell(&self) -> Signal<Option<CellCoordinate>> {
+        self.editing_cell.into()
+    }
+
+    /// Returns a `Signal` that is `true` while the table loads for the first time or rows are being
+    /// loaded, e.g. while scrolling. It includes the `loading` prop of [`TableContent`].
+    pub fn is_loading(&self) -> Signal<bool> {
+        self.loading.into()
+    }
+
+    /// Returns a `Signal` that is `true` until the data provider has returned data for the first time.
+    /// This is when the loading overlay of [`TableContent`] is shown.
+    pub fn is_initial_loading(&self) -> Signal<bool> {
+        self.initial_loading.into()
+    }
+
+    /// Returns a `Signal` of the ranges of rows that are currently loading, loaded or failed to load,
+    /// e.g. to find out which rows are fetched while scrolling. Rows that haven't been requested or
+    /// have been evicted (see the `cache` prop of [`TableContent`]) aren't contained. It's empty while
+    /// the table isn't mounted.
+    ///
+    /// The ranges are only computed while the signal is read.
+    pub fn cached_rows(&self) -> Signal<Vec<CachedRowRange>> {
+        let cached_rows = self.cached_rows;
+
+        Signal::derive(move || {
+            cached_rows
+                .get()
+                .map(|cached_rows| cached_rows.get())
+                .unwrap_or_default()
+        })
+    }
+
+    /// Loads the rows in `range` ahead of need, e.g. when the user hovers over the scrollbar or the
+    /// next page button. Rows that are already loaded or are loading (also because of an earlier
+    /// call) aren't requested again. The range is clamped to the row count if it's known.
+    ///
+    /// Without the `cache` prop of [`TableContent`] rows outside of the displayed ones are evicted
+    /// the next time the displayed rows change, so prefetching is most useful together with it.
+    ///
+    /// ```
+    /// # use leptos::*;
+    /// # use leptos_struct_table::*;
+    /// #
+    /// #[derive(TableRow, Clone)]
+    /// #[table(impl_vec_data_provider)]
+    /// pub struct Book {
+    ///     title: String,
+    /// }
+    ///
+    /// #[component]
+    /// fn App() -> impl IntoView {
+    ///     # let rows: Vec<Book> = vec![];
+    ///     let table_controller = TableController::default();
+    ///     let cached_rows = table_controller.cached_rows();
+    ///
+    ///     view! {
+    ///         <button on:mouseenter=move |_| table_controller.prefetch(100..200)>
+    ///             "Next 100"
+    ///         </button>
+    ///         <table>
+    ///             <TableContent rows table_controller cache=true />
+    ///         </table>
+    ///         <pre>{move || format!("{:#?}", cached_rows.get())}</pre>
+    ///     }
+    /// }
+    /// ```
+    pub fn prefetch(&self, range: Range<usize>) {
+        if range.is_empty() {
+            return;
+        }
+
+        self.prefetch_requests
+            .update(|prefetch_requests| prefetch_requests.push(range));
+    }
+}
+
+/// Provided by [`TableContent`] to the renderers of every loaded row.
+#[derive(Copy, Clone)]
+pub(crate) struct RenderedRowIndex(pub(crate) usize);
+
+/// Returns a `Signal` that is `true` while the cell in the column `col_index` of the current row is
+/// opened for editing through [`TableController::edit_cell`].
+///
+/// This is meant to be called inside of a cell renderer with the `index` the renderer receives.
+/// Outside of a [`TableContent`] this always returns `false`.
+pub fn use_cell_editing(col_index: usize) -> Signal<bool> {
+    match (
+        use_context::<TableController>(),
+        use_context::<RenderedRowIndex>(),
+    ) {
+        (Some(controller), Some(RenderedRowIndex(row_index))) => Signal::derive(move || {
+            controller.editing_cell.get()
+                == Some(CellCoordinate {
+                    row_index,
+                    col_index,
+                })
+        }),
+        _ => Signal::derive(|| false),
+    }
+}
+
+/// Provided by [`TableContent`] so that cell renderers can emit its `on_cell_input` event.
+#[derive(Clone)]
+pub(crate) struct CellInputHandler(pub(crate) EventHandler<CellInputEvent>);
+
+/// Returns a callback that emits the `on_cell_input` event of the surrounding [`TableContent`] for
+/// the cell in the column `col_index` of the current row. Call it with the current text of the input
+/// on every keystroke and call `on_change` once the value is committed, e.g. on blur or Enter.
+///
+/// This is meant to be called inside of a cell renderer with the `index` the renderer receives.
+/// Outside of a [`TableContent`] the callback does nothing.
+///
+/// ```
+/// # use leptos::*;
+/// # use leptos_struct_table::*;
+/// #
+/// #[component]
+/// pub fn ValidatedTextCellRenderer<F>(
+///     class: String,
+///     #[prop(into)] value: MaybeSignal<String>,
+///     on_change: F,
+///     index: usize,
+/// ) -> impl IntoView
+/// where
+///     F: Fn(String) + 'static,
+/// {
+///     let on_input = use_cell_input(index);
+///
+///     view! {
+///         <td class=class>
+///             <input
+///                 type="text"
+///                 prop:value=value
+///                 on:input=move |evt| on_input(event_target_value(&evt))
+///                 on:change=move |evt| on_change(event_target_value(&evt))
+///             />
+///         </td>
+///     }
+/// }
+/// ```
+pub fn use_cell_input(col_index: usize) -> Callback<String> {
+    match (
+        use_context::<CellInputHandler>(),
+        use_context::<RenderedRowIndex>(),
+    ) {
+        (Some(CellInputHandler(on_cell_input)), Some(RenderedRowIndex(row_index))) => {
+            Callback::new(move |value| {
+                on_cell_input.run(CellInputEvent {
+                    row_index,
+                    col_index,
+                    value,
+                })
+            })
+        }
+        _ => Callback::new(|_| {}),
+    }
+}
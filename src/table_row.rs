@@ -13,6 +13,12 @@ pub trait TableRow: Clone {
     /// How many columns this row has (i.e. the number of fields in the struct)
     const COLUMN_COUNT: usize;
 
+    /// How many columns this row has. Defaults to [`Self::COLUMN_COUNT`].
+    /// Override this for rows whose columns are only known at runtime like [`DynamicRow`].
+    fn column_count() -> usize {
+        Self::COLUMN_COUNT
+    }
+
     /// Renders the inner of one row of the table using the cell renderers.
     /// This produces the children that go into the `row_renderer` given to [`TableContent`].
     ///
@@ -87,3 +93,123 @@ pub fn get_sorting_for_column(
             .unwrap_or(ColumnSort::None)
     })
 }
+
+/// Encodes the sorting as text that can be put into a URL query parameter, e.g. `1:asc,0:desc`.
+/// The columns keep their order of priority. Columns with [`ColumnSort::None`] are left out and
+/// an empty sorting gives an empty string. Use [`sorting_from_param`] to restore it.
+///
+/// ```
+/// # use leptos_struct_table::*;
+/// # use std::collections::VecDeque;
+/// #
+/// let sorting = VecDeque::from([(1, ColumnSort::Ascending), (0, ColumnSort::Descending)]);
+///
+/// assert_eq!(sorting_to_param(&sorting), "1:asc,0:desc");
+/// assert_eq!(sorting_from_param("1:asc,0:desc"), sorting);
+/// assert_eq!(sorting_from_param(""), VecDeque::new());
+/// ```
+pub fn sorting_to_param(sorting: &VecDeque<(usize, ColumnSort)>) -> String {
+    sorting
+        .iter()
+        .filter_map(|(col_index, col_sort)| {
+            let order = match col_sort {
+                ColumnSort::Ascending => "asc",
+                ColumnSort::Descending => "desc",
+                ColumnSort::None => return None,
+            };
+
+            Some(format!("{col_index}:{order}"))
+        })
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+/// Decodes the sorting from text that has been created by [`sorting_to_param`].
+/// Malformed entries, e.g. from a URL that has been edited by hand, and repeated columns are ignored.
+pub fn sorting_from_param(param: &str) -> VecDeque<(usize, ColumnSort)> {
+    let mut sorting = VecDeque::new();
+
+    for entry in param.split(',') {
+        let Some((col_index, order)) = entry.trim().split_once(':') else {
+            continue;
+        };
+
+        let col_sort = match order {
+            "asc" => ColumnSort::Ascending,
+            "desc" => ColumnSort::Descending,
+            _ => continue,
+        };
+
+        if let Ok(col_index) = col_index.parse::<usize>() {
+            if !sorting.iter().any(|(col, _)| *col == col_index) {
+                sorting.push_back((col_index, col_sort));
+            }
+        }
+    }
+
+    sorting
+}
+
+/// Computes the initial value of the `sorting` prop of [`TableContent`] from the names of the
+/// columns (see [`TableRow::col_name`]). The first entry has the highest priority like in the
+/// `sorting` prop. Unknown names, repeated columns and [`ColumnSort::None`] are ignored.
+///
+/// Since the sorting is set before [`TableContent`] loads any rows, the data provider gets it through
+/// [`TableDataProvider::set_sorting`] before the first call of [`TableDataProvider::get_rows`] and
+/// unsorted rows are never shown. Clicking a header cell changes the sorting as usual afterwards.
+///
+/// ```
+/// # use leptos::*;
+/// # use leptos_struct_table::*;
+/// # use std::collections::VecDeque;
+/// #
+/// #[derive(TableRow, Clone)]
+/// #[table(sortable, impl_vec_data_provider)]
+/// pub struct LogEntry {
+///     timestamp: i64,
+///     level: String,
+///     message: String,
+/// }
+///
+/// let sorting = default_sorting::<LogEntry>([
+///     ("timestamp", ColumnSort::Descending),
+///     ("level", ColumnSort::Ascending),
+/// ]);
+///
+/// assert_eq!(sorting, VecDeque::from([(0, ColumnSort::Descending), (1, ColumnSort::Ascending)]));
+///
+/// #[component]
+/// fn App() -> impl IntoView {
+///     # let rows: Vec<LogEntry> = vec![];
+///     let sorting = create_rw_signal(default_sorting::<LogEntry>([("timestamp", ColumnSort::Descending)]));
+///
+///     view! {
+///         <table>
+///             <TableContent rows sorting />
+///         </table>
+///     }
+/// }
+/// ```
+pub fn default_sorting<'a, Row: TableRow>(
+    columns: impl IntoIterator<Item = (&'a str, ColumnSort)>,
+) -> VecDeque<(usize, ColumnSort)> {
+    let mut sorting = VecDeque::new();
+
+    for (col_name, col_sort) in columns {
+        if col_sort == ColumnSort::None {
+            continue;
+        }
+
+        let Some(col_index) =
+            (0..Row::column_count()).find(|col_index| Row::col_name(*col_index) == col_name)
+        else {
+            continue;
+        };
+
+        if !sorting.iter().any(|(col, _)| *col == col_index) {
+            sorting.push_back((col_index, col_sort));
+        }
+    }
+
+    sorting
+}
@@ -0,0 +1,465 @@
+use crate::loaded_rows::{LoadedRows, RowError, RowState};
+use crate::provider_cell::ProviderCell;
+use crate::{
+    CachedRowRange, CachedRowsState, ColumnSort, Selection, SortChangeEvent, TableDataProvider,
+};
+use leptos::*;
+use std::collections::VecDeque;
+use std::fmt::Debug;
+use std::marker::PhantomData;
+use std::ops::Range;
+use std::rc::Rc;
+
+type RowCountListener = Rc<dyn Fn(usize)>;
+
+/// The sorting, selection and row cache of a table without any rendering. Returned by
+/// [`use_table_state`] to build completely custom layouts like CSS grids or card lists on top of a
+/// [`TableDataProvider`]. [`TableContent`] keeps its state in a `TableState` as well and loads its
+/// rows the same way.
+///
+/// Rows are only requested from the data provider when [`TableState::load`] is called for them, so
+/// the layout decides which rows are needed, e.g. the current page or the rows in the viewport.
+/// Requests are aligned to the `CHUNK_SIZE` of the data provider like in [`TableContent`].
+pub struct TableState<Row, DataP, Err = String>
+where
+    Row: Clone + 'static,
+    DataP: TableDataProvider<Row, Err> + 'static,
+    Err: Debug + 'static,
+{
+    /// The sorting that is passed to the data provider. Changing it reloads the rows.
+    pub sorting: RwSignal<VecDeque<(usize, ColumnSort)>>,
+    /// The selection. See [`TableState::toggle_selected`].
+    pub selection: Selection,
+    provider: StoredValue<Rc<ProviderCell<DataP>>>,
+    pub(crate) loaded_rows: RwSignal<LoadedRows<Row>>,
+    pub(crate) row_count: RwSignal<Option<usize>>,
+    error: RwSignal<Option<Rc<Err>>>,
+    /// Bumped when the rows are discarded so the results of pending requests are ignored.
+    pub(crate) reload_count: RwSignal<usize>,
+    /// The id of the latest row count request so only its result is used.
+    row_count_request: StoredValue<usize>,
+    row_count_listener: StoredValue<Option<RowCountListener>>,
+    _phantom: PhantomData<Err>,
+}
+
+impl<Row, DataP, Err> Clone for TableState<Row, DataP, Err>
+where
+    Row: Clone + 'static,
+    DataP: TableDataProvider<Row, Err> + 'static,
+    Err: Debug + 'static,
+{
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<Row, DataP, Err> Copy for TableState<Row, DataP, Err>
+where
+    Row: Clone + 'static,
+    DataP: TableDataProvider<Row, Err> + 'static,
+    Err: Debug + 'static,
+{
+}
+
+/// Creates the headless state of a table for `rows` (the data provider), sorted by `sorting` and
+/// selectable according to `selection`. [`TableContent`] uses the same sorting logic, so header
+/// clicks behave the same in custom layouts.
+///
+/// ```
+/// # use leptos::*;
+/// # use leptos_struct_table::*;
+/// # use std::collections::{HashSet, VecDeque};
+/// #
+/// #[derive(TableRow, Clone)]
+/// #[table(sortable, impl_vec_data_provider)]
+/// pub struct Book {
+///     title: String,
+///     author: String,
+/// }
+///
+/// #[component]
+/// fn BookGrid() -> impl IntoView {
+///     # let rows: Vec<Book> = vec![];
+///     let selected = create_rw_signal(HashSet::new());
+///     let state = use_table_state(rows, create_rw_signal(VecDeque::new()), Selection::Multiple(selected));
+///
+///     create_effect(move |_| state.load(0..20));
+///
+///     view! {
+///         <div class="grid grid-cols-4 gap-2">
+///             <button on:click=move |_| { state.head_click(0); }>"Sort by title"</button>
+///             <For
+///                 each=move || state.loaded_indices()
+///                 key=|index| *index
+///                 children=move |index| view! {
+///                     <div
+///                         class:selected=move || state.selection.is_selected(index)
+///                         on:click=move |_| state.toggle_selected(index)
+///                     >
+///                         {move || state.row(index).map(|book| book.title)}
+///                     </div>
+///                 }
+///             />
+///             <Show when=move || state.loading().get()>"Loading..."</Show>
+///         </div>
+///     }
+/// }
+/// ```
+pub fn use_table_state<Row, DataP, Err>(
+    rows: DataP,
+    sorting: RwSignal<VecDeque<(usize, ColumnSort)>>,
+    selection: Selection,
+) -> TableState<Row, DataP, Err>
+where
+    Row: Clone + 'static,
+    DataP: TableDataProvider<Row, Err> + 'static,
+    Err: Debug + 'static,
+{
+    let state = TableState::new(Rc::new(ProviderCell::new(rows)), sorting, selection);
+
+    create_effect(move |prev: Option<()>| {
+        state.apply_sorting();
+
+        if prev.is_some() {
+            state.reload();
+        }
+    });
+
+    // reload when the signals that the data provider depends on have changed
+    create_effect(move |prev: Option<()>| {
+        state.track_provider();
+
+        if prev.is_some() {
+            state.reload();
+        } else {
+            state.load_row_count(None);
+        }
+    });
+
+    state
+}
+
+impl<Row, DataP, Err> TableState<Row, DataP, Err>
+where
+    Row: Clone + 'static,
+    DataP: TableDataProvider<Row, Err> + 'static,
+    Err: Debug + 'static,
+{
+    /// Creates the state without any effects. The owner is responsible for applying the sorting and
+    /// reloading, see [`use_table_state`].
+    pub(crate) fn new(
+        provider: Rc<ProviderCell<DataP>>,
+        sorting: RwSignal<VecDeque<(usize, ColumnSort)>>,
+        selection: Selection,
+    ) -> Self {
+        Self {
+            sorting,
+            selection,
+            provider: store_value(provider),
+            loaded_rows: create_rw_signal(LoadedRows::new()),
+            row_count: create_rw_signal(None),
+            error: create_rw_signal(None),
+            reload_count: create_rw_signal(0),
+            row_count_request: store_value(0),
+            row_count_listener: store_value(None),
+            _phantom: PhantomData,
+        }
+    }
+
+    /// The number of rows as reported by the data provider or as known from the last chunk.
+    /// `None` until it is known.
+    pub fn row_count(&self) -> Signal<Option<usize>> {
+        self.row_count.into()
+    }
+
+    /// `true` while any rows are being loaded.
+    pub fn loading(&self) -> Signal<bool> {
+        let loaded_rows = self.loaded_rows;
+
+        Signal::derive(move || {
+            loaded_rows.with(|loaded_rows| {
+                loaded_rows
+                    .cached_ranges()
+                    .iter()
+                    .any(|cached| cached.state == CachedRowsState::Loading)
+            })
+        })
+    }
+
+    /// The error of the last request that failed, or `None` if the last request succeeded.
+    pub fn error(&self) -> Signal<Option<Rc<Err>>> {
+        self.error.into()
+    }
+
+    /// The ranges of the rows that are loading, loaded or failed to load.
+    pub fn cached_rows(&self) -> Signal<Vec<CachedRowRange>> {
+        let loaded_rows = self.loaded_rows;
+
+        Signal::derive(move || loaded_rows.with(LoadedRows::cached_ranges))
+    }
+
+    /// The row at `index` if it has been loaded. Tracks the row cache.
+    pub fn row(&self, index: usize) -> Option<Row> {
+        self.loaded_rows.with(|loaded_rows| {
+            if index >= loaded_rows.len() {
+                return None;
+            }
+
+            match &loaded_rows[index] {
+                RowState::Loaded(row) => Some(row.clone()),
+                _ => None,
+            }
+        })
+    }
+
+    /// The indices of the loaded rows in ascending order. Tracks the row cache.
+    pub fn loaded_indices(&self) -> Vec<usize> {
+        self.loaded_rows.with(|loaded_rows| {
+            loaded_rows
+                .cached_ranges()
+                .into_iter()
+                .filter(|cached| cached.state == CachedRowsState::Loaded)
+                .flat_map(|cached| cached.range)
+                .collect()
+        })
+    }
+
+    /// Requests the rows in `range` that haven't been loaded or requested yet from the data provider.
+    /// The range is clamped to the row count once it's known.
+    ///
+    /// The rows are discarded when they're reloaded, e.g. because the sorting has changed. This
+    /// tracks reloads, so call it inside of an effect to request the rows again afterwards.
+    pub fn load(&self, range: Range<usize>) {
+        self.reload_count.track();
+
+        let row_count = self.row_count.get_untracked();
+        let clamp = |end: usize| row_count.map_or(end, |row_count| end.min(row_count));
+
+        let range = match DataP::CHUNK_SIZE {
+            Some(chunk_size) => {
+                range.start / chunk_size * chunk_size
+                    ..clamp(range.end.div_ceil(chunk_size) * chunk_size)
+            }
+            None => range.start..clamp(range.end),
+        };
+        if range.start >= range.end {
+            return;
+        }
+
+        // the row count isn't known yet, so the cache has to grow
+        if self.loaded_rows.with_untracked(LoadedRows::len) < range.end {
+            self.loaded_rows
+                .update(|loaded_rows| loaded_rows.resize(range.end));
+        }
+
+        let loaded_rows = self.loaded_rows;
+        self.load_missing_rows(range, move |result, missing_range| {
+            let result = result.map_err(RowError::new);
+            loaded_rows.update(|loaded_rows| loaded_rows.write_loaded(result, missing_range));
+        });
+    }
+
+    /// Requests the rows in `range` that are neither loaded nor loading from the data provider in
+    /// chunks of `CHUNK_SIZE`. Tracks the row count.
+    ///
+    /// Errors are kept for [`TableState::error`] and a chunk with fewer rows than requested sets the
+    /// row count. `on_result` is called with the result of every request that hasn't been discarded
+    /// by a reload in the meantime, together with the requested range. It has to write the result
+    /// to the row cache.
+    pub(crate) fn load_missing_rows<F>(&self, range: Range<usize>, on_result: F)
+    where
+        F: Fn(Result<(Vec<Row>, Range<usize>), Rc<Err>>, Range<usize>) + Clone + 'static,
+    {
+        let Some(missing_range) = self
+            .loaded_rows
+            .with_untracked(|loaded_rows| loaded_rows.missing_range(range))
+        else {
+            return;
+        };
+
+        if let Some(row_count) = self.row_count.get() {
+            if missing_range.end.min(row_count) <= missing_range.start {
+                return;
+            }
+        }
+
+        self.loaded_rows
+            .update(|loaded_rows| loaded_rows.write_loading(missing_range.clone()));
+
+        let loading_ranges = match DataP::CHUNK_SIZE {
+            Some(chunk_size) => (missing_range.start / chunk_size
+                ..missing_range.end.div_ceil(chunk_size))
+                .map(|chunk| chunk * chunk_size..(chunk + 1) * chunk_size)
+                .collect(),
+            None => vec![missing_range],
+        };
+
+        // TODO : implement max concurrent requests
+        for missing_range in loading_ranges {
+            let state = *self;
+            let on_result = on_result.clone();
+
+            spawn_local(async move {
+                let reload_count = state.reload_count.get_untracked();
+                let provider = state.provider.get_value();
+
+                let result = provider.get_rows(missing_range.clone()).await;
+
+                // the rows have been reloaded in the meantime
+                if reload_count != state.reload_count.get_untracked() {
+                    return;
+                }
+
+                let result = match result {
+                    Ok((rows, range)) => {
+                        state.error.set(None);
+
+                        // fewer rows than requested means that the end has been reached
+                        if range.end < missing_range.end
+                            && state
+                                .row_count
+                                .get_untracked()
+                                .is_none_or(|row_count| range.end < row_count)
+                        {
+                            state.set_row_count(range.end);
+                        }
+
+                        Ok((rows, range))
+                    }
+                    Err(error) => {
+                        let error = Rc::new(error);
+                        state.error.set(Some(Rc::clone(&error)));
+
+                        Err(error)
+                    }
+                };
+
+                on_result(result, missing_range);
+            });
+        }
+    }
+
+    /// Discards all loaded rows and the selection. Rows are loaded again by the next call of
+    /// [`TableState::load`].
+    pub fn reload(&self) {
+        self.selection.clear();
+        self.clear_rows();
+        self.row_count.set(None);
+        self.discard_requests();
+        self.load_row_count(None);
+    }
+
+    /// Changes the sorting like a click on the header of the column at `col_index` in [`TableContent`]
+    /// and returns the corresponding event.
+    pub fn head_click(&self, col_index: usize) -> SortChangeEvent {
+        self.sorting
+            .update(|sorting| update_sorting(sorting, col_index));
+
+        SortChangeEvent {
+            col_index,
+            sorting: self.sorting.get_untracked(),
+        }
+    }
+
+    /// Selects the row at `index` or de-selects it if it's selected already. With
+    /// [`Selection::Single`] it replaces the selected row.
+    pub fn toggle_selected(&self, index: usize) {
+        match self.selection {
+            Selection::None => {}
+            Selection::Single(selected_index) => {
+                selected_index.update(|selected_index| {
+                    *selected_index = (*selected_index != Some(index)).then_some(index);
+                });
+            }
+            Selection::Multiple(selected_indices) => {
+                selected_indices.update(|selected_indices| {
+                    if !selected_indices.remove(&index) {
+                        selected_indices.insert(index);
+                    }
+                });
+            }
+        }
+    }
+
+    /// Passes the sorting to the data provider. Tracks the sorting.
+    pub(crate) fn apply_sorting(&self) {
+        let sorting = self.sorting.get();
+        self.provider
+            .with_value(|provider| provider.update(move |provider| provider.set_sorting(&sorting)));
+    }
+
+    /// Tracks the signals that the data provider depends on.
+    pub(crate) fn track_provider(&self) {
+        self.provider.with_value(|provider| provider.track());
+    }
+
+    /// Resets all rows in the cache so they are requested again and clears the error.
+    pub(crate) fn clear_rows(&self) {
+        self.error.set(None);
+        self.loaded_rows.update(LoadedRows::clear);
+    }
+
+    /// Makes sure that the results of the requests that are pending are ignored.
+    pub(crate) fn discard_requests(&self) {
+        self.reload_count
+            .set(self.reload_count.get_untracked().overflowing_add(1).0);
+    }
+
+    /// Requests the row count from the data provider, or takes it from `total_row_count` if given.
+    /// Only the result of the latest request is used.
+    pub(crate) fn load_row_count(&self, total_row_count: Option<Signal<usize>>) {
+        let state = *self;
+
+        state
+            .row_count_request
+            .update_value(|request| *request = request.overflowing_add(1).0);
+        let request = state.row_count_request.get_value();
+
+        spawn_local(async move {
+            let row_count = match total_row_count {
+                Some(total_row_count) => Some(total_row_count.get_untracked()),
+                None => state.provider.get_value().row_count().await,
+            };
+
+            if let Some(row_count) = row_count {
+                if request == state.row_count_request.get_value() {
+                    state.set_row_count(row_count);
+                }
+            }
+        });
+    }
+
+    /// Calls `listener` with the row count every time it becomes known.
+    pub(crate) fn on_row_count(&self, listener: impl Fn(usize) + 'static) {
+        self.row_count_listener.set_value(Some(Rc::new(listener)));
+    }
+
+    pub(crate) fn set_row_count(&self, row_count: usize) {
+        self.row_count.set(Some(row_count));
+        self.loaded_rows
+            .update(|loaded_rows| loaded_rows.resize(row_count));
+
+        if let Some(listener) = self.row_count_listener.get_value() {
+            listener(row_count);
+        }
+    }
+}
+
+/// Updates `sorting` after the header of the column at `col_index` has been clicked. A column that
+/// isn't sorted yet becomes the first one. Sorted columns keep their priority and cycle through
+/// ascending, descending and unsorted, so the columns after a removed one move up.
+pub(crate) fn update_sorting(sorting: &mut VecDeque<(usize, ColumnSort)>, col_index: usize) {
+    sorting.retain(|(_, sort)| *sort != ColumnSort::None);
+
+    let Some(position) = sorting.iter().position(|(index, _)| *index == col_index) else {
+        sorting.push_front((col_index, ColumnSort::Ascending));
+        return;
+    };
+
+    match sorting[position].1 {
+        ColumnSort::Ascending => sorting[position].1 = ColumnSort::Descending,
+        _ => {
+            sorting.remove(position);
+        }
+    }
+}